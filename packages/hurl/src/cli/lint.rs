@@ -0,0 +1,124 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Implementation of the `hurl lint` subcommand.
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::process::ExitCode;
+
+use hurl::linter;
+use hurl_core::error::{DisplaySourceError, OutputFormat};
+use hurl_core::parser;
+
+/// Runs the `hurl lint` subcommand with `args`, the command line arguments following `lint`.
+///
+/// Each file is parsed and linted, issues are reported on standard output as
+/// `FILE:LINE:COL: CODE: message`. With `--fix`, style issues are corrected in place before
+/// linting. The exit code is 0 when every file is clean, 1 when issues have been found, and 2
+/// when a file can't be read or parsed.
+pub fn run(args: &[String]) -> ExitCode {
+    let command = clap::Command::new("hurl lint")
+        .about("Check Hurl files for style and semantic issues")
+        .arg(
+            clap::Arg::new("fix")
+                .long("fix")
+                .help("Fix style issues in place, leaving semantic issues untouched")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("strict_file_bindings")
+                .long("strict-file-bindings")
+                .help("Report bound files that do not exist")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("input_files")
+                .value_name("FILE")
+                .help("Hurl file(s) to lint")
+                .num_args(1..)
+                .required(true),
+        );
+    let program = String::from("hurl lint");
+    let args = std::iter::once(&program).chain(args.iter());
+    let matches = match command.try_get_matches_from(args) {
+        Ok(matches) => matches,
+        Err(error) => {
+            let _ = error.print();
+            return if error.use_stderr() {
+                ExitCode::from(crate::EXIT_ERROR_COMMANDLINE)
+            } else {
+                ExitCode::from(crate::EXIT_OK)
+            };
+        }
+    };
+    let fix_mode = matches.get_flag("fix");
+    let strict_file_bindings = matches.get_flag("strict_file_bindings");
+    let files = matches.get_many::<String>("input_files").unwrap();
+    let color = io::stderr().is_terminal();
+
+    let mut errors_count = 0;
+    let mut issues_count = 0;
+    for filename in files {
+        let mut content = match fs::read_to_string(filename) {
+            Ok(c) => c,
+            Err(error) => {
+                eprintln!("Issue reading from {filename}: {error}");
+                errors_count += 1;
+                continue;
+            }
+        };
+        if fix_mode {
+            let fixed = linter::fix(&content);
+            if fixed != content {
+                if let Err(error) = fs::write(filename, &fixed) {
+                    eprintln!("Issue writing to {filename}: {error}");
+                    errors_count += 1;
+                    continue;
+                }
+                content = fixed;
+            }
+        }
+        let hurl_file = match parser::parse_hurl_file(&content) {
+            Ok(file) => file,
+            Err(error) => {
+                let message = error.render(filename, &content, None, OutputFormat::Terminal(color));
+                eprintln!("{message}");
+                errors_count += 1;
+                continue;
+            }
+        };
+        let issues = linter::lint(&hurl_file, &content, strict_file_bindings);
+        for issue in &issues {
+            let line = issue.source_info.start.line;
+            let column = issue.source_info.start.column;
+            println!(
+                "{filename}:{line}:{column}: {}: {}",
+                issue.rule.code(),
+                issue.message
+            );
+        }
+        issues_count += issues.len();
+    }
+
+    if errors_count > 0 {
+        ExitCode::from(crate::EXIT_ERROR_PARSING)
+    } else if issues_count > 0 {
+        ExitCode::from(crate::EXIT_ERROR_COMMANDLINE)
+    } else {
+        ExitCode::from(crate::EXIT_OK)
+    }
+}
@@ -16,6 +16,7 @@
  *
  */
 mod error;
+pub(crate) mod lint;
 mod logger;
 pub(crate) mod options;
 mod summary;
@@ -23,4 +24,4 @@ mod summary;
 pub(crate) use self::error::CliError;
 pub(crate) use self::logger::BaseLogger;
 pub(crate) use self::options::OutputType;
-pub(crate) use self::summary::summary;
+pub(crate) use self::summary::{json_summary, summary};
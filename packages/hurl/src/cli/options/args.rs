@@ -30,10 +30,13 @@ use hurl_core::types::{BytesPerSec, Count, DurationUnit};
 
 use super::context::RunContext;
 use super::variables::TypeKind;
-use super::variables_file::VariablesFile;
-use super::{commands, get_version, secret, CliOptions};
-use super::{duration, variables, CliOptionsError, ErrorFormat, HttpVersion, IpResolve, Output};
-use super::{OutputType, Verbosity};
+use super::variables_file::{self, VariablesFile};
+use super::{commands, get_version, secret, CliOptions, CurlExport, OpenApiGroupBy};
+use super::{
+    duration, variables, CliOptionsError, ErrorFormat, HttpVersion, IpResolve, Output,
+    SummaryFormat,
+};
+use super::{OutputType, VariablesFormat, Verbosity};
 
 /// Parses the command line arguments given a `context` and default options values.
 ///
@@ -80,6 +83,7 @@ pub fn parse_cli_args(
         .arg(commands::negotiate())
         .arg(commands::no_cookie_store())
         .arg(commands::no_proxy())
+        .arg(commands::no_warn_insecure())
         .arg(commands::ntlm())
         .arg(commands::path_as_is())
         .arg(commands::pinned_pub_key())
@@ -90,9 +94,11 @@ pub fn parse_cli_args(
         .arg(commands::user())
         .arg(commands::user_agent())
         // Output options
+        .arg(commands::assert_fail_context())
         .arg(commands::color())
         .arg(commands::curl())
         .arg(commands::error_format())
+        .arg(commands::export_har())
         .arg(commands::include())
         .arg(commands::json())
         .arg(commands::no_color())
@@ -101,38 +107,59 @@ pub fn parse_cli_args(
         .arg(commands::output())
         .arg(commands::pretty())
         .arg(commands::progress_bar())
+        .arg(commands::summary_format())
         .arg(commands::verbose())
         .arg(commands::very_verbose())
         .arg(commands::verbosity())
         // Run options
         .arg(commands::continue_on_error())
         .arg(commands::delay())
+        .arg(commands::dry_run())
+        .arg(commands::env())
+        .arg(commands::env_ext())
+        .arg(commands::env_prefix())
+        .arg(commands::exclude_tags())
         .arg(commands::from_entry())
         .arg(commands::ignore_asserts())
         .arg(commands::jobs())
         .arg(commands::parallel())
         .arg(commands::repeat())
+        .arg(commands::require_env_file())
         .arg(commands::retry())
         .arg(commands::retry_interval())
+        .arg(commands::retry_on_status())
         .arg(commands::secret())
         .arg(commands::secrets_file())
+        .arg(commands::seed())
+        .arg(commands::tags())
         .arg(commands::test())
+        .arg(commands::to_curl())
         .arg(commands::to_entry())
         .arg(commands::variable())
         .arg(commands::variables_file())
+        .arg(commands::watch())
         // Report options
         .arg(commands::report_html())
         .arg(commands::report_json())
         .arg(commands::report_junit())
         .arg(commands::report_tap())
         // Other options
+        .arg(commands::allow_path_escape())
         .arg(commands::cookies_input_file())
         .arg(commands::cookies_output_file())
         .arg(commands::file_root())
         .arg(commands::glob())
+        .arg(commands::import_har())
+        .arg(commands::import_openapi())
+        .arg(commands::import_postman())
+        .arg(commands::include_secrets())
         .arg(commands::netrc())
         .arg(commands::netrc_file())
-        .arg(commands::netrc_optional());
+        .arg(commands::netrc_optional())
+        .arg(commands::openapi_group_by())
+        .arg(commands::output_dir())
+        .arg(commands::output_variables())
+        .arg(commands::output_variables_format());
 
     let arg_matches = command.try_get_matches_from_mut(std::env::args_os());
     let arg_matches = match arg_matches {
@@ -147,7 +174,12 @@ pub fn parse_cli_args(
 
     // If we've no file input (either from the standard input or from the command line arguments),
     // we just print help and exit.
-    if !has_input_files(&arg_matches, context) {
+    // In HAR, OpenAPI or Postman import modes, the input is the imported file, there is no Hurl
+    // file to run.
+    let import_mode = get::<String>(&arg_matches, "import_har").is_some()
+        || get::<String>(&arg_matches, "import_openapi").is_some()
+        || get::<String>(&arg_matches, "import_postman").is_some();
+    if !import_mode && !has_input_files(&arg_matches, context) {
         let help = if default_options.color_stdout {
             command.render_help().ansi().to_string()
         } else {
@@ -157,7 +189,7 @@ pub fn parse_cli_args(
     }
 
     let options = parse_arg_matches(&arg_matches, context, default_options)?;
-    if options.input_files.is_empty() {
+    if options.input_files.is_empty() && !import_mode {
         return Err(CliOptionsError::Error(
             "No input files provided".to_string(),
         ));
@@ -172,6 +204,9 @@ fn parse_arg_matches(
     context: &RunContext,
     default_options: CliOptions,
 ) -> Result<CliOptions, CliOptionsError> {
+    let allow_path_escape = allow_path_escape(arg_matches, default_options.allow_path_escape);
+    let assert_fail_context =
+        assert_fail_context(arg_matches, default_options.assert_fail_context);
     let aws_sigv4 = aws_sigv4(arg_matches, default_options.aws_sigv4);
     let cacert_file = cacert_file(arg_matches, default_options.cacert_file)?;
     let client_cert_file = client_cert_file(arg_matches, default_options.client_cert_file)?;
@@ -185,9 +220,12 @@ fn parse_arg_matches(
     let cookie_input_file = cookie_input_file(arg_matches, default_options.cookie_input_file);
     let cookie_output_file = cookie_output_file(arg_matches, default_options.cookie_output_file);
     let curl_file = curl_file(arg_matches, default_options.curl_file);
+    let har_file = har_file(arg_matches, default_options.har_file);
     let delay = delay(arg_matches, default_options.delay)?;
     let digest = digest(arg_matches, default_options.digest);
+    let dry_run = dry_run(arg_matches, default_options.dry_run);
     let error_format = error_format(arg_matches, default_options.error_format);
+    let exclude_tags = exclude_tags(arg_matches, default_options.exclude_tags);
     let file_root = file_root(arg_matches, default_options.file_root);
     let (follow_location, follow_location_trusted) = follow_location(
         arg_matches,
@@ -201,14 +239,18 @@ fn parse_arg_matches(
     let html_dir = html_dir(arg_matches, default_options.html_dir)?;
     let http_version = http_version(arg_matches, default_options.http_version);
     let ignore_asserts = ignore_asserts(arg_matches, default_options.ignore_asserts);
+    let import_har = import_har(arg_matches, default_options.import_har);
+    let import_openapi = import_openapi(arg_matches, default_options.import_openapi);
+    let import_postman = import_postman(arg_matches, default_options.import_postman);
     let include = include(arg_matches, default_options.include);
+    let include_secrets = include_secrets(arg_matches, default_options.include_secrets);
     let input_files = input_files(arg_matches, context)?;
     let insecure = insecure(arg_matches, default_options.insecure);
     let ip_resolve = ip_resolve(arg_matches, default_options.ip_resolve);
     let jobs = jobs(arg_matches, default_options.jobs);
     let json_report_dir = json_report_dir(arg_matches, default_options.json_report_dir)?;
     let junit_file = junit_file(arg_matches, default_options.junit_file);
-    let limit_rate = limit_rate(arg_matches, default_options.limit_rate);
+    let limit_rate = limit_rate(arg_matches, default_options.limit_rate)?;
     let max_filesize = max_filesize(arg_matches, default_options.max_filesize);
     let max_redirect = max_redirect(arg_matches, default_options.max_redirect);
     let negotiate = negotiate(arg_matches, default_options.negotiate);
@@ -217,7 +259,10 @@ fn parse_arg_matches(
     let netrc_optional = netrc_optional(arg_matches, default_options.netrc_optional);
     let no_cookie_store = no_cookie_store(arg_matches, default_options.no_cookie_store);
     let no_proxy = no_proxy(arg_matches, default_options.no_proxy);
+    let no_warn_insecure = no_warn_insecure(arg_matches, default_options.no_warn_insecure);
     let ntlm = ntlm(arg_matches, default_options.ntlm);
+    let openapi_group_by = openapi_group_by(arg_matches, default_options.openapi_group_by);
+    let output_dir = output_dir(arg_matches, default_options.output_dir);
     let parallel = parallel(arg_matches, default_options.parallel);
     let path_as_is = path_as_is(arg_matches, default_options.path_as_is);
     let pinned_pub_key = pinned_pub_key(arg_matches, default_options.pinned_pub_key);
@@ -226,20 +271,29 @@ fn parse_arg_matches(
     let proxy = proxy(arg_matches, default_options.proxy);
     let output = output(arg_matches, default_options.output);
     let output_type = output_type(arg_matches, default_options.output_type);
+    let output_variables = output_variables(arg_matches, default_options.output_variables);
+    let output_variables_format =
+        output_variables_format(arg_matches, default_options.output_variables_format);
     let repeat = repeat(arg_matches, default_options.repeat);
     let resolves = resolves(arg_matches, default_options.resolves);
     let retry = retry(arg_matches, default_options.retry);
     let retry_interval = retry_interval(arg_matches, default_options.retry_interval)?;
+    let retry_on_status = retry_on_status(arg_matches, default_options.retry_on_status)?;
     let secrets = secret(arg_matches, default_options.secrets)?;
+    let seed = get::<u64>(arg_matches, "seed").or(default_options.seed);
     let ssl_no_revoke = ssl_no_revoke(arg_matches, default_options.ssl_no_revoke);
+    let summary_format = summary_format(arg_matches, default_options.summary_format);
+    let tags = tags(arg_matches, default_options.tags);
     let tap_file = tap_file(arg_matches, default_options.tap_file);
     let test = test(arg_matches, default_options.test);
     let timeout = timeout(arg_matches, default_options.timeout)?;
+    let to_curl = to_curl(arg_matches, default_options.to_curl)?;
     let to_entry = to_entry(arg_matches, default_options.to_entry);
     let unix_socket = unix_socket(arg_matches, default_options.unix_socket);
     let user = user(arg_matches, default_options.user);
     let user_agent = user_agent(arg_matches, default_options.user_agent);
-    let variables = variables(arg_matches, default_options.variables)?;
+    let variables = variables(arg_matches, context, default_options.variables)?;
+    let watch = has_flag(arg_matches, "watch");
 
     let verbose = verbose(
         arg_matches,
@@ -259,6 +313,8 @@ fn parse_arg_matches(
     };
 
     Ok(CliOptions {
+        allow_path_escape,
+        assert_fail_context,
         aws_sigv4,
         cacert_file,
         client_cert_file,
@@ -272,9 +328,12 @@ fn parse_arg_matches(
         cookie_input_file,
         cookie_output_file,
         curl_file,
+        har_file,
         delay,
         digest,
+        dry_run,
         error_format,
+        exclude_tags,
         file_root,
         follow_location,
         follow_location_trusted,
@@ -283,7 +342,11 @@ fn parse_arg_matches(
         html_dir,
         http_version,
         ignore_asserts,
+        import_har,
+        import_openapi,
+        import_postman,
         include,
+        include_secrets,
         input_files,
         insecure,
         ip_resolve,
@@ -298,7 +361,10 @@ fn parse_arg_matches(
         netrc_optional,
         no_cookie_store,
         no_proxy,
+        no_warn_insecure,
         ntlm,
+        openapi_group_by,
+        output_dir,
         path_as_is,
         pinned_pub_key,
         parallel,
@@ -307,21 +373,29 @@ fn parse_arg_matches(
         proxy,
         output,
         output_type,
+        output_variables,
+        output_variables_format,
         repeat,
         resolves,
         retry,
         retry_interval,
+        retry_on_status,
         secrets,
+        seed,
         ssl_no_revoke,
+        summary_format,
+        tags,
         tap_file,
         test,
         timeout,
+        to_curl,
         to_entry,
         unix_socket,
         user,
         user_agent,
         variables,
         verbosity,
+        watch,
         jobs,
     })
 }
@@ -346,6 +420,18 @@ fn cacert_file(
     }
 }
 
+fn allow_path_escape(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "allow_path_escape") {
+        true
+    } else {
+        default_value
+    }
+}
+
+fn assert_fail_context(arg_matches: &ArgMatches, default_value: usize) -> usize {
+    get::<u32>(arg_matches, "assert_fail_context").map_or(default_value, |v| v as usize)
+}
+
 fn aws_sigv4(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
     get::<String>(arg_matches, "aws_sigv4").or(default_value)
 }
@@ -425,6 +511,38 @@ fn continue_on_error(arg_matches: &ArgMatches, default_value: bool) -> bool {
     }
 }
 
+fn import_har(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "import_har")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
+fn import_openapi(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "import_openapi")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
+fn import_postman(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "import_postman")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
+fn openapi_group_by(arg_matches: &ArgMatches, default_value: OpenApiGroupBy) -> OpenApiGroupBy {
+    match get::<String>(arg_matches, "openapi_group_by").as_deref() {
+        Some("path") => OpenApiGroupBy::Path,
+        Some(_) => OpenApiGroupBy::Tag,
+        None => default_value,
+    }
+}
+
+fn output_dir(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "output_dir")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
 fn cookie_input_file(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<String> {
     get::<String>(arg_matches, "cookies_input_file").or(default_value)
 }
@@ -441,6 +559,12 @@ fn curl_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option
         .or(default_value)
 }
 
+fn har_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "export_har")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
 fn delay(arg_matches: &ArgMatches, default_value: Duration) -> Result<Duration, CliOptionsError> {
     match get::<String>(arg_matches, "delay") {
         Some(s) => get_duration(&s, DurationUnit::MilliSecond),
@@ -461,6 +585,7 @@ fn error_format(arg_matches: &ArgMatches, default_value: ErrorFormat) -> ErrorFo
         Some(error_format) => match error_format.as_str() {
             "long" => ErrorFormat::Long,
             "short" => ErrorFormat::Short,
+            "json" => ErrorFormat::Json,
             _ => ErrorFormat::Short,
         },
         None => default_value,
@@ -536,6 +661,21 @@ fn http_version(
     }
 }
 
+fn exclude_tags(arg_matches: &ArgMatches, default_value: Vec<String>) -> Vec<String> {
+    match get_string(arg_matches, "exclude_tags") {
+        Some(value) => split_tags(&value),
+        None => default_value,
+    }
+}
+
+fn dry_run(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "dry_run") {
+        true
+    } else {
+        default_value
+    }
+}
+
 fn ignore_asserts(arg_matches: &ArgMatches, default_value: bool) -> bool {
     if has_flag(arg_matches, "ignore_asserts") {
         true
@@ -552,6 +692,14 @@ fn include(arg_matches: &ArgMatches, default_value: bool) -> bool {
     }
 }
 
+fn include_secrets(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "include_secrets") {
+        true
+    } else {
+        default_value
+    }
+}
+
 /// Returns true if we have at least one input files.
 /// The input file can be a file, the standard input, or a glob (even a glob returns empty results).
 fn has_input_files(arg_matches: &ArgMatches, context: &RunContext) -> bool {
@@ -583,7 +731,11 @@ fn input_files(
     for filename in glob_files(arg_matches)? {
         files.push(filename);
     }
-    if files.is_empty() && !context.is_stdin_term() {
+    // In HAR, OpenAPI or Postman import modes, the standard input is not a Hurl file input.
+    let import_mode = get::<String>(arg_matches, "import_har").is_some()
+        || get::<String>(arg_matches, "import_openapi").is_some()
+        || get::<String>(arg_matches, "import_postman").is_some();
+    if files.is_empty() && !context.is_stdin_term() && !import_mode {
         let input = match Input::from_stdin() {
             Ok(input) => input,
             Err(err) => return Err(CliOptionsError::Error(err.to_string())),
@@ -636,10 +788,38 @@ fn junit_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Optio
         .or(default_value)
 }
 
-fn limit_rate(arg_matches: &ArgMatches, default_value: Option<BytesPerSec>) -> Option<BytesPerSec> {
-    get::<u64>(arg_matches, "limit_rate")
-        .map(BytesPerSec)
-        .or(default_value)
+fn limit_rate(
+    arg_matches: &ArgMatches,
+    default_value: Option<BytesPerSec>,
+) -> Result<Option<BytesPerSec>, CliOptionsError> {
+    match get::<String>(arg_matches, "limit_rate") {
+        Some(value) => {
+            let value = parse_limit_rate(&value)?;
+            Ok(Some(BytesPerSec(value)))
+        }
+        None => Ok(default_value),
+    }
+}
+
+/// Parses a transfer speed in bytes per second, with optional binary `k`/`m`/`g` suffixes
+/// (like curl's `--limit-rate`): `56k` is 56 * 1024 bytes per second.
+fn parse_limit_rate(value: &str) -> Result<u64, CliOptionsError> {
+    let (digits, multiplier) = match value.strip_suffix(['k', 'K']) {
+        Some(digits) => (digits, 1024),
+        None => match value.strip_suffix(['m', 'M']) {
+            Some(digits) => (digits, 1024 * 1024),
+            None => match value.strip_suffix(['g', 'G']) {
+                Some(digits) => (digits, 1024 * 1024 * 1024),
+                None => (value, 1),
+            },
+        },
+    };
+    match digits.parse::<u64>() {
+        Ok(v) => Ok(v.saturating_mul(multiplier)),
+        Err(_) => Err(CliOptionsError::Error(format!(
+            "Invalid value '{value}' for option --limit-rate: expecting a speed in bytes/second with an optional k/m/g suffix"
+        ))),
+    }
 }
 
 fn max_filesize(arg_matches: &ArgMatches, default_value: Option<u64>) -> Option<u64> {
@@ -740,6 +920,14 @@ fn no_proxy(arg_matches: &ArgMatches, default_value: Option<String>) -> Option<S
     get::<String>(arg_matches, "no_proxy").or(default_value)
 }
 
+fn no_warn_insecure(arg_matches: &ArgMatches, default_value: bool) -> bool {
+    if has_flag(arg_matches, "no_warn_insecure") {
+        true
+    } else {
+        default_value
+    }
+}
+
 fn ntlm(arg_matches: &ArgMatches, default_value: bool) -> bool {
     if has_flag(arg_matches, "ntlm") {
         true
@@ -764,6 +952,23 @@ fn output_type(arg_matches: &ArgMatches, default_value: OutputType) -> OutputTyp
     }
 }
 
+fn output_variables(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
+    get::<String>(arg_matches, "output_variables")
+        .map(PathBuf::from)
+        .or(default_value)
+}
+
+fn output_variables_format(
+    arg_matches: &ArgMatches,
+    default_value: VariablesFormat,
+) -> VariablesFormat {
+    match get::<String>(arg_matches, "output_variables_format").as_deref() {
+        Some("json") => VariablesFormat::Json,
+        Some(_) => VariablesFormat::Env,
+        None => default_value,
+    }
+}
+
 fn parallel(arg_matches: &ArgMatches, default_value: bool) -> bool {
     if has_flag(arg_matches, "parallel") || has_flag(arg_matches, "test") {
         true
@@ -839,6 +1044,28 @@ fn retry_interval(
     }
 }
 
+fn retry_on_status(
+    arg_matches: &ArgMatches,
+    default_value: Vec<u32>,
+) -> Result<Vec<u32>, CliOptionsError> {
+    let Some(s) = get::<String>(arg_matches, "retry_on_status") else {
+        return Ok(default_value);
+    };
+    let mut statuses = vec![];
+    for code in s.split(',') {
+        let code = code.trim();
+        match code.parse::<u32>() {
+            Ok(status) => statuses.push(status),
+            Err(_) => {
+                return Err(CliOptionsError::Error(format!(
+                    "Invalid status code <{code}> for --retry-on-status"
+                )));
+            }
+        }
+    }
+    Ok(statuses)
+}
+
 fn secret(
     matches: &ArgMatches,
     default_value: HashMap<String, String>,
@@ -878,6 +1105,33 @@ fn ssl_no_revoke(arg_matches: &ArgMatches, default_value: bool) -> bool {
     }
 }
 
+fn summary_format(arg_matches: &ArgMatches, default_value: SummaryFormat) -> SummaryFormat {
+    match get::<String>(arg_matches, "summary_format") {
+        Some(summary_format) => match summary_format.as_str() {
+            "text" => SummaryFormat::Text,
+            "json" => SummaryFormat::Json,
+            _ => SummaryFormat::Text,
+        },
+        None => default_value,
+    }
+}
+
+fn tags(arg_matches: &ArgMatches, default_value: Vec<String>) -> Vec<String> {
+    match get_string(arg_matches, "tags") {
+        Some(value) => split_tags(&value),
+        None => default_value,
+    }
+}
+
+/// Splits a comma-separated list of tags, ignoring empty items.
+fn split_tags(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
 fn tap_file(arg_matches: &ArgMatches, default_value: Option<PathBuf>) -> Option<PathBuf> {
     get::<String>(arg_matches, "report_tap")
         .map(PathBuf::from)
@@ -899,6 +1153,23 @@ fn timeout(arg_matches: &ArgMatches, default_value: Duration) -> Result<Duration
     }
 }
 
+fn to_curl(
+    arg_matches: &ArgMatches,
+    default_value: Option<CurlExport>,
+) -> Result<Option<CurlExport>, CliOptionsError> {
+    match get::<String>(arg_matches, "to_curl") {
+        None => Ok(default_value),
+        // `--to-curl` without value exports all the entries.
+        Some(s) if s.is_empty() => Ok(Some(CurlExport::AllEntries)),
+        Some(s) => match s.parse::<usize>() {
+            Ok(n) if n >= 1 => Ok(Some(CurlExport::Entry(n))),
+            _ => Err(CliOptionsError::Error(format!(
+                "invalid value '{s}' for '--to-curl': expecting an entry number (starting at 1)"
+            ))),
+        },
+    }
+}
+
 fn to_entry(arg_matches: &ArgMatches, default_value: Option<usize>) -> Option<usize> {
     get::<u32>(arg_matches, "to_entry")
         .map(|x| x as usize)
@@ -920,6 +1191,7 @@ fn user_agent(arg_matches: &ArgMatches, default_value: Option<String>) -> Option
 /// Returns a map of variables from the command line options `matches`.
 fn variables(
     matches: &ArgMatches,
+    context: &RunContext,
     default_value: HashMap<String, Value>,
 ) -> Result<HashMap<String, Value>, CliOptionsError> {
     let mut variables = default_value;
@@ -927,15 +1199,44 @@ fn variables(
     // Variables are typed, based on their values.
     let type_kind = TypeKind::Inferred;
 
-    // Add variables from files:
+    // Add environment variables selected by `--env-prefix`, before files and explicit
+    // `--variable` values so the latter take precedence.
+    if let Some(prefix) = get::<String>(matches, "env_prefix") {
+        for (env_name, env_value) in context.prefixed_var_env_vars(&prefix) {
+            let value = variables::parse_value(env_value, type_kind)?;
+            variables.insert(env_name.to_string(), value);
+        }
+    }
+
+    // Add variables from the environment file selected by `--env`: `--env staging` loads
+    // `staging.vars` (extension configurable with `--env-ext`), resolved against `--file-root`
+    // when set. A missing file is only a warning, unless `--require-env-file` is set.
+    if let Some(env) = get::<String>(matches, "env") {
+        let ext = get::<String>(matches, "env_ext").unwrap_or_else(|| "vars".to_string());
+        let filename = match get::<String>(matches, "file_root") {
+            Some(root) => Path::new(&root).join(format!("{env}.{ext}")),
+            None => PathBuf::from(format!("{env}.{ext}")),
+        };
+        if filename.exists() {
+            load_variables_file(&filename, type_kind, &mut variables)?;
+        } else if has_flag(matches, "require_env_file") {
+            return Err(CliOptionsError::Error(format!(
+                "Environment file {} does not exist",
+                filename.display()
+            )));
+        } else {
+            eprintln!(
+                "warning: environment file {} does not exist",
+                filename.display()
+            );
+        }
+    }
+
+    // Add variables from files, the format (`.env` properties or TOML) being detected from the
+    // file extension:
     if let Some(filenames) = get_strings(matches, "variables_file") {
         for f in &filenames {
-            let filename = Path::new(f);
-            let vars = VariablesFile::open(filename, type_kind)?;
-            for var in vars {
-                let (name, value) = var?;
-                variables.insert(name.to_string(), value);
-            }
+            load_variables_file(Path::new(f), type_kind, &mut variables)?;
         }
     }
 
@@ -950,6 +1251,28 @@ fn variables(
     Ok(variables)
 }
 
+/// Loads variables from the file `filename` into `variables`, the format (`.env` properties or
+/// TOML) being detected from the file extension.
+fn load_variables_file(
+    filename: &Path,
+    type_kind: TypeKind,
+    variables: &mut HashMap<String, Value>,
+) -> Result<(), CliOptionsError> {
+    if filename.extension().is_some_and(|ext| ext == "toml") {
+        let vars = variables_file::parse_toml(filename)?;
+        for (name, value) in vars {
+            variables.insert(name, value);
+        }
+    } else {
+        let vars = VariablesFile::open(filename, type_kind)?;
+        for var in vars {
+            let (name, value) = var?;
+            variables.insert(name.to_string(), value);
+        }
+    }
+    Ok(())
+}
+
 fn verbosity(arg_matches: &ArgMatches, default_value: Option<Verbosity>) -> Option<Verbosity> {
     match get::<String>(arg_matches, "verbosity") {
         Some(value) => Some(match value.as_str() {
@@ -26,6 +26,24 @@ pub fn input_files() -> clap::Arg {
         .num_args(1..)
 }
 
+pub fn allow_path_escape() -> clap::Arg {
+    clap::Arg::new("allow_path_escape")
+        .long("allow-path-escape")
+        .help("Allow access to files outside of the file root directory")
+        .help_heading("Other options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn assert_fail_context() -> clap::Arg {
+    clap::Arg::new("assert_fail_context")
+        .long("assert-fail-context")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u32))
+        .help("Show NUM lines of the response body around assert failures")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
 pub fn aws_sigv4() -> clap::Arg {
     clap::Arg::new("aws_sigv4")
         .long("aws-sigv4")
@@ -122,7 +140,7 @@ pub fn cookies_output_file() -> clap::Arg {
         .long("cookie-jar")
         .short('c')
         .value_name("FILE")
-        .help("Write cookies to FILE after running the session")
+        .help("Write cookies to FILE after running the session (an existing FILE seeds the cookie store)")
         .help_heading("Other options")
         .num_args(1)
 }
@@ -153,16 +171,60 @@ pub fn digest() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn dry_run() -> clap::Arg {
+    clap::Arg::new("dry_run")
+        .long("dry-run")
+        .help("Parse and validate files without running them, no HTTP request is executed")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn env() -> clap::Arg {
+    clap::Arg::new("env")
+        .long("env")
+        .value_name("NAME")
+        .help("Load variables from the environment file NAME.vars")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn env_ext() -> clap::Arg {
+    clap::Arg::new("env_ext")
+        .long("env-ext")
+        .value_name("EXT")
+        .help("Set the extension of the environment file loaded by --env [default: vars]")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn env_prefix() -> clap::Arg {
+    clap::Arg::new("env_prefix")
+        .long("env-prefix")
+        .value_name("PREFIX")
+        .help("Inject environment variables starting with PREFIX as variables, stripping the prefix")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
         .value_name("FORMAT")
-        .value_parser(["short", "long"])
+        .value_parser(["short", "long", "json"])
         .help("Control the format of error messages [default: short]")
         .help_heading("Output options")
         .num_args(1)
 }
 
+pub fn export_har() -> clap::Arg {
+    clap::Arg::new("export_har")
+        .long("export-har")
+        .value_name("FILE")
+        .help("Export the run to a HAR (HTTP Archive) file")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
 pub fn file_root() -> clap::Arg {
     clap::Arg::new("file_root")
         .long("file-root")
@@ -270,6 +332,41 @@ pub fn include() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn import_har() -> clap::Arg {
+    clap::Arg::new("import_har")
+        .long("import-har")
+        .value_name("FILE")
+        .help("Generate Hurl entries from a HAR file, no HTTP request is executed")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
+pub fn import_openapi() -> clap::Arg {
+    clap::Arg::new("import_openapi")
+        .long("import-openapi")
+        .value_name("FILE")
+        .help("Generate Hurl stub files from an OpenAPI 3 spec, no HTTP request is executed")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
+pub fn import_postman() -> clap::Arg {
+    clap::Arg::new("import_postman")
+        .long("import-postman")
+        .value_name("FILE")
+        .help("Generate Hurl files from a Postman collection, no HTTP request is executed")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
+pub fn include_secrets() -> clap::Arg {
+    clap::Arg::new("include_secrets")
+        .long("include-secrets")
+        .help("Include secret variables in the file written by --output-variables")
+        .help_heading("Output options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn insecure() -> clap::Arg {
     clap::Arg::new("insecure")
         .long("insecure")
@@ -320,8 +417,7 @@ pub fn limit_rate() -> clap::Arg {
     clap::Arg::new("limit_rate")
         .long("limit-rate")
         .value_name("SPEED")
-        .value_parser(clap::value_parser!(u64))
-        .help("Specify the maximum transfer rate in bytes/second, for both downloads and uploads")
+        .help("Specify the maximum transfer rate in bytes/second (with optional k/m/g suffixes), for both downloads and uploads")
         .help_heading("HTTP options")
         .num_args(1)
 }
@@ -407,6 +503,7 @@ pub fn no_color() -> clap::Arg {
 pub fn no_cookie_store() -> clap::Arg {
     clap::Arg::new("no_cookie_store")
         .long("no-cookie-store")
+        .alias("no-cookies")
         .help("Do not use cookie store between requests")
         .help_heading("HTTP options")
         .conflicts_with("cookies_input_file")
@@ -441,6 +538,14 @@ pub fn no_proxy() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn no_warn_insecure() -> clap::Arg {
+    clap::Arg::new("no_warn_insecure")
+        .long("no-warn-insecure")
+        .help("Do not warn when an entry allows insecure SSL connections with an [Options] section")
+        .help_heading("HTTP options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn ntlm() -> clap::Arg {
     clap::Arg::new("ntlm")
         .long("ntlm")
@@ -449,6 +554,16 @@ pub fn ntlm() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn openapi_group_by() -> clap::Arg {
+    clap::Arg::new("openapi_group_by")
+        .long("openapi-group-by")
+        .value_name("GROUP")
+        .help("Group the files generated by --import-openapi by operation tag or by path")
+        .help_heading("Other options")
+        .value_parser(["tag", "path"])
+        .num_args(1)
+}
+
 pub fn output() -> clap::Arg {
     clap::Arg::new("output")
         .long("output")
@@ -459,6 +574,34 @@ pub fn output() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn output_dir() -> clap::Arg {
+    clap::Arg::new("output_dir")
+        .long("output-dir")
+        .value_name("DIR")
+        .help("Write the files generated by --import-openapi to DIR")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
+pub fn output_variables() -> clap::Arg {
+    clap::Arg::new("output_variables")
+        .long("output-variables")
+        .value_name("FILE")
+        .help("Write all the variables at the end of the run to FILE")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
+pub fn output_variables_format() -> clap::Arg {
+    clap::Arg::new("output_variables_format")
+        .long("output-variables-format")
+        .value_name("FORMAT")
+        .help("Format of the file written by --output-variables: env or json")
+        .help_heading("Output options")
+        .value_parser(["env", "json"])
+        .num_args(1)
+}
+
 pub fn parallel() -> clap::Arg {
     clap::Arg::new("parallel")
         .long("parallel")
@@ -557,6 +700,14 @@ pub fn report_tap() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn require_env_file() -> clap::Arg {
+    clap::Arg::new("require_env_file")
+        .long("require-env-file")
+        .help("Make a missing environment file selected by --env an error")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn resolve() -> clap::Arg {
     clap::Arg::new("resolve")
         .long("resolve")
@@ -587,6 +738,15 @@ pub fn retry_interval() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn retry_on_status() -> clap::Arg {
+    clap::Arg::new("retry_on_status")
+        .long("retry-on-status")
+        .value_name("CODES")
+        .help("Retry requests when the HTTP response status code is in the comma-separated list CODES")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn secret() -> clap::Arg {
     clap::Arg::new("secret")
         .long("secret")
@@ -607,6 +767,16 @@ pub fn secrets_file() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn seed() -> clap::Arg {
+    clap::Arg::new("seed")
+        .long("seed")
+        .value_name("NUM")
+        .help("Seed the random number generator used by random_int/random_float to make runs reproducible")
+        .help_heading("Run options")
+        .value_parser(clap::value_parser!(u64))
+        .num_args(1)
+}
+
 pub fn ssl_no_revoke() -> clap::Arg {
     clap::Arg::new("ssl_no_revoke")
         .long("ssl-no-revoke")
@@ -615,6 +785,34 @@ pub fn ssl_no_revoke() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn summary_format() -> clap::Arg {
+    clap::Arg::new("summary_format")
+        .long("summary-format")
+        .value_name("FORMAT")
+        .value_parser(["text", "json"])
+        .help("Control the format of the run summary printed on completion [default: text]")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
+pub fn tags() -> clap::Arg {
+    clap::Arg::new("tags")
+        .long("tags")
+        .value_name("TAGS")
+        .help("Execute only the entries tagged with one of TAGS (comma-separated)")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn exclude_tags() -> clap::Arg {
+    clap::Arg::new("exclude_tags")
+        .long("exclude-tags")
+        .value_name("TAGS")
+        .help("Skip the entries tagged with one of TAGS (comma-separated)")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn test() -> clap::Arg {
     clap::Arg::new("test")
         .long("test")
@@ -633,6 +831,17 @@ pub fn to_entry() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn to_curl() -> clap::Arg {
+    clap::Arg::new("to_curl")
+        .long("to-curl")
+        .value_name("ENTRY_NUMBER")
+        .help("Print the curl commands of the entries without running them, optionally only the entry at ENTRY_NUMBER (starting at 1)")
+        .help_heading("Run options")
+        .num_args(0..=1)
+        .require_equals(true)
+        .default_missing_value("")
+}
+
 pub fn unix_socket() -> clap::Arg {
     clap::Arg::new("unix_socket")
         .long("unix-socket")
@@ -676,7 +885,7 @@ pub fn variables_file() -> clap::Arg {
     clap::Arg::new("variables_file")
         .long("variables-file")
         .value_name("FILE")
-        .help("Define a properties file in which you define your variables")
+        .help("Define a properties or TOML file in which you define your variables")
         .help_heading("Run options")
         .num_args(1)
         .action(clap::ArgAction::Append)
@@ -708,3 +917,17 @@ pub fn very_verbose() -> clap::Arg {
         .help_heading("Output options")
         .action(clap::ArgAction::SetTrue)
 }
+
+pub fn watch() -> clap::Arg {
+    clap::Arg::new("watch")
+        .long("watch")
+        .help("Re-run the files each time one of them changes on disk")
+        .help_heading("Run options")
+        .conflicts_with("jobs")
+        .conflicts_with("parallel")
+        .conflicts_with("report_html")
+        .conflicts_with("report_json")
+        .conflicts_with("report_junit")
+        .conflicts_with("report_tap")
+        .action(clap::ArgAction::SetTrue)
+}
@@ -136,6 +136,21 @@ impl RunContext {
             .collect()
     }
 
+    /// Returns the map of variables injected by environment variables with a custom `prefix`
+    /// (see `--env-prefix`). Returned values have their name stripped of this prefix; Hurl's
+    /// own configuration variables (like `HURL_COLOR`) are never injected.
+    pub fn prefixed_var_env_vars(&self, prefix: &str) -> HashMap<&str, &str> {
+        self.env_vars
+            .iter()
+            .filter(|(name, _)| !is_hurl_option(name))
+            .filter_map(|(name, value)| {
+                name.strip_prefix(prefix)
+                    .filter(|n| !n.is_empty())
+                    .map(|stripped| (stripped, value.as_str()))
+            })
+            .collect()
+    }
+
     /// Returns the map of Hurl secrets injected by environment variables.
     ///
     /// Environment variables are prefixed with `HURL_SECRET_` and returned values have their name
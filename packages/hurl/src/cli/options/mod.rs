@@ -23,7 +23,7 @@ mod duration;
 mod env_vars;
 mod error;
 mod secret;
-mod variables;
+pub(crate) mod variables;
 mod variables_file;
 
 use std::collections::HashMap;
@@ -47,6 +47,8 @@ pub use error::CliOptionsError;
 /// Represents the list of all options that can be used in Hurl command line.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CliOptions {
+    pub allow_path_escape: bool,
+    pub assert_fail_context: usize,
     pub aws_sigv4: Option<String>,
     pub cacert_file: Option<String>,
     pub client_cert_file: Option<String>,
@@ -60,9 +62,12 @@ pub struct CliOptions {
     pub cookie_input_file: Option<String>,
     pub cookie_output_file: Option<PathBuf>,
     pub curl_file: Option<PathBuf>,
+    pub har_file: Option<PathBuf>,
     pub delay: Duration,
     pub digest: bool,
+    pub dry_run: bool,
     pub error_format: ErrorFormat,
+    pub exclude_tags: Vec<String>,
     pub file_root: Option<String>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
@@ -71,7 +76,11 @@ pub struct CliOptions {
     pub html_dir: Option<PathBuf>,
     pub http_version: Option<HttpVersion>,
     pub ignore_asserts: bool,
+    pub import_har: Option<PathBuf>,
+    pub import_openapi: Option<PathBuf>,
+    pub import_postman: Option<PathBuf>,
     pub include: bool,
+    pub include_secrets: bool,
     pub input_files: Vec<Input>,
     pub insecure: bool,
     pub ip_resolve: Option<IpResolve>,
@@ -87,9 +96,14 @@ pub struct CliOptions {
     pub netrc_optional: bool,
     pub no_cookie_store: bool,
     pub no_proxy: Option<String>,
+    pub no_warn_insecure: bool,
     pub ntlm: bool,
+    pub openapi_group_by: OpenApiGroupBy,
     pub output: Option<Output>,
+    pub output_dir: Option<PathBuf>,
     pub output_type: OutputType,
+    pub output_variables: Option<PathBuf>,
+    pub output_variables_format: VariablesFormat,
     pub parallel: bool,
     pub path_as_is: bool,
     pub pinned_pub_key: Option<String>,
@@ -100,17 +114,50 @@ pub struct CliOptions {
     pub resolves: Vec<String>,
     pub retry: Option<Count>,
     pub retry_interval: Duration,
+    pub retry_on_status: Vec<u32>,
     pub secrets: HashMap<String, String>,
+    pub seed: Option<u64>,
     pub ssl_no_revoke: bool,
+    pub summary_format: SummaryFormat,
+    pub tags: Vec<String>,
     pub tap_file: Option<PathBuf>,
     pub test: bool,
     pub timeout: Duration,
+    pub to_curl: Option<CurlExport>,
     pub to_entry: Option<usize>,
     pub unix_socket: Option<String>,
     pub user: Option<String>,
     pub user_agent: Option<String>,
     pub variables: HashMap<String, Value>,
     pub verbosity: Option<Verbosity>,
+    pub watch: bool,
+}
+
+/// Entry selection of the `--to-curl` export.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CurlExport {
+    /// Export all the entries of the files.
+    AllEntries,
+    /// Export a single entry, identified by its 1-based index.
+    Entry(usize),
+}
+
+/// Grouping of the files generated by the `--import-openapi` import.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OpenApiGroupBy {
+    /// One file per operation tag.
+    Tag,
+    /// One file per path.
+    Path,
+}
+
+/// Format of the variables file written by `--output-variables`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VariablesFormat {
+    /// One `KEY=VALUE` line per variable, consumable by `--variables-file`.
+    Env,
+    /// A JSON object, variable names as keys.
+    Json,
 }
 
 /// Log verbosity level
@@ -121,11 +168,12 @@ pub enum Verbosity {
     Debug,
 }
 
-/// Error format: long or rich.
+/// Error format: short, long or JSON.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ErrorFormat {
     Short,
     Long,
+    Json,
 }
 
 impl From<ErrorFormat> for logger::ErrorFormat {
@@ -133,10 +181,18 @@ impl From<ErrorFormat> for logger::ErrorFormat {
         match value {
             ErrorFormat::Short => logger::ErrorFormat::Short,
             ErrorFormat::Long => logger::ErrorFormat::Long,
+            ErrorFormat::Json => logger::ErrorFormat::Json,
         }
     }
 }
 
+/// Format of the run summary printed on completion: text or JSON.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SummaryFormat {
+    Text,
+    Json,
+}
+
 /// Requested HTTP version.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum HttpVersion {
@@ -209,6 +265,8 @@ pub enum OutputType {
 impl Default for CliOptions {
     fn default() -> Self {
         CliOptions {
+            allow_path_escape: false,
+            assert_fail_context: 0,
             aws_sigv4: None,
             cacert_file: None,
             client_cert_file: None,
@@ -222,9 +280,12 @@ impl Default for CliOptions {
             cookie_input_file: None,
             cookie_output_file: None,
             curl_file: None,
+            har_file: None,
             delay: Duration::from_millis(0),
             digest: false,
+            dry_run: false,
             error_format: ErrorFormat::Short,
+            exclude_tags: Vec::new(),
             file_root: None,
             follow_location: false,
             follow_location_trusted: false,
@@ -233,7 +294,11 @@ impl Default for CliOptions {
             html_dir: None,
             http_version: None,
             ignore_asserts: false,
+            import_har: None,
+            import_openapi: None,
+            import_postman: None,
             include: false,
+            include_secrets: false,
             input_files: Vec::new(),
             insecure: false,
             ip_resolve: None,
@@ -249,9 +314,14 @@ impl Default for CliOptions {
             netrc_optional: false,
             no_cookie_store: false,
             no_proxy: None,
+            no_warn_insecure: false,
             ntlm: false,
+            openapi_group_by: OpenApiGroupBy::Tag,
             output: None,
+            output_dir: None,
             output_type: OutputType::ResponseBody,
+            output_variables: None,
+            output_variables_format: VariablesFormat::Env,
             parallel: false,
             path_as_is: false,
             pinned_pub_key: None,
@@ -262,17 +332,23 @@ impl Default for CliOptions {
             resolves: Vec::new(),
             retry: None,
             retry_interval: Duration::from_millis(1000),
+            retry_on_status: vec![],
             secrets: HashMap::new(),
+            seed: None,
             ssl_no_revoke: false,
+            summary_format: SummaryFormat::Text,
+            tags: Vec::new(),
             tap_file: None,
             test: false,
             timeout: Duration::from_secs(300),
+            to_curl: None,
             to_entry: None,
             unix_socket: None,
             user: None,
             user_agent: None,
             variables: HashMap::new(),
             verbosity: None,
+            watch: false,
         }
     }
 }
@@ -294,9 +370,17 @@ impl CliOptions {
                 InputKind::Stdin(_) => current_dir,
             },
         };
-        let context_dir = ContextDir::new(current_dir, file_root);
+        let mut context_dir = ContextDir::new(current_dir, file_root);
+        context_dir.allow_path_escape(self.allow_path_escape);
         let continue_on_error = self.continue_on_error;
-        let cookie_input_file = self.cookie_input_file.clone();
+        // `--cookie-jar` is read/write: when no explicit input file is given, an existing jar
+        // file seeds the cookie store before being overwritten at the end of the run.
+        let cookie_input_file = self.cookie_input_file.clone().or_else(|| {
+            self.cookie_output_file
+                .as_ref()
+                .filter(|file| file.exists())
+                .map(|file| file.display().to_string())
+        });
         let delay = self.delay;
         let digest = self.digest;
         let follow_location = self.follow_location;
@@ -330,7 +414,10 @@ impl CliOptions {
         let resolves = self.resolves.clone();
         let retry = self.retry;
         let retry_interval = self.retry_interval;
+        let retry_on_status = self.retry_on_status.clone();
         let ssl_no_revoke = self.ssl_no_revoke;
+        let tags = self.tags.clone();
+        let exclude_tags = self.exclude_tags.clone();
         let negotiate = self.negotiate;
         let ntlm = self.ntlm;
         let timeout = self.timeout;
@@ -339,6 +426,7 @@ impl CliOptions {
         let use_cookie_store = !self.no_cookie_store;
         let user = self.user.clone();
         let user_agent = self.user_agent.clone();
+        let warn_insecure = !self.no_warn_insecure;
 
         RunnerOptionsBuilder::new()
             .aws_sigv4(aws_sigv4)
@@ -353,6 +441,7 @@ impl CliOptions {
             .continue_on_error(continue_on_error)
             .context_dir(&context_dir)
             .cookie_input_file(cookie_input_file)
+            .exclude_tags(&exclude_tags)
             .follow_location(follow_location)
             .follow_location_trusted(follow_location_trusted)
             .from_entry(from_entry)
@@ -378,13 +467,16 @@ impl CliOptions {
             .resolves(&resolves)
             .retry(retry)
             .retry_interval(retry_interval)
+            .retry_on_status(retry_on_status)
             .ssl_no_revoke(ssl_no_revoke)
+            .tags(&tags)
             .timeout(timeout)
             .to_entry(to_entry)
             .unix_socket(unix_socket)
             .use_cookie_store(use_cookie_store)
             .user(user)
             .user_agent(user_agent)
+            .warn_insecure(warn_insecure)
             .build()
     }
 
@@ -396,6 +488,7 @@ impl CliOptions {
             Verbosity::Debug => logger::Verbosity::VeryVerbose,
         });
         LoggerOptionsBuilder::new()
+            .assert_fail_context(self.assert_fail_context)
             .color(self.color_stderr)
             .error_format(self.error_format.into())
             .verbosity(verbosity)
@@ -17,8 +17,8 @@
  */
 use crate::cli::options::variables::TypeKind;
 use crate::cli::options::{variables, CliOptionsError};
-use hurl::runner::Value;
-use std::fs::File;
+use hurl::runner::{Number, Value};
+use std::fs::{self, File};
 use std::io::{BufRead, BufReader, Lines};
 use std::iter::Enumerate;
 use std::path::{Path, PathBuf};
@@ -96,9 +96,89 @@ impl Iterator for VariablesFile {
     }
 }
 
+/// Parses the TOML variables file at `path`, returning a list of name/value pairs.
+///
+/// Top-level scalars map to string, integer, float and boolean values, arrays map to lists, and
+/// tables are flattened with a dot notation (`[db]` `host=…` gives a `db.host` variable).
+pub fn parse_toml(path: &Path) -> Result<Vec<(String, Value)>, CliOptionsError> {
+    if !path.exists() {
+        return Err(CliOptionsError::Error(format!(
+            "Variables file {} does not exist",
+            path.display()
+        )));
+    }
+    let Ok(content) = fs::read_to_string(path) else {
+        let error = CliOptionsError::Error(format!("Error opening {}", path.display()));
+        return Err(error);
+    };
+    let table = match content.parse::<toml::Value>() {
+        Ok(toml::Value::Table(table)) => table,
+        Ok(_) => {
+            let error = CliOptionsError::Error(format!(
+                "Variables file {} is not a TOML table",
+                path.display()
+            ));
+            return Err(error);
+        }
+        Err(error) => {
+            let error = CliOptionsError::Error(format!(
+                "Can not parse variables file {}: {error}",
+                path.display()
+            ));
+            return Err(error);
+        }
+    };
+    let mut variables = vec![];
+    flatten_toml_table("", &table, &mut variables)?;
+    Ok(variables)
+}
+
+/// Flattens a TOML `table` into `variables`, nested tables keys being prefixed with `prefix`.
+fn flatten_toml_table(
+    prefix: &str,
+    table: &toml::value::Table,
+    variables: &mut Vec<(String, Value)>,
+) -> Result<(), CliOptionsError> {
+    for (key, value) in table {
+        let name = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        if let toml::Value::Table(table) = value {
+            flatten_toml_table(&name, table, variables)?;
+        } else {
+            let value = toml_to_value(&name, value)?;
+            variables.push((name, value));
+        }
+    }
+    Ok(())
+}
+
+/// Converts a TOML `value` to a runner value, `name` being used if an error is returned.
+fn toml_to_value(name: &str, value: &toml::Value) -> Result<Value, CliOptionsError> {
+    match value {
+        toml::Value::String(value) => Ok(Value::String(value.clone())),
+        toml::Value::Integer(value) => Ok(Value::Number(Number::Integer(*value))),
+        toml::Value::Float(value) => Ok(Value::Number(Number::Float(*value))),
+        toml::Value::Boolean(value) => Ok(Value::Bool(*value)),
+        toml::Value::Datetime(value) => Ok(Value::String(value.to_string())),
+        toml::Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(|item| toml_to_value(name, item))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::List(items))
+        }
+        toml::Value::Table(_) => Err(CliOptionsError::Error(format!(
+            "Variable {name} can not hold a table value"
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::cli::options::variables_file::{TypeKind, VariablesFile};
+    use crate::cli::options::variables_file::{parse_toml, TypeKind, VariablesFile};
     use hurl::runner::{Number, Value};
     use std::path::PathBuf;
     use std::{env, fs};
@@ -149,4 +229,45 @@ id=123
             ]
         );
     }
+
+    #[test]
+    fn test_toml_properties() {
+        let path = temp_file("file3.toml");
+        let content = r#"foo = "bar"
+flag = true
+id = 123
+ratio = 0.5
+ids = [1, 2, 3]
+
+[db]
+host = "localhost"
+"#;
+        fs::write(&path, content).unwrap();
+        let vars = parse_toml(&path).unwrap();
+        assert_eq!(
+            vars,
+            vec![
+                ("db.host".to_string(), Value::String("localhost".to_string())),
+                ("flag".to_string(), Value::Bool(true)),
+                ("foo".to_string(), Value::String("bar".to_string())),
+                ("id".to_string(), Value::Number(Number::Integer(123))),
+                (
+                    "ids".to_string(),
+                    Value::List(vec![
+                        Value::Number(Number::Integer(1)),
+                        Value::Number(Number::Integer(2)),
+                        Value::Number(Number::Integer(3)),
+                    ])
+                ),
+                ("ratio".to_string(), Value::Number(Number::Float(0.5))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_toml_properties_error() {
+        let path = temp_file("file4.toml");
+        fs::write(&path, "foo = [").unwrap();
+        assert!(parse_toml(&path).is_err());
+    }
 }
@@ -17,12 +17,14 @@
  */
 use std::time::Duration;
 
+use hurl::runner::EntryFailure;
+
 use crate::HurlRun;
 
 /// Returns the text summary of this Hurl `runs`.
 ///
 /// This is used in `--test`mode.
-pub fn summary(runs: &[HurlRun], duration: Duration) -> String {
+pub fn summary(runs: &[HurlRun], duration: Duration, seed: u64) -> String {
     let total_files = runs.len();
     let total_requests = requests_count(runs);
     let duration_in_ms = duration.as_millis() as f64;
@@ -33,16 +35,73 @@ pub fn summary(runs: &[HurlRun], duration: Duration) -> String {
     let failed = total_files - success_files;
     let failed_percent = 100.0 * failed as f32 / total_files as f32;
     let formatted_duration = format_duration(duration);
-    format!(
+    let mut summary = format!(
         "--------------------------------------------------------------------------------\n\
              Executed files:    {total_files}\n\
              Executed requests: {total_requests} ({requests_rate:.1}/s)\n\
              Succeeded files:   {success_files} ({success_percent:.1}%)\n\
              Failed files:      {failed} ({failed_percent:.1}%)\n\
-             Duration:          {duration_in_ms} ms ({formatted_duration})\n"
+             Duration:          {duration_in_ms} ms ({formatted_duration})\n\
+             Seed:              {seed}\n"
+    );
+    let failures = failures(runs);
+    if !failures.is_empty() {
+        summary.push_str("Failed entries:\n");
+        for (filename, failure) in failures {
+            summary.push_str(&format!(
+                "  {filename}: entry {} {} {}: {}\n",
+                failure.entry_index, failure.method, failure.url, failure.message
+            ));
+        }
+    }
+    summary
+}
+
+/// Returns a one-line, machine-readable JSON summary of this Hurl `runs`.
+///
+/// This is used when `--summary-format json` is set, so that CI systems can parse the
+/// last line of the standard output.
+pub fn json_summary(runs: &[HurlRun], duration: Duration) -> String {
+    let total = runs.len();
+    let passed = runs.iter().filter(|r| r.hurl_result.success).count();
+    let failed = total - passed;
+    let duration_ms = duration.as_millis();
+    let failures = failures(runs)
+        .iter()
+        .map(|(filename, failure)| {
+            format!(
+                "{{\"file\": {}, \"entry\": {}, \"method\": {}, \"url\": {}, \"message\": {}}}",
+                encode_json_string(filename),
+                failure.entry_index,
+                encode_json_string(&failure.method),
+                encode_json_string(&failure.url),
+                encode_json_string(&failure.message),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "{{\"total\": {total}, \"passed\": {passed}, \"failed\": {failed}, \"duration_ms\": {duration_ms}, \"failures\": [{failures}]}}"
     )
 }
 
+/// Returns the failed entries of these `runs`, with the name of the file they belong to.
+fn failures(runs: &[HurlRun]) -> Vec<(String, &EntryFailure)> {
+    runs.iter()
+        .flat_map(|r| {
+            r.hurl_result
+                .failures
+                .iter()
+                .map(|f| (r.filename.to_string(), f))
+        })
+        .collect()
+}
+
+/// Encodes a string to its JSON representation, quotes included.
+fn encode_json_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
 /// Returns a formatted duration string (h:m:s:ms).
 fn format_duration(duration: Duration) -> String {
     let total_ms = duration.as_millis();
@@ -107,7 +166,7 @@ pub mod tests {
 
         let runs = vec![new_run(true, 10), new_run(true, 20), new_run(true, 4)];
         let duration = Duration::from_millis(128);
-        let s = summary(&runs, duration);
+        let s = summary(&runs, duration, 42);
         assert_eq!(
             s,
             "--------------------------------------------------------------------------------\n\
@@ -115,12 +174,13 @@ pub mod tests {
              Executed requests: 0 (0.0/s)\n\
              Succeeded files:   3 (100.0%)\n\
              Failed files:      0 (0.0%)\n\
-             Duration:          128 ms (0h:0m:0s:128ms)\n"
+             Duration:          128 ms (0h:0m:0s:128ms)\n\
+             Seed:              42\n"
         );
 
         let runs = vec![new_run(true, 10), new_run(false, 10), new_run(true, 40)];
         let duration = Duration::from_millis(200);
-        let s = summary(&runs, duration);
+        let s = summary(&runs, duration, 42);
         assert_eq!(
             s,
             "--------------------------------------------------------------------------------\n\
@@ -128,12 +188,13 @@ pub mod tests {
             Executed requests: 0 (0.0/s)\n\
             Succeeded files:   2 (66.7%)\n\
             Failed files:      1 (33.3%)\n\
-            Duration:          200 ms (0h:0m:0s:200ms)\n"
+            Duration:          200 ms (0h:0m:0s:200ms)\n\
+            Seed:              42\n"
         );
 
         let runs = vec![new_run(true, 5), new_run(true, 15)];
         let duration = Duration::from_millis(3661111);
-        let s = summary(&runs, duration);
+        let s = summary(&runs, duration, 42);
         assert_eq!(
             s,
             "--------------------------------------------------------------------------------\n\
@@ -141,7 +202,57 @@ pub mod tests {
              Executed requests: 0 (0.0/s)\n\
              Succeeded files:   2 (100.0%)\n\
              Failed files:      0 (0.0%)\n\
-             Duration:          3661111 ms (1h:1m:1s:111ms)\n"
+             Duration:          3661111 ms (1h:1m:1s:111ms)\n\
+             Seed:              42\n"
+        );
+
+        let runs = vec![new_run(true, 10), new_run(false, 10), new_run(true, 40)];
+        let duration = Duration::from_millis(3421);
+        let s = json_summary(&runs, duration);
+        assert_eq!(
+            s,
+            "{\"total\": 3, \"passed\": 2, \"failed\": 1, \"duration_ms\": 3421, \"failures\": []}"
+        );
+    }
+
+    #[test]
+    fn create_run_summary_with_failures() {
+        let run = HurlRun {
+            content: String::new(),
+            filename: Input::new("test.hurl"),
+            hurl_result: HurlResult {
+                failures: vec![EntryFailure {
+                    entry_index: Index::new(2),
+                    method: "GET".to_string(),
+                    url: "http://localhost:8000/hello".to_string(),
+                    message: "Assert failure".to_string(),
+                }],
+                ..Default::default()
+            },
+        };
+        let runs = vec![run];
+        let duration = Duration::from_millis(128);
+
+        let s = summary(&runs, duration, 42);
+        assert_eq!(
+            s,
+            "--------------------------------------------------------------------------------\n\
+             Executed files:    1\n\
+             Executed requests: 0 (0.0/s)\n\
+             Succeeded files:   0 (0.0%)\n\
+             Failed files:      1 (100.0%)\n\
+             Duration:          128 ms (0h:0m:0s:128ms)\n\
+             Seed:              42\n\
+             Failed entries:\n  \
+             test.hurl: entry 2 GET http://localhost:8000/hello: Assert failure\n"
+        );
+
+        let s = json_summary(&runs, duration);
+        assert_eq!(
+            s,
+            "{\"total\": 1, \"passed\": 0, \"failed\": 1, \"duration_ms\": 128, \"failures\": \
+             [{\"file\": \"test.hurl\", \"entry\": 2, \"method\": \"GET\", \
+             \"url\": \"http://localhost:8000/hello\", \"message\": \"Assert failure\"}]}"
         );
     }
 }
@@ -0,0 +1,226 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Import of [HAR (HTTP Archive)](http://www.softwareishard.com/blog/har-12-spec/) files.
+//!
+//! A HAR file records browser traffic as a JSON document; each HAR entry is converted to a Hurl
+//! entry preserving method, URL, request headers, request body and expected response status, so
+//! that a recorded session can be replayed as a Hurl test.
+
+/// Converts the HAR document `content` to Hurl format.
+///
+/// Cookies recorded in the HAR are inlined as `Cookie:` headers unless `inline_cookies` is false
+/// (when a cookie jar manages them at replay time). Returns an error message if `content` is not
+/// a valid HAR document.
+pub fn to_hurl(content: &str, inline_cookies: bool) -> Result<String, String> {
+    let har = serde_json::from_str::<serde_json::Value>(content)
+        .map_err(|error| format!("the HAR file is not a valid JSON document: {error}"))?;
+    let Some(entries) = har["log"]["entries"].as_array() else {
+        return Err("the HAR file has no log entries".to_string());
+    };
+    let mut s = String::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let text = entry_to_hurl(entry, inline_cookies)
+            .map_err(|message| format!("HAR entry {}: {message}", index + 1))?;
+        if !s.is_empty() {
+            s.push('\n');
+        }
+        s.push_str(&text);
+    }
+    Ok(s)
+}
+
+/// Converts a single HAR `entry` to a Hurl entry.
+fn entry_to_hurl(entry: &serde_json::Value, inline_cookies: bool) -> Result<String, String> {
+    let request = &entry["request"];
+    let Some(method) = request["method"].as_str() else {
+        return Err("the request has no method".to_string());
+    };
+    let Some(url) = request["url"].as_str() else {
+        return Err("the request has no URL".to_string());
+    };
+    let mut s = format!("{method} {url}");
+
+    let post_data = &request["postData"];
+    let mime_type = post_data["mimeType"].as_str().unwrap_or_default();
+    let form_body = mime_type.starts_with("application/x-www-form-urlencoded");
+
+    let headers = name_value_pairs(&request["headers"]);
+    let mut has_cookie_header = false;
+    for (name, value) in &headers {
+        // Entity and connection headers are managed by the HTTP client at replay time.
+        if is_managed_header(name) {
+            continue;
+        }
+        // When the body is converted to a `[Form]` section, Hurl sets the content type itself.
+        if form_body && name.eq_ignore_ascii_case("content-type") {
+            continue;
+        }
+        if name.eq_ignore_ascii_case("cookie") {
+            has_cookie_header = true;
+            if !inline_cookies {
+                continue;
+            }
+        }
+        s.push_str(&format!("\n{name}: {value}"));
+    }
+
+    // Cookies recorded outside the headers are inlined as a `Cookie:` header, unless they are
+    // already there or a cookie jar manages them.
+    let cookies = name_value_pairs(&request["cookies"]);
+    if inline_cookies && !has_cookie_header && !cookies.is_empty() {
+        let cookies = cookies
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        s.push_str(&format!("\nCookie: {cookies}"));
+    }
+
+    if let Some(text) = post_data["text"].as_str() {
+        if form_body {
+            s.push_str("\n[Form]");
+            for (name, value) in form_params(text) {
+                s.push_str(&format!("\n{name}: {value}"));
+            }
+        } else {
+            s.push_str(&format!("\n```\n{text}\n```"));
+        }
+    }
+
+    match entry["response"]["status"].as_i64() {
+        // A status of 0 records a failed exchange (aborted request, network error...), there is
+        // no expected response.
+        Some(status) if status > 0 => s.push_str(&format!("\nHTTP {status}\n")),
+        _ => s.push_str("\nHTTP *\n"),
+    }
+    Ok(s)
+}
+
+/// Returns the `(name, value)` pairs of a HAR list `value` (headers, cookies, query string...).
+fn name_value_pairs(value: &serde_json::Value) -> Vec<(String, String)> {
+    let Some(items) = value.as_array() else {
+        return vec![];
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            let name = item["name"].as_str()?;
+            let value = item["value"].as_str()?;
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Returns `true` if the header `name` is managed by the HTTP client and should not be replayed.
+fn is_managed_header(name: &str) -> bool {
+    name.eq_ignore_ascii_case("content-length")
+        || name.eq_ignore_ascii_case("host")
+        || name.eq_ignore_ascii_case("connection")
+        // Pseudo-headers of HTTP/2 recordings (`:method`, `:path`...)
+        || name.starts_with(':')
+}
+
+/// Parses an `application/x-www-form-urlencoded` body `text` to a list of `(name, value)` pairs,
+/// percent-decoded.
+fn form_params(text: &str) -> Vec<(String, String)> {
+    text.split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+            (url_decode(name), url_decode(value))
+        })
+        .collect()
+}
+
+/// Percent-decodes a form-urlencoded `value` (`+` is a space).
+fn url_decode(value: &str) -> String {
+    let value = value.replace('+', " ");
+    percent_encoding::percent_decode_str(&value)
+        .decode_utf8()
+        .map(|s| s.to_string())
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_simple_har() {
+        let har = r#"{
+          "log": {
+            "entries": [
+              {
+                "request": {
+                  "method": "GET",
+                  "url": "https://example.org/index.html",
+                  "headers": [
+                    {"name": "Host", "value": "example.org"},
+                    {"name": "Accept", "value": "text/html"}
+                  ],
+                  "cookies": [
+                    {"name": "theme", "value": "dark"},
+                    {"name": "lang", "value": "fr"}
+                  ]
+                },
+                "response": { "status": 200 }
+              },
+              {
+                "request": {
+                  "method": "POST",
+                  "url": "https://example.org/login",
+                  "headers": [],
+                  "cookies": [],
+                  "postData": {
+                    "mimeType": "application/x-www-form-urlencoded",
+                    "text": "user=bob&password=secret%21"
+                  }
+                },
+                "response": { "status": 302 }
+              }
+            ]
+          }
+        }"#;
+        assert_eq!(
+            to_hurl(har, true).unwrap(),
+            "\
+GET https://example.org/index.html
+Accept: text/html
+Cookie: theme=dark; lang=fr
+HTTP 200
+
+POST https://example.org/login
+[Form]
+user: bob
+password: secret!
+HTTP 302
+"
+        );
+
+        // With a cookie jar, cookies are not inlined.
+        assert!(!to_hurl(har, false).unwrap().contains("Cookie:"));
+    }
+
+    #[test]
+    fn import_invalid_har() {
+        assert_eq!(
+            to_hurl("{}", true).unwrap_err(),
+            "the HAR file has no log entries"
+        );
+    }
+}
@@ -35,6 +35,7 @@ pub struct Certificate {
     expire_date: Option<DateTime<Utc>>,
     serial_number: Option<String>,
     subject_alt_name: Option<String>,
+    version: Option<String>,
 }
 
 impl Certificate {
@@ -46,6 +47,7 @@ impl Certificate {
         expire_date: Option<DateTime<Utc>>,
         serial_number: Option<String>,
         subject_alt_name: Option<String>,
+        version: Option<String>,
     ) -> Self {
         Self {
             subject,
@@ -54,6 +56,7 @@ impl Certificate {
             expire_date,
             serial_number,
             subject_alt_name,
+            version,
         }
     }
 
@@ -86,6 +89,11 @@ impl Certificate {
     pub fn subject_alt_name(&self) -> Option<&String> {
         self.subject_alt_name.as_ref()
     }
+
+    /// Returns the X.509 version attribute.
+    pub fn version(&self) -> Option<&String> {
+        self.version.as_ref()
+    }
 }
 
 impl TryFrom<CertInfo> for Certificate {
@@ -104,6 +112,7 @@ impl TryFrom<CertInfo> for Certificate {
         let expire_date = parse_expire_date(&attributes);
         let serial_number = parse_serial_number(&attributes);
         let subject_alt_name = parse_subject_alt_name(&attributes);
+        let version = parse_version(&attributes);
         Ok(Certificate {
             subject,
             issuer,
@@ -111,6 +120,7 @@ impl TryFrom<CertInfo> for Certificate {
             expire_date,
             serial_number,
             subject_alt_name,
+            version,
         })
     }
 }
@@ -121,6 +131,7 @@ const START_DATE_ATTRIBUTE: &str = "start date";
 const EXPIRE_DATE_ATTRIBUTE: &str = "expire date";
 const SERIAL_NUMBER_ATTRIBUTE: &str = "serial number";
 const SUBJECT_ALT_NAME_ATTRIBUTE: &str = "x509v3 subject alternative name";
+const VERSION_ATTRIBUTE: &str = "version";
 const ATTRIBUTES: &[&str] = &[
     SUBJECT_ATTRIBUTE,
     ISSUER_ATTRIBUTE,
@@ -128,6 +139,7 @@ const ATTRIBUTES: &[&str] = &[
     EXPIRE_DATE_ATTRIBUTE,
     SERIAL_NUMBER_ATTRIBUTE,
     SUBJECT_ALT_NAME_ATTRIBUTE,
+    VERSION_ATTRIBUTE,
 ];
 
 /// Parses certificate's subject attribute.
@@ -205,6 +217,10 @@ fn parse_subject_alt_name(attributes: &HashMap<&str, &str>) -> Option<String> {
         .map(|it| it.to_string())
 }
 
+fn parse_version(attributes: &HashMap<&str, &str>) -> Option<String> {
+    attributes.get(VERSION_ATTRIBUTE).map(|it| it.to_string())
+}
+
 fn parse_attributes(data: &Vec<String>) -> HashMap<&str, &str> {
     let mut map = HashMap::new();
     for s in data {
@@ -316,6 +332,7 @@ mod tests {
                     "Expire date:Oct 30 08:29:52 2025 GMT".to_string(),
                     "x509v3 subject alternative name:DNS:localhost, IP address:127.0.0.1, IP address:0:0:0:0:0:0:0:1"
                         .to_string(),
+                    "Version:3".to_string(),
                 ]
             })
             .unwrap(),
@@ -331,7 +348,8 @@ mod tests {
                     .with_timezone(&Utc)),
                 serial_number: Some("1e:e8:b1:7f:1b:64:d8:d6:b3:de:87:01:03:d2:a4:f5:33:53:5a:b0"
                     .to_string()),
-                subject_alt_name: Some("DNS:localhost, IP address:127.0.0.1, IP address:0:0:0:0:0:0:0:1".to_string())
+                subject_alt_name: Some("DNS:localhost, IP address:127.0.0.1, IP address:0:0:0:0:0:0:0:1".to_string()),
+                version: Some("3".to_string())
             }
         );
     }
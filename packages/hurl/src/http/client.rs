@@ -35,8 +35,8 @@ use super::debug;
 use super::easy_ext;
 use super::error::HttpError;
 use super::header::{
-    Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, LOCATION,
-    USER_AGENT,
+    Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE, COOKIE,
+    EXPECT, LOCATION, USER_AGENT,
 };
 use super::ip::IpAddr;
 use super::options::{ClientOptions, Verbosity};
@@ -169,6 +169,7 @@ impl Client {
                 cookies: request_spec.cookies,
                 body,
                 implicit_content_type,
+                asterisk_form: false,
             };
         }
         Ok(calls)
@@ -205,6 +206,7 @@ impl Client {
         // of key-value.
         let mut request_body = Vec::<u8>::new();
         let mut response_body = Vec::<u8>::new();
+        let mut filesize_exceeded = false;
 
         {
             let mut transfer = self.handle.transfer();
@@ -278,12 +280,18 @@ impl Client {
             })?;
 
             if let Err(e) = transfer.perform() {
-                let code = e.code() as i32; // due to windows build
-                let description = match e.extra_description() {
-                    None => e.description().to_string(),
-                    Some(s) => s.to_string(),
-                };
-                return Err(HttpError::Libcurl { code, description });
+                // When libcurl aborts the transfer because the maximum file size is exceeded, a
+                // dedicated error is raised after this scope, where the received body is available.
+                if e.is_filesize_exceeded() {
+                    filesize_exceeded = true;
+                } else {
+                    let code = e.code() as i32; // due to windows build
+                    let description = match e.extra_description() {
+                        None => e.description().to_string(),
+                        Some(s) => s.to_string(),
+                    };
+                    return Err(HttpError::Libcurl { code, description });
+                }
             }
         }
 
@@ -295,8 +303,24 @@ impl Client {
         // > Note: before curl 8.4.0, when the file size is not known prior to download, for such files
         // > this option has no effect even if the file transfer ends up being larger than this given limit.
         if let Some(max_filesize) = options.max_filesize {
-            if response_body.len() as u64 > max_filesize {
-                return Err(HttpError::AllowedResponseSizeExceeded(max_filesize));
+            let mut actual = response_body.len() as u64;
+            if filesize_exceeded {
+                // When libcurl aborts the transfer early (from the `Content-Length` header), the
+                // body may not have been read: the announced body size is used instead.
+                let content_length = response_headers
+                    .iter()
+                    .filter_map(|h| h.split_once(':'))
+                    .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, value)| value.trim().parse::<u64>().ok());
+                if let Some(size) = content_length {
+                    actual = actual.max(size);
+                }
+            }
+            if filesize_exceeded || actual > max_filesize {
+                return Err(HttpError::AllowedResponseSizeExceeded {
+                    limit: max_filesize,
+                    actual,
+                });
             }
         }
 
@@ -330,6 +354,7 @@ impl Client {
             url,
             certificate,
             ip_addr,
+            timings.clone(),
         );
 
         if verbose {
@@ -429,21 +454,27 @@ impl Client {
             self.handle.cainfo(cacert_file)?;
             self.handle.ssl_cert_type("PEM")?;
         }
+        if let Some(cacert_append_file) = &options.cacert_append_file {
+            let blob = cacert_append_blob(cacert_append_file)?;
+            self.handle.ssl_cainfo_blob(&blob)?;
+            self.handle.ssl_cert_type("PEM")?;
+        }
         if let Some(client_cert_file) = &options.client_cert_file {
             match parse_cert_password(client_cert_file) {
                 (cert, Some(password)) => {
+                    self.handle.ssl_cert_type(cert_file_format(&cert))?;
                     self.handle.ssl_cert(cert)?;
                     self.handle.key_password(&password)?;
                 }
                 (cert, None) => {
+                    self.handle.ssl_cert_type(cert_file_format(&cert))?;
                     self.handle.ssl_cert(cert)?;
                 }
             }
-            self.handle.ssl_cert_type("PEM")?;
         }
         if let Some(client_key_file) = &options.client_key_file {
             self.handle.ssl_key(client_key_file)?;
-            self.handle.ssl_cert_type("PEM")?;
+            self.handle.ssl_key_type(cert_file_format(client_key_file))?;
         }
         self.handle.path_as_is(options.path_as_is)?;
         if let Some(proxy) = &options.proxy {
@@ -501,11 +532,27 @@ impl Client {
         self.handle.url(url.as_str())?;
         let method = &request_spec.method;
         self.set_method(method)?;
+        if request_spec.asterisk_form {
+            easy_ext::request_target(&mut self.handle, "*")?;
+        }
         self.set_cookies(&request_spec.cookies)?;
         self.set_form(&request_spec.form)?;
         self.set_multipart(&request_spec.multipart)?;
-        let request_spec_body = &request_spec.body.bytes();
-        self.set_body(request_spec_body)?;
+        let request_spec_body = request_spec.body.bytes();
+        let request_spec_body = match options.compress {
+            Some(encoding) if !request_spec_body.is_empty() => {
+                let compressed = encoding.encode(&request_spec_body)?;
+                logger.debug(&format!(
+                    "Request body compressed with {} ({} bytes uncompressed, {} bytes sent)",
+                    encoding.as_str(),
+                    request_spec_body.len(),
+                    compressed.len()
+                ));
+                compressed
+            }
+            _ => request_spec_body,
+        };
+        self.set_body(&request_spec_body)?;
         // TODO: do we want to manage the headers with no content? There are two type of no-content
         // headers: `foo:` and `foo;`. The first one can be used to remove libcurl headers (`Host:`)
         // while the second one is used to send an empty header.
@@ -515,9 +562,16 @@ impl Client {
             .iter()
             .map(|h| h.as_str())
             .collect::<Vec<&str>>();
-        let headers = &request_spec.headers.with_raw_headers(&options_headers);
+        let mut headers = request_spec.headers.with_raw_headers(&options_headers);
+        // When the request body is sent compressed, we advertise the encoding, unless the user
+        // has already set the header explicitly.
+        if let Some(encoding) = options.compress {
+            if !request_spec_body.is_empty() && !headers.contains_key(CONTENT_ENCODING) {
+                headers.push(Header::new(CONTENT_ENCODING, encoding.as_str()));
+            }
+        }
         self.set_headers(
-            headers,
+            &headers,
             request_spec.implicit_content_type.as_deref(),
             options,
         )?;
@@ -686,13 +740,16 @@ impl Client {
                     MultipartParam::FileParam(FileParam {
                         name,
                         filename,
+                        filename_override,
                         data,
                         content_type,
-                    }) => form
-                        .part(name)
-                        .buffer(filename, data.clone())
-                        .content_type(content_type)
-                        .add()?,
+                    }) => {
+                        let filename = filename_override.as_ref().unwrap_or(filename);
+                        form.part(name)
+                            .buffer(filename, data.clone())
+                            .content_type(content_type)
+                            .add()?;
+                    }
                 }
             }
             self.handle.httppost(form)?;
@@ -980,6 +1037,40 @@ fn to_list(items: &[String]) -> Result<List, Error> {
     Ok(list)
 }
 
+/// Builds a CA certificate bundle from the default CA certificates, with the PEM file
+/// `cacert_append_file` appended to them. The default certificates are read from the CA bundle
+/// that libcurl has been built with; when libcurl doesn't expose one, the bundle contains only
+/// the appended file.
+fn cacert_append_blob(cacert_append_file: &str) -> Result<Vec<u8>, HttpError> {
+    let mut blob = vec![];
+    if let Some(cainfo) = Version::get().cainfo() {
+        if let Ok(bundle) = std::fs::read(cainfo) {
+            blob.extend(bundle);
+            blob.push(b'\n');
+        }
+    }
+    let pem =
+        std::fs::read(cacert_append_file).map_err(|e| HttpError::CouldNotReadCaCertificate {
+            path: cacert_append_file.to_string(),
+            description: e.to_string(),
+        })?;
+    blob.extend(pem);
+    Ok(blob)
+}
+
+/// Returns the certificate/key format expected by libcurl, inferred from the file extension:
+/// `.der` files are DER encoded, everything else is assumed to be PEM.
+fn cert_file_format(filename: &str) -> &'static str {
+    if std::path::Path::new(filename)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("der"))
+    {
+        "DER"
+    } else {
+        "PEM"
+    }
+}
+
 /// Parses a cert file name, with a potential user provided password, and returns a pair of
 /// cert file name, password.
 /// See <https://curl.se/docs/manpage.html#-E>
@@ -1244,6 +1335,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cert_format_from_extension() {
+        assert_eq!(cert_file_format("client.pem"), "PEM");
+        assert_eq!(cert_file_format("client.der"), "DER");
+        assert_eq!(cert_file_format("client.DER"), "DER");
+        assert_eq!(cert_file_format("client"), "PEM");
+    }
+
     #[test]
     fn test_to_curl_headers() {
         let mut headers = HeaderVec::new();
@@ -67,6 +67,11 @@ impl CurlCmd {
         let mut params = method_params(request_spec, options.follow_location);
         args.append(&mut params);
 
+        if request_spec.asterisk_form {
+            args.push("--request-target".to_string());
+            args.push("'*'".to_string());
+        }
+
         let options_headers = options
             .headers
             .iter()
@@ -368,11 +373,15 @@ impl MultipartParam {
             MultipartParam::FileParam(FileParam {
                 name,
                 filename,
+                filename_override,
                 content_type,
                 ..
             }) => {
                 let path = context_dir.resolved_path(Path::new(filename));
-                let value = format!("@{};type={}", path.to_string_lossy(), content_type);
+                let mut value = format!("@{};type={}", path.to_string_lossy(), content_type);
+                if let Some(filename) = filename_override {
+                    value.push_str(&format!(";filename={filename}"));
+                }
                 format!("{name}={value}")
             }
         }
@@ -674,9 +683,11 @@ mod tests {
         let options = ClientOptions {
             allow_reuse: true,
             aws_sigv4: None,
+            cacert_append_file: None,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
+            compress: None,
             compressed: true,
             connect_timeout: Duration::from_secs(20),
             connects_to: vec!["example.com:443:host-47.example.com:443".to_string()],
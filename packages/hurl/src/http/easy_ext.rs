@@ -34,6 +34,8 @@ const CURLINFO_STARTTRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 54;
 const CURLINFO_APPCONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 56;
 const CURLINFO_CONN_ID: CURLINFO = CURLINFO_OFF_T + 64;
 
+const CURLOPT_REQUEST_TARGET: curl_sys::CURLoption = 10266;
+
 /// Represents certificate information.
 /// `data` has format "name:content";
 #[derive(Clone)]
@@ -183,6 +185,15 @@ pub fn total_time_t(easy: &mut Easy) -> Result<Duration, Error> {
     getopt_off_t(easy, CURLINFO_TOTAL_TIME_T).map(microseconds_to_duration)
 }
 
+/// Sends `target` as the request-target, instead of extracting it from the URL.
+/// Corresponds to [`CURLOPT_REQUEST_TARGET`].
+pub fn request_target(easy: &mut Easy, target: &str) -> Result<(), Error> {
+    let target = CString::new(target)?;
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_REQUEST_TARGET, target.as_ptr())
+    })
+}
+
 /// Read .netrc information from a file.
 pub fn netrc_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
     let filename = CString::new(filename)?;
@@ -19,8 +19,15 @@ use super::request::RequestedHttpVersion;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HttpError {
+    CouldNotCompressRequest {
+        description: String,
+    },
     CouldNotParseCookieExpires(String),
     CouldNotParseResponse,
+    CouldNotReadCaCertificate {
+        path: String,
+        description: String,
+    },
     CouldNotUncompressResponse {
         description: String,
     },
@@ -49,7 +56,10 @@ pub enum HttpError {
     /// The maximum response size has been exceeded.
     /// This error can be raised even if libcurl has been configured to respect a given maximum
     /// file size.
-    AllowedResponseSizeExceeded(u64),
+    AllowedResponseSizeExceeded {
+        limit: u64,
+        actual: u64,
+    },
 }
 
 impl From<curl::Error> for HttpError {
@@ -71,9 +81,11 @@ impl From<curl::FormError> for HttpError {
 impl HttpError {
     pub fn description(&self) -> String {
         match self {
-            HttpError::AllowedResponseSizeExceeded(_) => "HTTP connection".to_string(),
+            HttpError::AllowedResponseSizeExceeded { .. } => "HTTP connection".to_string(),
+            HttpError::CouldNotCompressRequest { .. } => "Compression error".to_string(),
             HttpError::CouldNotParseCookieExpires(_) => "HTTP connection".to_string(),
             HttpError::CouldNotParseResponse => "HTTP connection".to_string(),
+            HttpError::CouldNotReadCaCertificate { .. } => "SSL certificate".to_string(),
             HttpError::CouldNotUncompressResponse { .. } => "Decompression error".to_string(),
             HttpError::InvalidCharset { .. } => "Invalid charset".to_string(),
             HttpError::InvalidDecoding { .. } => "Invalid decoding".to_string(),
@@ -89,13 +101,19 @@ impl HttpError {
 
     pub fn message(&self) -> String {
         match self {
-            HttpError::AllowedResponseSizeExceeded(max_size) => {
-                format!("exceeded the maximum allowed file size ({max_size} bytes)")
+            HttpError::AllowedResponseSizeExceeded { limit, .. } => {
+                format!("exceeded the maximum allowed file size ({limit} bytes)")
+            }
+            HttpError::CouldNotCompressRequest { description } => {
+                format!("could not compress request body with {description}")
             }
             HttpError::CouldNotParseCookieExpires(value) => {
                 format!("could not parse Cookie Expires attribute value <{value}>")
             }
             HttpError::CouldNotParseResponse => "could not parse Response".to_string(),
+            HttpError::CouldNotReadCaCertificate { path, description } => {
+                format!("could not read CA certificate file <{path}> ({description})")
+            }
             HttpError::CouldNotUncompressResponse { description } => {
                 format!("could not uncompress response with {description}")
             }
@@ -34,6 +34,7 @@ pub use self::request::{IpResolve, Request, RequestedHttpVersion};
 pub(crate) use self::request_cookie::RequestCookie;
 pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
 pub use self::response::{HttpVersion, Response};
+pub use self::response_decoding::ContentEncoding;
 pub use self::response_cookie::{CookieAttribute, ResponseCookie};
 #[cfg(test)]
 pub use self::tests::*;
@@ -52,7 +53,7 @@ mod error;
 mod header;
 mod headers_helper;
 mod ip;
-mod mimetype;
+pub(crate) mod mimetype;
 mod options;
 mod param;
 mod request;
@@ -20,6 +20,7 @@ use std::time::Duration;
 use hurl_core::types::{BytesPerSec, Count};
 
 use super::request::{IpResolve, RequestedHttpVersion};
+use super::response_decoding::ContentEncoding;
 
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
@@ -29,9 +30,12 @@ pub struct ClientOptions {
     /// and [`CURLOPT_FORBID_REUSE`](https://curl.se/libcurl/c/CURLOPT_FORBID_REUSE.html).
     pub allow_reuse: bool,
     pub aws_sigv4: Option<String>,
+    pub cacert_append_file: Option<String>,
     pub cacert_file: Option<String>,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
+    /// Compresses the request body with the given algorithm before sending it.
+    pub compress: Option<ContentEncoding>,
     pub compressed: bool,
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
@@ -79,9 +83,11 @@ impl Default for ClientOptions {
         ClientOptions {
             allow_reuse: true,
             aws_sigv4: None,
+            cacert_append_file: None,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
+            compress: None,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
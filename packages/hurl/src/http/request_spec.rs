@@ -39,6 +39,10 @@ pub struct RequestSpec {
     /// implicit content type can be different from the user provided one through the `headers`
     /// field.
     pub implicit_content_type: Option<String>,
+    /// When `true`, the request is sent with the literal `*` as request-target (the asterisk form
+    /// of a server-wide `OPTIONS` request). The `url` field then only carries the origin used to
+    /// connect to the server.
+    pub asterisk_form: bool,
 }
 
 impl Default for RequestSpec {
@@ -53,6 +57,7 @@ impl Default for RequestSpec {
             cookies: vec![],
             body: Body::Binary(vec![]),
             implicit_content_type: None,
+            asterisk_form: false,
         }
     }
 }
@@ -70,6 +75,9 @@ pub enum MultipartParam {
 pub struct FileParam {
     pub name: String,
     pub filename: String,
+    /// Filename announced in the part's `Content-Disposition` header, when overridden with a
+    /// `filename:` annotation (the source file name is used otherwise).
+    pub filename_override: Option<String>,
     pub data: Vec<u8>,
     pub content_type: String,
 }
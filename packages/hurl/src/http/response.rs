@@ -21,6 +21,7 @@ use std::time::Duration;
 use super::certificate::Certificate;
 use super::header::HeaderVec;
 use super::ip::IpAddr;
+use super::timings::Timings;
 use super::url::Url;
 
 /// Represents a runtime HTTP response.
@@ -36,6 +37,8 @@ pub struct Response {
     /// The end-user certificate, in the response certificate chain
     pub certificate: Option<Certificate>,
     pub ip_addr: IpAddr,
+    /// Timings of the transfer phases, see <https://hurl.dev/docs/response.html#timings>
+    pub timings: Timings,
 }
 
 impl Response {
@@ -50,6 +53,7 @@ impl Response {
         url: Url,
         certificate: Option<Certificate>,
         ip_addr: IpAddr,
+        timings: Timings,
     ) -> Self {
         Response {
             version,
@@ -60,6 +64,7 @@ impl Response {
             url,
             certificate,
             ip_addr,
+            timings,
         }
     }
 }
@@ -104,6 +109,7 @@ mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            timings: Default::default(),
         };
         assert_eq!(response.headers.values("Content-Length"), vec!["12"]);
         assert!(response.headers.values("Unknown").is_empty());
@@ -61,6 +61,26 @@ impl ContentEncoding {
             ContentEncoding::Brotli => uncompress_brotli(data),
         }
     }
+
+    /// Compresses `data` bytes, used to send a compressed request body.
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8>, HttpError> {
+        match self {
+            ContentEncoding::Identity => Ok(data.to_vec()),
+            ContentEncoding::Gzip => compress_gzip(data),
+            ContentEncoding::Deflate => compress_zlib(data),
+            ContentEncoding::Brotli => compress_brotli(data),
+        }
+    }
+
+    /// Returns the HTTP header value of this encoding.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentEncoding::Brotli => "br",
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Deflate => "deflate",
+            ContentEncoding::Identity => "identity",
+        }
+    }
 }
 
 impl Response {
@@ -107,6 +127,42 @@ impl Response {
     }
 }
 
+/// Compresses `data` with Brotli.
+fn compress_brotli(data: &[u8]) -> Result<Vec<u8>, HttpError> {
+    let buffer_size = 4096;
+    let quality = 11;
+    let lgwin = 22;
+    let mut buf = Vec::new();
+    let mut writer = brotli::CompressorWriter::new(&mut buf, buffer_size, quality, lgwin);
+    if writer.write_all(data).is_err() {
+        return Err(HttpError::CouldNotCompressRequest {
+            description: "brotli".to_string(),
+        });
+    }
+    drop(writer);
+    Ok(buf)
+}
+
+/// Compresses `data` with GZip.
+fn compress_gzip(data: &[u8]) -> Result<Vec<u8>, HttpError> {
+    let error = || HttpError::CouldNotCompressRequest {
+        description: "gzip".to_string(),
+    };
+    let mut encoder = libflate::gzip::Encoder::new(Vec::new()).map_err(|_| error())?;
+    encoder.write_all(data).map_err(|_| error())?;
+    encoder.finish().into_result().map_err(|_| error())
+}
+
+/// Compresses `data` with Zlib.
+fn compress_zlib(data: &[u8]) -> Result<Vec<u8>, HttpError> {
+    let error = || HttpError::CouldNotCompressRequest {
+        description: "zlib".to_string(),
+    };
+    let mut encoder = libflate::zlib::Encoder::new(Vec::new()).map_err(|_| error())?;
+    encoder.write_all(data).map_err(|_| error())?;
+    encoder.finish().into_result().map_err(|_| error())
+}
+
 /// Decompresses Brotli compressed `data`.
 fn uncompress_brotli(data: &[u8]) -> Result<Vec<u8>, HttpError> {
     let buffer_size = 4096;
@@ -173,6 +229,7 @@ pub mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            timings: Default::default(),
         }
     }
 
@@ -190,6 +247,24 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_content_encoding() {
+        let data = b"Hello World! Hello World! Hello World!";
+
+        // The raw bytes on the wire are a valid gzip stream (0x1f 0x8b magic number).
+        let bytes = ContentEncoding::Gzip.encode(data).unwrap();
+        assert_eq!(&bytes[0..2], &[0x1f, 0x8b]);
+        assert_eq!(ContentEncoding::Gzip.decode(&bytes).unwrap(), data);
+
+        let bytes = ContentEncoding::Deflate.encode(data).unwrap();
+        assert_eq!(ContentEncoding::Deflate.decode(&bytes).unwrap(), data);
+
+        let bytes = ContentEncoding::Brotli.encode(data).unwrap();
+        assert_eq!(ContentEncoding::Brotli.decode(&bytes).unwrap(), data);
+
+        assert_eq!(ContentEncoding::Identity.encode(data).unwrap(), data);
+    }
+
     #[test]
     fn test_content_encoding() {
         let response = default_response();
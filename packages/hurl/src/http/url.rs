@@ -138,6 +138,24 @@ impl Url {
         self.inner.path()
     }
 
+    /// Returns the origin of this URL (scheme, host and port), as a new URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use hurl::http::Url;
+    ///
+    /// let url = Url::from_str("http://foo.com:8000/index.html?q=1").unwrap();
+    /// assert_eq!(url.origin().raw(), "http://foo.com:8000");
+    /// ```
+    pub fn origin(&self) -> Url {
+        let origin = self.inner.origin().ascii_serialization();
+        origin
+            .parse()
+            .expect("the origin of an HTTP or HTTPS URL is a valid URL")
+    }
+
     /// Parse a string `input` as an URL, with this URL as the base URL.
     pub fn join(&self, input: &str) -> Result<Url, UrlError> {
         let new_inner = self.inner.join(input);
@@ -0,0 +1,164 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use hurl_core::input::Input;
+
+use crate::runner;
+use crate::runner::{HurlResult, RunnerOptions, Value, VariableSet};
+use crate::util::logger::{LoggerOptions, LoggerOptionsBuilder};
+
+/// A runner to embed Hurl in Rust programs, without going through the Hurl binary.
+///
+/// A [`HurlRunner`] wraps [`runner::run`]: it holds the [`RunnerOptions`] and variables of a run,
+/// and exposes [`HurlRunner::run_string`] and [`HurlRunner::run_file`] to run Hurl content. It
+/// never calls [`std::process::exit`]; the success or failure of a run is read in the returned
+/// [`HurlResult`] `success` field.
+///
+/// # Example
+///
+/// ```no_run
+/// use hurl::runner::RunnerOptions;
+/// use hurl::HurlRunner;
+///
+/// let runner = HurlRunner::new(RunnerOptions::default());
+/// let result = runner.run_string("GET http://localhost:8000\nHTTP 200\n").unwrap();
+/// assert!(result.success);
+/// ```
+pub struct HurlRunner {
+    runner_options: RunnerOptions,
+    logger_options: LoggerOptions,
+    variables: VariableSet,
+}
+
+impl HurlRunner {
+    /// Creates a new runner with the given `options`.
+    ///
+    /// ```
+    /// use hurl::runner::RunnerOptionsBuilder;
+    /// use hurl::HurlRunner;
+    ///
+    /// let options = RunnerOptionsBuilder::new().follow_location(true).build();
+    /// let runner = HurlRunner::new(options);
+    /// ```
+    pub fn new(options: RunnerOptions) -> Self {
+        HurlRunner {
+            runner_options: options,
+            logger_options: LoggerOptionsBuilder::new().build(),
+            variables: VariableSet::new(),
+        }
+    }
+
+    /// Sets a variable `name` to `value`, usable in the Hurl content as `{{name}}`.
+    ///
+    /// ```
+    /// use hurl::runner::{RunnerOptions, Value};
+    /// use hurl::HurlRunner;
+    ///
+    /// let mut runner = HurlRunner::new(RunnerOptions::default());
+    /// runner.set_variable("endpoint", Value::String("http://localhost:8000".to_string()));
+    /// let result = runner.run_string("GET {{endpoint}}\nHTTP 200\n").unwrap();
+    /// assert!(result.success);
+    /// ```
+    pub fn set_variable(&mut self, name: &str, value: Value) {
+        self.variables.insert(name.to_string(), value);
+    }
+
+    /// Sets the logger options of this runner (quiet by default).
+    pub fn set_logger_options(&mut self, options: LoggerOptions) {
+        self.logger_options = options;
+    }
+
+    /// Runs a Hurl `content` and returns a [`HurlResult`] upon completion.
+    ///
+    /// An error is returned only if `content` is not a syntactically correct Hurl file: assert
+    /// failures and runtime failures are reported in the [`HurlResult`] itself.
+    ///
+    /// ```
+    /// use hurl::runner::RunnerOptions;
+    /// use hurl::HurlRunner;
+    ///
+    /// let runner = HurlRunner::new(RunnerOptions::default());
+    /// let result = runner.run_string("GET http://localhost:8000\nHTTP 200\n").unwrap();
+    /// assert!(result.success);
+    ///
+    /// assert!(runner.run_string("this is not a Hurl file").is_err());
+    /// ```
+    pub fn run_string(&self, content: &str) -> Result<HurlResult, HurlRunnerError> {
+        runner::run(
+            content,
+            None,
+            &self.runner_options,
+            &self.variables,
+            &self.logger_options,
+        )
+        .map_err(HurlRunnerError::Parse)
+    }
+
+    /// Runs the Hurl file `path` and returns a [`HurlResult`] upon completion.
+    ///
+    /// ```no_run
+    /// use hurl::runner::RunnerOptions;
+    /// use hurl::HurlRunner;
+    ///
+    /// let runner = HurlRunner::new(RunnerOptions::default());
+    /// let result = runner.run_file("tests/hello.hurl").unwrap();
+    /// assert!(result.success);
+    /// ```
+    pub fn run_file<P: AsRef<Path>>(&self, path: P) -> Result<HurlResult, HurlRunnerError> {
+        let filename = Input::from(path.as_ref());
+        let content = filename.read_to_string().map_err(HurlRunnerError::Io)?;
+        runner::run(
+            &content,
+            Some(&filename),
+            &self.runner_options,
+            &self.variables,
+            &self.logger_options,
+        )
+        .map_err(HurlRunnerError::Parse)
+    }
+}
+
+/// The error type of a [`HurlRunner`] run: either the input file can't be read, or the content is
+/// not a syntactically correct Hurl file.
+#[derive(Debug)]
+pub enum HurlRunnerError {
+    Io(io::Error),
+    Parse(String),
+}
+
+impl fmt::Display for HurlRunnerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HurlRunnerError::Io(error) => write!(f, "{error}"),
+            HurlRunnerError::Parse(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl Error for HurlRunnerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HurlRunnerError::Io(error) => Some(error),
+            HurlRunnerError::Parse(_) => None,
+        }
+    }
+}
@@ -282,6 +282,7 @@ fn load_testcases() -> Vec<TestCase> {
 }
 
 #[test]
+#[allow(clippy::result_large_err)]
 fn run() {
     let testcases = load_testcases();
     let testcases = testcases.iter();
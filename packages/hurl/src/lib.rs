@@ -40,9 +40,11 @@
 //! This crate works on Windows, macOS and Linux.
 
 mod html;
+mod hurl_runner;
 pub mod http;
 mod json;
 mod jsonpath;
+pub mod linter;
 pub mod output;
 #[doc(hidden)]
 pub mod parallel;
@@ -50,3 +52,5 @@ pub mod pretty;
 pub mod report;
 pub mod runner;
 pub mod util;
+
+pub use hurl_runner::{HurlRunner, HurlRunnerError};
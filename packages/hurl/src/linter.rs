@@ -0,0 +1,532 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! A static linter for Hurl files.
+//!
+//! Beyond pure syntax validation (see `--dry-run`), the linter flags style and semantic issues
+//! that are likely mistakes: duplicate headers, assertions that can never pass, references to
+//! variables that are never defined, etc. Each rule has a stable `Lxxx` code and can be
+//! suppressed for a whole file with a `# hurl-lint-ignore L001 L002` comment.
+//!
+//! The linter works on the parsed AST and never executes any HTTP request, so rules relying on
+//! runtime state are best effort: for instance, a variable injected with `--variable` at run
+//! time is reported as undefined ([`LintRule::UndefinedVariable`] can be suppressed in this
+//! case).
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use hurl_core::ast::visit::Visitor;
+use hurl_core::ast::{
+    BindingExpr, Entry, ExprKind, FilterValue, HurlFile, Number, OptionKind, Placeholder,
+    PredicateFuncValue, PredicateValue, QueryValue, SectionValue, SourceInfo, Template,
+    TemplateElement,
+};
+use hurl_core::reader::Pos;
+
+/// Identifies a lint rule, with a stable code used in reports and in `# hurl-lint-ignore`
+/// comments.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LintRule {
+    /// A header name is set more than once in the same request.
+    DuplicateHeader,
+    /// A `Content-Type` header is set on a request without a body.
+    ContentTypeWithoutBody,
+    /// A `status` assert compares to a value outside the valid HTTP status range.
+    ImpossibleStatus,
+    /// A template references a variable that is never defined in the file.
+    UndefinedVariable,
+    /// A file bound in the `[Bindings]` section does not exist (opt-in, strict mode only).
+    MissingBoundFile,
+    /// A deprecated spelling is used (`includes`, `format`, `decode`).
+    DeprecatedSpelling,
+    /// A line ends with spaces or tabs (fixed by `--fix`).
+    TrailingWhitespace,
+}
+
+impl LintRule {
+    /// Returns the stable code of this rule.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LintRule::DuplicateHeader => "L001",
+            LintRule::ContentTypeWithoutBody => "L002",
+            LintRule::ImpossibleStatus => "L003",
+            LintRule::UndefinedVariable => "L004",
+            LintRule::MissingBoundFile => "L005",
+            LintRule::DeprecatedSpelling => "L006",
+            LintRule::TrailingWhitespace => "L007",
+        }
+    }
+}
+
+/// A single issue reported by the linter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintIssue {
+    pub rule: LintRule,
+    pub message: String,
+    pub source_info: SourceInfo,
+}
+
+impl LintIssue {
+    fn new(rule: LintRule, message: String, source_info: SourceInfo) -> LintIssue {
+        LintIssue {
+            rule,
+            message,
+            source_info,
+        }
+    }
+}
+
+/// Lints a parsed `hurl_file`, returning issues sorted by source position.
+///
+/// `content` is the source string that `hurl_file` was parsed from: it's used for pure style
+/// rules and to collect `# hurl-lint-ignore` comments. When `strict_file_bindings` is set,
+/// bound files that don't exist yet are reported ([`LintRule::MissingBoundFile`]): this is
+/// opt-in because bound files are created on the first write.
+pub fn lint(hurl_file: &HurlFile, content: &str, strict_file_bindings: bool) -> Vec<LintIssue> {
+    let mut issues = vec![];
+    for entry in &hurl_file.entries {
+        check_duplicate_headers(entry, &mut issues);
+        check_content_type_without_body(entry, &mut issues);
+        check_impossible_status(entry, &mut issues);
+        check_deprecated_spellings(entry, &mut issues);
+    }
+    check_undefined_variables(hurl_file, &mut issues);
+    if strict_file_bindings {
+        check_bound_files(hurl_file, &mut issues);
+    }
+    check_trailing_whitespace(content, &mut issues);
+
+    let ignored = ignored_rules(content);
+    issues.retain(|issue| !ignored.contains(issue.rule.code()));
+    issues.sort_by_key(|issue| issue.source_info.start);
+    issues
+}
+
+/// Fixes the style issues of `content`, returning the corrected source string.
+///
+/// Only style issues are fixed (trailing whitespace for now), semantic issues are left for the
+/// user.
+pub fn fix(content: &str) -> String {
+    let mut s = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let (text, eol) = match line.strip_suffix("\r\n") {
+            Some(text) => (text, "\r\n"),
+            None => match line.strip_suffix('\n') {
+                Some(text) => (text, "\n"),
+                None => (line, ""),
+            },
+        };
+        s.push_str(text.trim_end_matches([' ', '\t']));
+        s.push_str(eol);
+    }
+    s
+}
+
+/// Returns the rule codes suppressed by `# hurl-lint-ignore` comments in `content`.
+///
+/// A suppression applies to the whole file, codes can be separated by spaces or commas.
+fn ignored_rules(content: &str) -> HashSet<String> {
+    let mut codes = HashSet::new();
+    for line in content.lines() {
+        let Some(comment) = line.trim_start().strip_prefix('#') else {
+            continue;
+        };
+        let Some(rest) = comment.trim_start().strip_prefix("hurl-lint-ignore") else {
+            continue;
+        };
+        for code in rest.split([' ', ',']).filter(|c| !c.is_empty()) {
+            codes.insert(code.to_string());
+        }
+    }
+    codes
+}
+
+/// Returns the literal value of `template` if it doesn't contain any placeholder.
+fn literal_value(template: &Template) -> Option<String> {
+    if template
+        .elements
+        .iter()
+        .any(|e| matches!(e, TemplateElement::Placeholder(_)))
+    {
+        return None;
+    }
+    Some(template.to_string())
+}
+
+/// Checks that no header name is set more than once in the request of this `entry` (L001).
+fn check_duplicate_headers(entry: &Entry, issues: &mut Vec<LintIssue>) {
+    let mut seen = HashMap::new();
+    for header in &entry.request.headers {
+        let Some(name) = literal_value(&header.key) else {
+            continue;
+        };
+        if let Some(first) = seen.insert(name.to_lowercase(), name.clone()) {
+            let message = format!("header <{first}> is set more than once in this request");
+            issues.push(LintIssue::new(
+                LintRule::DuplicateHeader,
+                message,
+                header.key.source_info,
+            ));
+        }
+    }
+}
+
+/// Checks that a `Content-Type` header comes with a request body (L002).
+fn check_content_type_without_body(entry: &Entry, issues: &mut Vec<LintIssue>) {
+    if entry.request.body.is_some() {
+        return;
+    }
+    // Form and GraphQL sections produce an implicit body.
+    let has_implicit_body = entry.request.sections.iter().any(|s| {
+        matches!(
+            s.value,
+            SectionValue::FormParams(..)
+                | SectionValue::MultipartFormData(..)
+                | SectionValue::GraphQl(..)
+        )
+    });
+    if has_implicit_body {
+        return;
+    }
+    for header in &entry.request.headers {
+        let Some(name) = literal_value(&header.key) else {
+            continue;
+        };
+        if name.eq_ignore_ascii_case("Content-Type") {
+            let message = "header <Content-Type> is set on a request without a body".to_string();
+            issues.push(LintIssue::new(
+                LintRule::ContentTypeWithoutBody,
+                message,
+                header.key.source_info,
+            ));
+        }
+    }
+}
+
+/// Checks that `status` asserts compare to a valid HTTP status code (L003).
+fn check_impossible_status(entry: &Entry, issues: &mut Vec<LintIssue>) {
+    let Some(response) = &entry.response else {
+        return;
+    };
+    for assert in response.asserts() {
+        if !matches!(assert.query.value, QueryValue::Status) || assert.predicate.not {
+            continue;
+        }
+        let PredicateFuncValue::Equal {
+            value: PredicateValue::Number(Number::Integer(value)),
+            ..
+        } = &assert.predicate.predicate_func.value
+        else {
+            continue;
+        };
+        let status = value.as_i64();
+        if !(100..=599).contains(&status) {
+            let message =
+                format!("assert can never pass: <{status}> is not a valid HTTP status code");
+            issues.push(LintIssue::new(
+                LintRule::ImpossibleStatus,
+                message,
+                assert.predicate.predicate_func.source_info,
+            ));
+        }
+    }
+}
+
+/// Checks that no deprecated spelling is used in the response spec of this `entry` (L006).
+fn check_deprecated_spellings(entry: &Entry, issues: &mut Vec<LintIssue>) {
+    let Some(response) = &entry.response else {
+        return;
+    };
+    for assert in response.asserts() {
+        if matches!(
+            assert.predicate.predicate_func.value,
+            PredicateFuncValue::Include { .. }
+        ) {
+            let message =
+                "<includes> predicate is deprecated in favor of <contains> predicate".to_string();
+            issues.push(LintIssue::new(
+                LintRule::DeprecatedSpelling,
+                message,
+                assert.predicate.predicate_func.source_info,
+            ));
+        }
+    }
+    let assert_filters = response.asserts().iter().flat_map(|a| &a.filters);
+    let capture_filters = response.captures().iter().flat_map(|c| &c.filters);
+    for (_, filter) in assert_filters.chain(capture_filters) {
+        let replacement = match filter.value {
+            FilterValue::Format { .. } => "dateFormat",
+            FilterValue::Decode { .. } => "charsetDecode",
+            _ => continue,
+        };
+        let message = format!(
+            "<{}> filter is deprecated in favor of <{replacement}> filter",
+            filter.value.identifier()
+        );
+        issues.push(LintIssue::new(
+            LintRule::DeprecatedSpelling,
+            message,
+            filter.source_info,
+        ));
+    }
+}
+
+/// Checks that every variable referenced in a template is defined somewhere in the file (L004).
+///
+/// Definitions are collected from captures, `[Options]` variables and `[Bindings]`: a variable
+/// provided at run time (`--variable`, secrets...) is reported as undefined, suppress the rule
+/// in this case.
+fn check_undefined_variables(hurl_file: &HurlFile, issues: &mut Vec<LintIssue>) {
+    let mut defined = HashSet::new();
+    if let Some(section) = &hurl_file.bindings {
+        if let SectionValue::Bindings(params) = &section.value {
+            for param in params {
+                defined.insert(param.name.to_string());
+            }
+        }
+    }
+    for entry in &hurl_file.entries {
+        for option in entry.request.options() {
+            if let OptionKind::Variable(definition) = &option.kind {
+                defined.insert(definition.name.clone());
+            }
+        }
+        if let Some(response) = &entry.response {
+            for capture in response.captures() {
+                defined.insert(capture.name.to_string());
+            }
+        }
+    }
+
+    let mut collector = VariableUsageCollector { usages: vec![] };
+    collector.visit_hurl_file(hurl_file);
+    let mut reported = HashSet::new();
+    for (name, source_info) in collector.usages {
+        if !defined.contains(&name) && reported.insert(name.clone()) {
+            let message = format!("variable <{name}> is never defined in this file");
+            issues.push(LintIssue::new(
+                LintRule::UndefinedVariable,
+                message,
+                source_info,
+            ));
+        }
+    }
+}
+
+/// Collects the variables referenced in templates, with the position of their first usage.
+struct VariableUsageCollector {
+    usages: Vec<(String, SourceInfo)>,
+}
+
+impl VariableUsageCollector {
+    fn scan_template(&mut self, template: &Template) {
+        for element in &template.elements {
+            if let TemplateElement::Placeholder(placeholder) = element {
+                self.visit_placeholder(placeholder);
+            }
+        }
+    }
+}
+
+impl Visitor for VariableUsageCollector {
+    fn visit_template(&mut self, template: &Template) {
+        self.scan_template(template);
+    }
+
+    fn visit_url(&mut self, url: &Template) {
+        self.scan_template(url);
+    }
+
+    fn visit_filename(&mut self, filename: &Template) {
+        self.scan_template(filename);
+    }
+
+    fn visit_placeholder(&mut self, placeholder: &Placeholder) {
+        if let ExprKind::Variable(variable) = &placeholder.expr.kind {
+            self.usages
+                .push((variable.name.clone(), variable.source_info));
+        }
+    }
+}
+
+/// Checks that every file bound in the `[Bindings]` section exists (L005).
+///
+/// Paths are resolved relatively to the current working directory, like the runner does.
+/// Templated filenames are skipped: their value is only known at run time.
+fn check_bound_files(hurl_file: &HurlFile, issues: &mut Vec<LintIssue>) {
+    let Some(section) = &hurl_file.bindings else {
+        return;
+    };
+    let SectionValue::Bindings(params) = &section.value else {
+        return;
+    };
+    for param in params {
+        let filename = match &param.value {
+            BindingExpr::File { filename, .. }
+            | BindingExpr::JsonFile { filename, .. }
+            | BindingExpr::YamlFile { filename, .. } => filename,
+            BindingExpr::Env { .. } => continue,
+        };
+        let Some(path) = literal_value(filename) else {
+            continue;
+        };
+        if path == ":memory:" || Path::new(&path).exists() {
+            continue;
+        }
+        let message = format!("bound file <{path}> does not exist");
+        issues.push(LintIssue::new(
+            LintRule::MissingBoundFile,
+            message,
+            filename.source_info,
+        ));
+    }
+}
+
+/// Checks that no line of `content` ends with spaces or tabs (L007).
+fn check_trailing_whitespace(content: &str, issues: &mut Vec<LintIssue>) {
+    for (index, line) in content.lines().enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let trimmed = line.trim_end_matches([' ', '\t']);
+        if trimmed.len() == line.len() {
+            continue;
+        }
+        let line_number = index + 1;
+        let start = Pos::new(line_number, trimmed.chars().count() + 1);
+        let end = Pos::new(line_number, line.chars().count() + 1);
+        let message = "line ends with trailing whitespace".to_string();
+        issues.push(LintIssue::new(
+            LintRule::TrailingWhitespace,
+            message,
+            SourceInfo::new(start, end),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser;
+
+    use super::*;
+
+    fn lint_content(content: &str, strict_file_bindings: bool) -> Vec<LintIssue> {
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        lint(&hurl_file, content, strict_file_bindings)
+    }
+
+    #[test]
+    fn lint_duplicate_header() {
+        let content = "GET http://example.org\n\
+                       Accept: text/html\n\
+                       accept: application/json\n";
+        let issues = lint_content(content, false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule.code(), "L001");
+        assert_eq!(
+            issues[0].message,
+            "header <Accept> is set more than once in this request"
+        );
+        assert_eq!(issues[0].source_info.start, Pos::new(3, 1));
+    }
+
+    #[test]
+    fn lint_content_type_without_body() {
+        let content = "GET http://example.org\n\
+                       Content-Type: application/json\n";
+        let issues = lint_content(content, false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, LintRule::ContentTypeWithoutBody);
+
+        let content = "POST http://example.org\n\
+                       Content-Type: application/json\n\
+                       {\"a\": 1}\n";
+        assert!(lint_content(content, false).is_empty());
+    }
+
+    #[test]
+    fn lint_impossible_status() {
+        let content = "GET http://example.org\n\
+                       HTTP 200\n\
+                       [Asserts]\n\
+                       status == 999\n";
+        let issues = lint_content(content, false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, LintRule::ImpossibleStatus);
+        assert_eq!(
+            issues[0].message,
+            "assert can never pass: <999> is not a valid HTTP status code"
+        );
+
+        let content = "GET http://example.org\n\
+                       HTTP 200\n\
+                       [Asserts]\n\
+                       status == 404\n";
+        assert!(lint_content(content, false).is_empty());
+    }
+
+    #[test]
+    fn lint_undefined_variable() {
+        let content = "GET http://example.org/{{id}}\n";
+        let issues = lint_content(content, false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, LintRule::UndefinedVariable);
+        assert_eq!(
+            issues[0].message,
+            "variable <id> is never defined in this file"
+        );
+
+        // A variable captured in a previous entry is defined.
+        let content = "GET http://example.org\n\
+                       HTTP 200\n\
+                       [Captures]\n\
+                       id: jsonpath \"$.id\"\n\
+                       \n\
+                       GET http://example.org/{{id}}\n";
+        assert!(lint_content(content, false).is_empty());
+    }
+
+    #[test]
+    fn lint_missing_bound_file() {
+        let content = "[Bindings]\n\
+                       counter: file /nonexistent/counter.txt\n\
+                       \n\
+                       GET http://example.org\n";
+        assert!(lint_content(content, false).is_empty());
+        let issues = lint_content(content, true);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, LintRule::MissingBoundFile);
+    }
+
+    #[test]
+    fn lint_trailing_whitespace_and_fix() {
+        let content = "GET http://example.org  \n";
+        let issues = lint_content(content, false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, LintRule::TrailingWhitespace);
+        assert_eq!(issues[0].source_info.start, Pos::new(1, 23));
+        assert_eq!(fix(content), "GET http://example.org\n");
+    }
+
+    #[test]
+    fn lint_ignore_comment() {
+        let content = "# hurl-lint-ignore L003\n\
+                       GET http://example.org\n\
+                       HTTP 200\n\
+                       [Asserts]\n\
+                       status == 999\n";
+        assert!(lint_content(content, false).is_empty());
+    }
+}
@@ -16,9 +16,12 @@
  *
  */
 mod cli;
+mod har;
+mod openapi;
+mod postman;
 mod run;
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::io::prelude::*;
 use std::io::IsTerminal;
 use std::path::Path;
@@ -28,12 +31,14 @@ use std::{env, io, thread};
 
 use hurl::report::{curl, html, json, junit, tap};
 use hurl::runner;
-use hurl::runner::HurlResult;
+use hurl::runner::{HurlResult, Value};
 use hurl::util::redacted::Redact;
 use hurl_core::input::Input;
 use hurl_core::text;
 
-use crate::cli::options::{CliOptions, CliOptionsError, RunContext, Verbosity};
+use crate::cli::options::{
+    variables, CliOptions, CliOptionsError, RunContext, SummaryFormat, VariablesFormat, Verbosity,
+};
 use crate::cli::{BaseLogger, CliError};
 
 const EXIT_OK: u8 = 0;
@@ -57,6 +62,13 @@ struct HurlRun {
 fn main() -> ExitCode {
     text::init_crate_colored();
 
+    // The `lint` subcommand has its own options: it's intercepted before the regular flag-based
+    // options parsing.
+    let args: Vec<String> = env::args().collect();
+    if args.get(1).map(String::as_str) == Some("lint") {
+        return cli::lint::run(&args[2..]);
+    }
+
     // Construct the run context environment, this should be the sole place where we read
     // environment variables. The run context will be injected in functions that need to access
     // environment variables.
@@ -94,8 +106,65 @@ fn main() -> ExitCode {
         }
     };
     let current_dir = current_dir.as_path();
+
+    // In HAR import mode, the HAR file is converted to Hurl format, no HTTP request is executed.
+    if let Some(filename) = &opts.import_har {
+        return import_har(filename, &opts, &base_logger);
+    }
+
+    // In OpenAPI import mode, the spec is converted to Hurl stub files, no HTTP request is
+    // executed.
+    if let Some(filename) = &opts.import_openapi {
+        return import_openapi(filename, &opts, &base_logger);
+    }
+
+    // In Postman import mode, the collection is converted to Hurl files, no HTTP request is
+    // executed.
+    if let Some(filename) = &opts.import_postman {
+        return import_postman(filename, &opts, &base_logger);
+    }
+
+    // In curl export mode, entries are converted to curl commands, no HTTP request is executed.
+    if let Some(export) = opts.to_curl {
+        return run::to_curl(&opts.input_files, current_dir, &opts, export);
+    }
+
+    // In dry-run mode, files are only parsed and validated, no HTTP request is executed.
+    if opts.dry_run {
+        return run::dry_run(&opts.input_files, current_dir, &opts);
+    }
+
+    // In watch mode, files are rerun each time one of them (or one of their includes) changes
+    // on disk. The loop only ends when the user interrupts it.
+    if opts.watch {
+        loop {
+            // Clear the terminal and move the cursor to the top-left corner.
+            print!("\x1b[2J\x1b[H");
+            let _ = io::stdout().flush();
+            run_once(&opts, current_dir, &base_logger);
+            base_logger.info("Watching for file changes...");
+            if let Err(error) = run::wait_for_change(&opts.input_files, current_dir) {
+                base_logger.error(&error);
+                return ExitCode::from(EXIT_ERROR_UNDEFINED);
+            }
+        }
+    }
+
+    run_once(&opts, current_dir, &base_logger)
+}
+
+/// Runs the input files once and returns the process exit code.
+///
+/// This is the body of a standard run; in watch mode it is called at each file change.
+fn run_once(opts: &CliOptions, current_dir: &Path, base_logger: &BaseLogger) -> ExitCode {
     let start = Instant::now();
 
+    // The random number generator backing the `random_int`/`random_float` functions is seeded
+    // upfront, even without an explicit `--seed`: the effective seed is reported in the test
+    // summary so that a failing random run can be replayed.
+    let seed = opts.seed.unwrap_or_else(rand::random);
+    runner::seed_rng(seed);
+
     let runs = if opts.parallel {
         let available = match thread::available_parallelism() {
             Ok(a) => a,
@@ -107,9 +176,9 @@ fn main() -> ExitCode {
         let workers_count = opts.jobs.unwrap_or(available.get());
         base_logger.debug(&format!("Parallel run using {workers_count} workers"));
 
-        run::run_par(&opts.input_files, current_dir, &opts, workers_count)
+        run::run_par(&opts.input_files, current_dir, opts, workers_count)
     } else {
-        run::run_seq(&opts.input_files, current_dir, &opts)
+        run::run_seq(&opts.input_files, current_dir, opts)
     };
     let runs = match runs {
         // Even in the presence of false assertions, `run::run_par` or `run::run_seq` return an `Ok`
@@ -137,26 +206,172 @@ fn main() -> ExitCode {
     let duration = start.elapsed();
 
     // Write HTML, JUnit, TAP reports on disk.
-    if has_report(&opts) {
-        let ret = export_results(&runs, &opts, &base_logger);
+    if has_report(opts) {
+        let ret = export_results(&runs, opts, base_logger);
         if let Err(err) = ret {
             base_logger.error(&err.to_string());
             return ExitCode::from(EXIT_ERROR_UNDEFINED);
         }
     }
 
-    if opts.test {
-        let summary = cli::summary(&runs, duration);
-        base_logger.info(summary.as_str());
+    // Dump the variables of the run (injected and captured) so that they can be fed to a next
+    // run with `--variables-file`.
+    if let Some(file) = &opts.output_variables {
+        if let Err(err) = create_variables_export(&runs, file, opts) {
+            base_logger.error(&err.to_string());
+            return ExitCode::from(EXIT_ERROR_UNDEFINED);
+        }
+    }
+
+    match opts.summary_format {
+        SummaryFormat::Json => {
+            // The JSON summary is a single line written to standard output, so that CI systems
+            // can parse it whether `--test` is set or not.
+            let summary = cli::json_summary(&runs, duration);
+            println!("{summary}");
+        }
+        SummaryFormat::Text => {
+            if opts.test {
+                let summary = cli::summary(&runs, duration, seed);
+                base_logger.info(summary.as_str());
+            }
+        }
     }
 
     let exit_code = exit_code(&runs);
     ExitCode::from(exit_code)
 }
 
+/// Converts the HAR file `filename` to Hurl format, written to the output of `opts` (a file or
+/// the standard output).
+fn import_har(filename: &Path, opts: &CliOptions, base_logger: &BaseLogger) -> ExitCode {
+    let content = match std::fs::read_to_string(filename) {
+        Ok(c) => c,
+        Err(error) => {
+            base_logger.error(&format!(
+                "Issue reading from {}: {error}",
+                filename.display()
+            ));
+            return ExitCode::from(EXIT_ERROR_PARSING);
+        }
+    };
+    // With a cookie jar, cookies are managed at replay time and are not inlined in the entries.
+    let inline_cookies = opts.cookie_output_file.is_none();
+    let text = match har::to_hurl(&content, inline_cookies) {
+        Ok(t) => t,
+        Err(message) => {
+            base_logger.error(&message);
+            return ExitCode::from(EXIT_ERROR_PARSING);
+        }
+    };
+    let output = opts.output.clone().unwrap_or(hurl::runner::Output::Stdout);
+    let mut stdout = hurl::util::term::Stdout::new(hurl::util::term::WriteMode::Immediate);
+    if let Err(error) = output.write(text.as_bytes(), &mut stdout, false) {
+        base_logger.error(&format!("Issue writing to {output}: {error}"));
+        return ExitCode::from(EXIT_ERROR_UNDEFINED);
+    }
+    ExitCode::from(EXIT_OK)
+}
+
+/// Imports the OpenAPI 3 spec `filename` and generates one Hurl stub file per operation group.
+///
+/// The files are written in the directory set by `--output-dir` (the current directory by
+/// default), no HTTP request is executed.
+fn import_openapi(filename: &Path, opts: &CliOptions, base_logger: &BaseLogger) -> ExitCode {
+    let content = match std::fs::read_to_string(filename) {
+        Ok(c) => c,
+        Err(error) => {
+            base_logger.error(&format!(
+                "Issue reading from {}: {error}",
+                filename.display()
+            ));
+            return ExitCode::from(EXIT_ERROR_PARSING);
+        }
+    };
+    let files = match openapi::to_hurl_files(&content, opts.openapi_group_by) {
+        Ok(f) => f,
+        Err(message) => {
+            base_logger.error(&message);
+            return ExitCode::from(EXIT_ERROR_PARSING);
+        }
+    };
+    let output_dir = opts
+        .output_dir
+        .clone()
+        .unwrap_or_else(|| std::path::PathBuf::from("."));
+    if !output_dir.exists() {
+        if let Err(error) = std::fs::create_dir_all(&output_dir) {
+            base_logger.error(&format!(
+                "Issue creating directory {}: {error}",
+                output_dir.display()
+            ));
+            return ExitCode::from(EXIT_ERROR_UNDEFINED);
+        }
+    }
+    for (name, content) in &files {
+        let path = output_dir.join(format!("{name}.hurl"));
+        if let Err(error) = std::fs::write(&path, content) {
+            base_logger.error(&format!("Issue writing to {}: {error}", path.display()));
+            return ExitCode::from(EXIT_ERROR_UNDEFINED);
+        }
+        base_logger.info(&format!("Generated {}", path.display()));
+    }
+    ExitCode::from(EXIT_OK)
+}
+
+/// Imports the Postman collection `filename` and generates one Hurl file per folder.
+///
+/// The files are written in the directory set by `--output-dir` (the current directory by
+/// default), no HTTP request is executed.
+fn import_postman(filename: &Path, opts: &CliOptions, base_logger: &BaseLogger) -> ExitCode {
+    let content = match std::fs::read_to_string(filename) {
+        Ok(c) => c,
+        Err(error) => {
+            base_logger.error(&format!(
+                "Issue reading from {}: {error}",
+                filename.display()
+            ));
+            return ExitCode::from(EXIT_ERROR_PARSING);
+        }
+    };
+    let (files, warnings) = match postman::to_hurl_files(&content) {
+        Ok(f) => f,
+        Err(message) => {
+            base_logger.error(&message);
+            return ExitCode::from(EXIT_ERROR_PARSING);
+        }
+    };
+    for warning in &warnings {
+        base_logger.warning(warning);
+    }
+    let output_dir = opts
+        .output_dir
+        .clone()
+        .unwrap_or_else(|| std::path::PathBuf::from("."));
+    if !output_dir.exists() {
+        if let Err(error) = std::fs::create_dir_all(&output_dir) {
+            base_logger.error(&format!(
+                "Issue creating directory {}: {error}",
+                output_dir.display()
+            ));
+            return ExitCode::from(EXIT_ERROR_UNDEFINED);
+        }
+    }
+    for (name, content) in &files {
+        let path = output_dir.join(format!("{name}.hurl"));
+        if let Err(error) = std::fs::write(&path, content) {
+            base_logger.error(&format!("Issue writing to {}: {error}", path.display()));
+            return ExitCode::from(EXIT_ERROR_UNDEFINED);
+        }
+        base_logger.info(&format!("Generated {}", path.display()));
+    }
+    ExitCode::from(EXIT_OK)
+}
+
 /// Returns `true` if any kind of report should be created, `false` otherwise.
 fn has_report(opts: &CliOptions) -> bool {
     opts.curl_file.is_some()
+        || opts.har_file.is_some()
         || opts.junit_file.is_some()
         || opts.tap_file.is_some()
         || opts.html_dir.is_some()
@@ -183,6 +398,10 @@ fn export_results(
     if let Some(file) = &opts.curl_file {
         create_curl_export(runs, file, &secrets)?;
     }
+    if let Some(file) = &opts.har_file {
+        logger.debug(&format!("Writing HAR export to {}", file.display()));
+        create_har_export(runs, file, &secrets)?;
+    }
     if let Some(file) = &opts.junit_file {
         logger.debug(&format!("Writing JUnit report to {}", file.display()));
         create_junit_report(runs, file, &secrets)?;
@@ -214,6 +433,18 @@ fn create_curl_export(runs: &[HurlRun], filename: &Path, secrets: &[&str]) -> Re
     Ok(())
 }
 
+/// Creates a HAR export of all HTTP exchanges for this run.
+fn create_har_export(runs: &[HurlRun], filename: &Path, secrets: &[&str]) -> Result<(), CliError> {
+    let results = runs.iter().map(|r| &r.hurl_result).collect::<Vec<_>>();
+    hurl::report::har::write_har(
+        &results,
+        filename,
+        secrets,
+        hurl::report::har::DEFAULT_BODY_SIZE_LIMIT,
+    )?;
+    Ok(())
+}
+
 /// Creates a JUnit report for this run.
 fn create_junit_report(
     runs: &[HurlRun],
@@ -271,6 +502,71 @@ fn create_json_report(runs: &[HurlRun], dir_path: &Path, secrets: &[&str]) -> Re
     Ok(())
 }
 
+/// Writes the variables of `runs` to `filename` file, in `KEY=VALUE` or JSON format.
+///
+/// Variables from all runs are merged, later runs overwriting earlier ones. Secret variables are
+/// omitted, unless `--include-secrets` is used. The `KEY=VALUE` format is directly consumable by
+/// `--variables-file` in a next run. As this format is line-based, a value holding a newline
+/// can't be exported with it and makes this function fail: the JSON format supports any value.
+fn create_variables_export(
+    runs: &[HurlRun],
+    filename: &Path,
+    opts: &CliOptions,
+) -> Result<(), CliError> {
+    let mut variables = BTreeMap::new();
+    for run in runs {
+        for (name, variable) in run.hurl_result.variables.variables() {
+            if variable.is_secret() && !opts.include_secrets {
+                continue;
+            }
+            variables.insert(name.to_string(), variable.value().clone());
+        }
+    }
+    let content = match opts.output_variables_format {
+        VariablesFormat::Env => {
+            let mut s = String::new();
+            for (name, value) in &variables {
+                let value = quote_env_value(value);
+                if value.contains('\n') || value.contains('\r') {
+                    return Err(CliError::GenericIO(format!(
+                        "Variable {name} contains a newline and can not be exported in env format, use --output-variables-format json"
+                    )));
+                }
+                s.push_str(&format!("{name}={value}\n"));
+            }
+            s
+        }
+        VariablesFormat::Json => {
+            let mut map = serde_json::Map::new();
+            for (name, value) in &variables {
+                map.insert(name.clone(), value.to_json(&[]));
+            }
+            let mut s = serde_json::to_string_pretty(&serde_json::Value::Object(map)).unwrap();
+            s.push('\n');
+            s
+        }
+    };
+    std::fs::write(filename, content).map_err(|err| {
+        CliError::GenericIO(format!("Issue writing to {}: {err}", filename.display()))
+    })?;
+    Ok(())
+}
+
+/// Serializes a variable `value` to the env format, quoting it if its bare form would change
+/// its meaning when read back by `--variables-file`.
+fn quote_env_value(value: &Value) -> String {
+    let s = value.to_string();
+    // A string value whose bare form is inferred as another type (`true`, `null`, `123`...) or
+    // reparsed to a different string (`"foo"`) must also be quoted to round-trip unchanged.
+    let reparsed_differently = matches!(value, Value::String(_))
+        && variables::parse_value(&s, variables::TypeKind::Inferred).ok() != Some(value.clone());
+    if s.chars().any(|c| c.is_whitespace() || c == '"' || c == '#') || reparsed_differently {
+        format!("\"{s}\"")
+    } else {
+        s
+    }
+}
+
 /// Returns an exit code for a list of HurlResult.
 fn exit_code(runs: &[HurlRun]) -> u8 {
     let mut count_errors_runner = 0;
@@ -0,0 +1,292 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Import of OpenAPI 3 specifications.
+//!
+//! Generates Hurl stub files from the operations of a spec: entries are grouped in one file per
+//! tag (or per path), operation parameters are substituted with template variables, and example
+//! values of the spec are used as request bodies.
+use crate::cli::options::OpenApiGroupBy;
+
+/// HTTP methods of an OpenAPI path item.
+const METHODS: [&str; 7] = ["get", "put", "post", "delete", "options", "head", "patch"];
+
+/// Converts an OpenAPI 3 spec `content` (in YAML or JSON format) to a list of Hurl stub files.
+///
+/// Each returned pair is a file stem (without the `.hurl` extension) and the file content, with
+/// one entry per operation. `group_by` controls whether operations are grouped by tag or by path.
+pub fn to_hurl_files(
+    content: &str,
+    group_by: OpenApiGroupBy,
+) -> Result<Vec<(String, String)>, String> {
+    let spec = serde_yaml::from_str::<serde_json::Value>(content)
+        .map_err(|error| format!("the OpenAPI spec is not valid YAML: {error}"))?;
+    let Some(paths) = spec.get("paths").and_then(|p| p.as_object()) else {
+        return Err("the OpenAPI spec has no paths".to_string());
+    };
+    let mut files: Vec<(String, String)> = vec![];
+    for (path, item) in paths {
+        let Some(item) = item.as_object() else {
+            continue;
+        };
+        for method in METHODS {
+            let Some(operation) = item.get(method) else {
+                continue;
+            };
+            let group = match group_by {
+                OpenApiGroupBy::Tag => operation
+                    .get("tags")
+                    .and_then(|tags| tags.as_array())
+                    .and_then(|tags| tags.first())
+                    .and_then(|tag| tag.as_str())
+                    .unwrap_or("default")
+                    .to_string(),
+                OpenApiGroupBy::Path => file_stem(path),
+            };
+            let entry = operation_to_hurl(method, path, operation, item);
+            match files.iter_mut().find(|(name, _)| *name == group) {
+                Some((_, content)) => {
+                    content.push('\n');
+                    content.push_str(&entry);
+                }
+                None => files.push((group, entry)),
+            }
+        }
+    }
+    if files.is_empty() {
+        return Err("the OpenAPI spec has no operations".to_string());
+    }
+    Ok(files)
+}
+
+/// Converts the `operation` of a path `item` to a Hurl entry.
+fn operation_to_hurl(
+    method: &str,
+    path: &str,
+    operation: &serde_json::Value,
+    item: &serde_json::Map<String, serde_json::Value>,
+) -> String {
+    let mut s = String::new();
+
+    // The operation summary and description are kept as a comment block.
+    for text in [operation.get("summary"), operation.get("description")]
+        .into_iter()
+        .flatten()
+        .filter_map(|text| text.as_str())
+    {
+        for line in text.trim_end().lines() {
+            if line.is_empty() {
+                s.push_str("#\n");
+            } else {
+                s.push_str(&format!("# {line}\n"));
+            }
+        }
+    }
+
+    // Path parameters `{id}` become template variables `{{id}}`, the server base URL is left to
+    // the user as a `base_url` variable.
+    let url_path = path.replace('{', "{{").replace('}', "}}");
+    s.push_str(&format!(
+        "{} {{{{base_url}}}}{url_path}\n",
+        method.to_uppercase()
+    ));
+
+    let query_params = parameter_names(operation, item, "query");
+    if !query_params.is_empty() {
+        s.push_str("[Query]\n");
+        for name in &query_params {
+            s.push_str(&format!("{name}: {{{{{name}}}}}\n"));
+        }
+    }
+
+    if let Some(body) = request_body(operation) {
+        s.push_str(&body);
+    }
+
+    let status = success_status(operation);
+    s.push_str(&format!("HTTP *\n[Asserts]\nstatus == {status}\n"));
+    s
+}
+
+/// Returns the names of the `kind` (`query`, `path`...) parameters of an `operation`, including
+/// those declared on the path `item`.
+fn parameter_names(
+    operation: &serde_json::Value,
+    item: &serde_json::Map<String, serde_json::Value>,
+    kind: &str,
+) -> Vec<String> {
+    let mut names = vec![];
+    let params = item
+        .get("parameters")
+        .into_iter()
+        .chain(operation.get("parameters"))
+        .filter_map(|params| params.as_array())
+        .flatten();
+    for param in params {
+        if param.get("in").and_then(|i| i.as_str()) != Some(kind) {
+            continue;
+        }
+        let Some(name) = param.get("name").and_then(|n| n.as_str()) else {
+            continue;
+        };
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Returns the request body of an `operation`, built from the `example` (or `x-example`) field of
+/// its content.
+fn request_body(operation: &serde_json::Value) -> Option<String> {
+    let content = operation.get("requestBody")?.get("content")?.as_object()?;
+    let (mime_type, media) = content
+        .iter()
+        .find(|(mime_type, _)| mime_type.starts_with("application/json"))
+        .or_else(|| content.iter().next())?;
+    let example = media.get("example").or_else(|| media.get("x-example"))?;
+    if mime_type.starts_with("application/json") {
+        let mut text = serde_json::to_string_pretty(example).unwrap();
+        text.push('\n');
+        Some(text)
+    } else {
+        let text = example.as_str()?;
+        Some(format!("```\n{}\n```\n", text.trim_end()))
+    }
+}
+
+/// Returns the documented success status code of an `operation` (200 if none is documented).
+fn success_status(operation: &serde_json::Value) -> u32 {
+    operation
+        .get("responses")
+        .and_then(|responses| responses.as_object())
+        .and_then(|responses| {
+            responses
+                .keys()
+                .filter_map(|code| code.parse::<u32>().ok())
+                .find(|code| (200..300).contains(code))
+        })
+        .unwrap_or(200)
+}
+
+/// Builds a file stem from a `path` (`/pets/{petId}` gives `pets_petId`).
+fn file_stem(path: &str) -> String {
+    let mut name = String::new();
+    for c in path.chars() {
+        if c.is_alphanumeric() {
+            name.push(c);
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    let name = name.trim_end_matches('_');
+    if name.is_empty() {
+        "root".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_simple_openapi() {
+        let spec = r#"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: 1.0.0
+paths:
+  /pets:
+    get:
+      tags: [pets]
+      summary: List all pets
+      parameters:
+        - name: limit
+          in: query
+      responses:
+        "200":
+          description: OK
+    post:
+      tags: [pets]
+      summary: Create a pet
+      requestBody:
+        content:
+          application/json:
+            example:
+              name: Rex
+      responses:
+        "201":
+          description: Created
+  /pets/{petId}:
+    get:
+      tags: [pets]
+      summary: Get a pet
+      parameters:
+        - name: petId
+          in: path
+      responses:
+        "200":
+          description: OK
+"#;
+        let files = to_hurl_files(spec, OpenApiGroupBy::Tag).unwrap();
+        assert_eq!(files.len(), 1);
+        let (name, content) = &files[0];
+        assert_eq!(name, "pets");
+        assert_eq!(
+            content,
+            r#"# List all pets
+GET {{base_url}}/pets
+[Query]
+limit: {{limit}}
+HTTP *
+[Asserts]
+status == 200
+
+# Create a pet
+POST {{base_url}}/pets
+{
+  "name": "Rex"
+}
+HTTP *
+[Asserts]
+status == 201
+
+# Get a pet
+GET {{base_url}}/pets/{{petId}}
+HTTP *
+[Asserts]
+status == 200
+"#
+        );
+
+        // Grouped by path, each path gets its own file.
+        let files = to_hurl_files(spec, OpenApiGroupBy::Path).unwrap();
+        let names = files.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["pets", "pets_petId"]);
+    }
+
+    #[test]
+    fn import_invalid_openapi() {
+        let error = to_hurl_files("openapi: 3.0.0", OpenApiGroupBy::Tag)
+            .err()
+            .unwrap();
+        assert_eq!(error, "the OpenAPI spec has no paths");
+    }
+}
@@ -170,6 +170,7 @@ mod tests {
             url: Url::from_str("http://localhost").unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            timings: Default::default(),
         }
     }
 
@@ -232,6 +233,7 @@ mod tests {
                             url: Url::from_str("https://baz.com").unwrap(),
                             certificate: None,
                             ip_addr: Default::default(),
+                            timings: Default::default(),
                         },
                         timings: Default::default(),
                     }],
@@ -17,12 +17,13 @@
  */
 use std::sync::mpsc::{Receiver, Sender};
 use std::sync::{Arc, Mutex};
-use std::{fmt, thread};
+use std::{fmt, io, thread};
 
 use super::job::{Job, JobResult};
 use super::message::{CompletedMsg, InputReadErrorMsg, ParsingErrorMsg, RunningMsg, WorkerMessage};
 use crate::runner;
 use crate::runner::EventListener;
+use crate::util::include;
 use crate::util::logger::Logger;
 use crate::util::term::{Stderr, Stdout, WriteMode};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
@@ -99,6 +100,16 @@ impl Worker {
                     return;
                 }
             };
+            let current_dir = std::env::current_dir().unwrap_or_default();
+            let content = match include::expand_includes(&content, &job.filename, &current_dir) {
+                Ok(c) => c,
+                Err(error) => {
+                    let error = io::Error::other(error);
+                    let msg = InputReadErrorMsg::new(worker_id, &job, error);
+                    _ = tx.send(WorkerMessage::InputReadError(msg));
+                    return;
+                }
+            };
 
             // Try to parse the content
             let hurl_file = parser::parse_hurl_file(&content);
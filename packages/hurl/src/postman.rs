@@ -0,0 +1,385 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Import of Postman collections (v2.1).
+//!
+//! Converts each request item of a collection to a Hurl entry: entries are grouped in one file per
+//! folder. Postman variables (`{{VAR}}`) already use Hurl template syntax and are kept as-is.
+//! JavaScript pre-request and test scripts can't be converted automatically and are kept as
+//! comments; other unconvertible features (OAuth2 helper, form-data files without a path...) are
+//! reported as warnings.
+
+/// A generated Hurl file: a file stem (without the `.hurl` extension) and the file content.
+type HurlFile = (String, String);
+
+/// Converts a Postman collection `content` (in v2.1 JSON format) to a list of Hurl files.
+///
+/// Each returned file holds one entry per request item, grouped by folder. Also returns the
+/// warnings emitted for the features that can't be converted.
+pub fn to_hurl_files(content: &str) -> Result<(Vec<HurlFile>, Vec<String>), String> {
+    let collection = serde_json::from_str::<serde_json::Value>(content)
+        .map_err(|error| format!("the Postman collection is not valid JSON: {error}"))?;
+    let Some(items) = collection.get("item").and_then(|i| i.as_array()) else {
+        return Err("the Postman collection has no items".to_string());
+    };
+    let root = collection
+        .pointer("/info/name")
+        .and_then(|name| name.as_str())
+        .unwrap_or("collection");
+    let mut files: Vec<(String, String)> = vec![];
+    let mut warnings: Vec<String> = vec![];
+    import_items(items, &file_stem(root), &mut files, &mut warnings);
+    if files.is_empty() {
+        return Err("the Postman collection has no requests".to_string());
+    }
+    Ok((files, warnings))
+}
+
+/// Imports a list of `items` into the file named `group`: folders are imported recursively, each
+/// top-level folder getting its own file.
+fn import_items(
+    items: &[serde_json::Value],
+    group: &str,
+    files: &mut Vec<HurlFile>,
+    warnings: &mut Vec<String>,
+) {
+    for item in items {
+        let name = item.get("name").and_then(|n| n.as_str()).unwrap_or("");
+        if let Some(children) = item.get("item").and_then(|i| i.as_array()) {
+            // This item is a folder: nested folders are flattened into their top-level folder
+            // file.
+            import_items(children, &file_stem(name), files, warnings);
+            continue;
+        }
+        let Some(request) = item.get("request") else {
+            continue;
+        };
+        let entry = request_to_hurl(name, item, request, warnings);
+        match files.iter_mut().find(|(file, _)| file == group) {
+            Some((_, content)) => {
+                content.push('\n');
+                content.push_str(&entry);
+            }
+            None => files.push((group.to_string(), entry)),
+        }
+    }
+}
+
+/// Converts the `request` of an `item` named `name` to a Hurl entry.
+fn request_to_hurl(
+    name: &str,
+    item: &serde_json::Value,
+    request: &serde_json::Value,
+    warnings: &mut Vec<String>,
+) -> String {
+    let mut s = String::new();
+
+    if !name.is_empty() {
+        s.push_str(&format!("# {name}\n"));
+    }
+    // JavaScript pre-request and test scripts can't be converted, they're kept as comments.
+    push_scripts(item, &mut s);
+
+    let method = request
+        .get("method")
+        .and_then(|m| m.as_str())
+        .unwrap_or("GET");
+    let url = match request.get("url") {
+        Some(serde_json::Value::String(url)) => url.clone(),
+        Some(url) => url
+            .get("raw")
+            .and_then(|raw| raw.as_str())
+            .unwrap_or("")
+            .to_string(),
+        None => String::new(),
+    };
+    s.push_str(&format!("{method} {url}\n"));
+
+    for header in request
+        .get("header")
+        .and_then(|h| h.as_array())
+        .into_iter()
+        .flatten()
+    {
+        if header.get("disabled").and_then(|d| d.as_bool()) == Some(true) {
+            continue;
+        }
+        let key = header.get("key").and_then(|k| k.as_str()).unwrap_or("");
+        let value = header.get("value").and_then(|v| v.as_str()).unwrap_or("");
+        s.push_str(&format!("{key}: {value}\n"));
+    }
+
+    push_auth(name, request, &mut s, warnings);
+    push_body(name, request, &mut s, warnings);
+    s
+}
+
+/// Keeps the pre-request and test scripts of an `item` as a comment block.
+fn push_scripts(item: &serde_json::Value, s: &mut String) {
+    for event in item
+        .get("event")
+        .and_then(|e| e.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let title = match event.get("listen").and_then(|l| l.as_str()) {
+            Some("prerequest") => "Pre-request script (not converted):",
+            Some("test") => "Test script (not converted):",
+            _ => continue,
+        };
+        let Some(lines) = event.pointer("/script/exec").and_then(|e| e.as_array()) else {
+            continue;
+        };
+        s.push_str(&format!("# {title}\n"));
+        for line in lines.iter().filter_map(|line| line.as_str()) {
+            if line.is_empty() {
+                s.push_str("#\n");
+            } else {
+                s.push_str(&format!("# {line}\n"));
+            }
+        }
+    }
+}
+
+/// Converts the auth of a `request`: basic auth becomes a `[BasicAuth]` section, bearer auth an
+/// `Authorization` header, other helpers (OAuth2...) are reported as warnings.
+fn push_auth(
+    name: &str,
+    request: &serde_json::Value,
+    s: &mut String,
+    warnings: &mut Vec<String>,
+) {
+    let Some(auth) = request.get("auth") else {
+        return;
+    };
+    match auth.get("type").and_then(|t| t.as_str()) {
+        Some("basic") => {
+            let username = auth_param(auth, "basic", "username").unwrap_or_default();
+            let password = auth_param(auth, "basic", "password").unwrap_or_default();
+            s.push_str(&format!("[BasicAuth]\n{username}: {password}\n"));
+        }
+        Some("bearer") => {
+            let token = auth_param(auth, "bearer", "token").unwrap_or_default();
+            s.push_str(&format!("Authorization: Bearer {token}\n"));
+        }
+        Some("noauth") | None => {}
+        Some(kind) => warnings.push(format!("{name}: {kind} auth can't be converted")),
+    }
+}
+
+/// Returns the value of the auth parameter `key` for the auth helper `kind`.
+fn auth_param(auth: &serde_json::Value, kind: &str, key: &str) -> Option<String> {
+    auth.get(kind)?
+        .as_array()?
+        .iter()
+        .find(|param| param.get("key").and_then(|k| k.as_str()) == Some(key))?
+        .get("value")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Converts the body of a `request`: raw bodies are kept as-is (JSON) or in a multiline string,
+/// urlencoded and form-data bodies become `[Form]` and `[Multipart]` sections.
+fn push_body(
+    name: &str,
+    request: &serde_json::Value,
+    s: &mut String,
+    warnings: &mut Vec<String>,
+) {
+    let Some(body) = request.get("body") else {
+        return;
+    };
+    match body.get("mode").and_then(|m| m.as_str()) {
+        Some("raw") => {
+            let raw = body.get("raw").and_then(|r| r.as_str()).unwrap_or("");
+            if raw.is_empty() {
+                return;
+            }
+            let language = body.pointer("/options/raw/language").and_then(|l| l.as_str());
+            if language == Some("json") || raw.starts_with('{') || raw.starts_with('[') {
+                s.push_str(raw.trim_end());
+                s.push('\n');
+            } else {
+                s.push_str(&format!("```\n{}\n```\n", raw.trim_end()));
+            }
+        }
+        Some("urlencoded") => {
+            s.push_str("[Form]\n");
+            for param in body
+                .get("urlencoded")
+                .and_then(|u| u.as_array())
+                .into_iter()
+                .flatten()
+            {
+                if param.get("disabled").and_then(|d| d.as_bool()) == Some(true) {
+                    continue;
+                }
+                let key = param.get("key").and_then(|k| k.as_str()).unwrap_or("");
+                let value = param.get("value").and_then(|v| v.as_str()).unwrap_or("");
+                s.push_str(&format!("{key}: {value}\n"));
+            }
+        }
+        Some("formdata") => {
+            s.push_str("[Multipart]\n");
+            for param in body
+                .get("formdata")
+                .and_then(|f| f.as_array())
+                .into_iter()
+                .flatten()
+            {
+                if param.get("disabled").and_then(|d| d.as_bool()) == Some(true) {
+                    continue;
+                }
+                let key = param.get("key").and_then(|k| k.as_str()).unwrap_or("");
+                if param.get("type").and_then(|t| t.as_str()) == Some("file") {
+                    match param.get("src").and_then(|src| src.as_str()) {
+                        Some(src) => s.push_str(&format!("{key}: file,{src};\n")),
+                        None => warnings.push(format!(
+                            "{name}: form-data file {key} has no path and can't be converted"
+                        )),
+                    }
+                } else {
+                    let value = param.get("value").and_then(|v| v.as_str()).unwrap_or("");
+                    s.push_str(&format!("{key}: {value}\n"));
+                }
+            }
+        }
+        Some("graphql") => {
+            let query = body.pointer("/graphql/query").and_then(|q| q.as_str());
+            if let Some(query) = query {
+                s.push_str(&format!("```graphql\n{}\n```\n", query.trim_end()));
+            }
+        }
+        Some(mode) => warnings.push(format!("{name}: {mode} body can't be converted")),
+        None => {}
+    }
+}
+
+/// Builds a file stem from an item `name` (`My folder` gives `My_folder`).
+fn file_stem(name: &str) -> String {
+    let mut stem = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            stem.push(c);
+        } else if !stem.is_empty() && !stem.ends_with('_') {
+            stem.push('_');
+        }
+    }
+    let stem = stem.trim_end_matches('_');
+    if stem.is_empty() {
+        "collection".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_simple_collection() {
+        let collection = r#"
+{
+    "info": { "name": "My API", "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json" },
+    "item": [
+        {
+            "name": "Pets",
+            "item": [
+                {
+                    "name": "List pets",
+                    "event": [
+                        {
+                            "listen": "test",
+                            "script": { "exec": ["pm.test(\"ok\", function () {", "    pm.response.to.have.status(200);", "});"] }
+                        }
+                    ],
+                    "request": {
+                        "method": "GET",
+                        "url": { "raw": "{{base_url}}/pets?limit=10" },
+                        "header": [{ "key": "Accept", "value": "application/json" }]
+                    }
+                },
+                {
+                    "name": "Create a pet",
+                    "request": {
+                        "method": "POST",
+                        "url": { "raw": "{{base_url}}/pets" },
+                        "auth": {
+                            "type": "basic",
+                            "basic": [
+                                { "key": "username", "value": "bob" },
+                                { "key": "password", "value": "secret" }
+                            ]
+                        },
+                        "body": {
+                            "mode": "raw",
+                            "raw": "{\n  \"name\": \"Rex\"\n}",
+                            "options": { "raw": { "language": "json" } }
+                        }
+                    }
+                }
+            ]
+        },
+        {
+            "name": "Health",
+            "request": {
+                "method": "GET",
+                "url": "{{base_url}}/health",
+                "auth": { "type": "oauth2" }
+            }
+        }
+    ]
+}
+"#;
+        let (files, warnings) = to_hurl_files(collection).unwrap();
+        let names = files.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["Pets", "My_API"]);
+        assert_eq!(
+            files[0].1,
+            r#"# List pets
+# Test script (not converted):
+# pm.test("ok", function () {
+#     pm.response.to.have.status(200);
+# });
+GET {{base_url}}/pets?limit=10
+Accept: application/json
+
+# Create a pet
+POST {{base_url}}/pets
+[BasicAuth]
+bob: secret
+{
+  "name": "Rex"
+}
+"#
+        );
+        assert_eq!(
+            files[1].1,
+            r#"# Health
+GET {{base_url}}/health
+"#
+        );
+        assert_eq!(warnings, vec!["Health: oauth2 auth can't be converted"]);
+    }
+
+    #[test]
+    fn import_invalid_collection() {
+        let error = to_hurl_files("{}").err().unwrap();
+        assert_eq!(error, "the Postman collection has no items");
+    }
+}
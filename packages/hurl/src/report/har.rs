@@ -0,0 +1,319 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! HAR (HTTP Archive) 1.2 export of a run, see
+//! <http://www.softwareishard.com/blog/har-12-spec/>.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use chrono::SecondsFormat;
+use serde_json::json;
+
+use crate::http::{Call, HeaderVec, Request, Response, Timings};
+use crate::report::ReportError;
+use crate::runner::HurlResult;
+use crate::util::path::create_dir_all;
+use crate::util::redacted::Redact;
+
+/// Maximum size of a request or response body embedded in a HAR export.
+///
+/// Bigger bodies are omitted from the export, with a comment giving the actual size.
+pub const DEFAULT_BODY_SIZE_LIMIT: usize = 1024 * 1024;
+
+/// Creates a HAR export from a list of `hurl_results`.
+///
+/// Bodies bigger than `body_size_limit` bytes are omitted, and `secrets` strings are redacted
+/// from this export.
+pub fn write_har(
+    hurl_results: &[&HurlResult],
+    filename: &Path,
+    secrets: &[&str],
+    body_size_limit: usize,
+) -> Result<(), ReportError> {
+    let entries = hurl_results
+        .iter()
+        .flat_map(|h| &h.entries)
+        .flat_map(|e| &e.calls)
+        .map(|call| har_entry(call, body_size_limit))
+        .collect::<Vec<_>>();
+    let log = json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": "hurl",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "entries": entries,
+        }
+    });
+    let mut content = serde_json::to_string_pretty(&log).unwrap().redact(secrets);
+    content.push('\n');
+
+    create_dir_all(filename)
+        .map_err(|e| ReportError::from_io_error(&e, filename, "Issue creating HAR export"))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(filename)
+        .map_err(|e| ReportError::from_io_error(&e, filename, "Issue creating HAR export"))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing HAR export"))?;
+
+    Ok(())
+}
+
+/// Converts a single HTTP exchange to a HAR entry.
+fn har_entry(call: &Call, body_size_limit: usize) -> serde_json::Value {
+    let started = call
+        .timings
+        .begin_call
+        .to_rfc3339_opts(SecondsFormat::Millis, true);
+    // The negotiated HTTP version lives on the response only, HAR wants it on both sides.
+    let version = call.response.version.to_string();
+    json!({
+        "startedDateTime": started,
+        "time": to_millis(call.timings.total),
+        "request": har_request(&call.request, &version, body_size_limit),
+        "response": har_response(&call.response, body_size_limit),
+        "cache": {},
+        "timings": har_timings(&call.timings),
+    })
+}
+
+/// Converts an HTTP request to a HAR request object.
+fn har_request(request: &Request, version: &str, body_size_limit: usize) -> serde_json::Value {
+    let cookies = request
+        .cookies()
+        .iter()
+        .map(|c| json!({"name": c.name, "value": c.value}))
+        .collect::<Vec<_>>();
+    let query_string = request
+        .url
+        .query_params()
+        .iter()
+        .map(|p| json!({"name": p.name, "value": p.value}))
+        .collect::<Vec<_>>();
+    let mut value = json!({
+        "method": request.method,
+        "url": request.url.raw(),
+        "httpVersion": version,
+        "cookies": cookies,
+        "headers": har_headers(&request.headers),
+        "queryString": query_string,
+        "headersSize": -1,
+        "bodySize": request.body.len(),
+    });
+    if !request.body.is_empty() {
+        let mime_type = content_type(&request.headers);
+        let mut post_data = json!({
+            "mimeType": mime_type,
+            "text": "",
+        });
+        if request.body.len() > body_size_limit {
+            post_data["comment"] = json!(omitted_body_note(request.body.len(), body_size_limit));
+        } else {
+            match std::str::from_utf8(&request.body) {
+                Ok(text) => post_data["text"] = json!(text),
+                Err(_) => {
+                    // HAR 1.2 has no encoding field on postData: binary request
+                    // bodies are embedded base64-encoded, with a comment.
+                    post_data["text"] = json!(general_purpose::STANDARD.encode(&request.body));
+                    post_data["comment"] = json!("base64-encoded binary body");
+                }
+            }
+        }
+        value["postData"] = post_data;
+    }
+    value
+}
+
+/// Converts an HTTP response to a HAR response object.
+fn har_response(response: &Response, body_size_limit: usize) -> serde_json::Value {
+    let cookies = response
+        .cookies()
+        .iter()
+        .map(|c| json!({"name": c.name, "value": c.value}))
+        .collect::<Vec<_>>();
+    let redirect_url = response
+        .headers
+        .get("Location")
+        .map_or(String::new(), |h| h.value.clone());
+    let mut content = json!({
+        "size": response.body.len(),
+        "mimeType": content_type(&response.headers),
+    });
+    if response.body.len() > body_size_limit {
+        content["comment"] = json!(omitted_body_note(response.body.len(), body_size_limit));
+    } else {
+        match std::str::from_utf8(&response.body) {
+            Ok(text) => content["text"] = json!(text),
+            Err(_) => {
+                content["text"] = json!(general_purpose::STANDARD.encode(&response.body));
+                content["encoding"] = json!("base64");
+            }
+        }
+    }
+    json!({
+        "status": response.status,
+        "statusText": "",
+        "httpVersion": response.version.to_string(),
+        "cookies": cookies,
+        "headers": har_headers(&response.headers),
+        "content": content,
+        "redirectURL": redirect_url,
+        "headersSize": -1,
+        "bodySize": response.body.len(),
+    })
+}
+
+/// Converts a header list to a HAR headers array.
+fn har_headers(headers: &HeaderVec) -> Vec<serde_json::Value> {
+    headers
+        .iter()
+        .map(|h| json!({"name": h.name, "value": h.value}))
+        .collect()
+}
+
+/// Converts libcurl cumulative timings to the per-phase HAR timings object.
+fn har_timings(timings: &Timings) -> serde_json::Value {
+    let dns = timings.name_lookup;
+    let connect = timings.connect.saturating_sub(timings.name_lookup);
+    // A zero app_connect means no TLS handshake happened.
+    let ssl = if timings.app_connect.is_zero() {
+        -1.0
+    } else {
+        to_millis(timings.app_connect.saturating_sub(timings.connect))
+    };
+    let connected = timings.app_connect.max(timings.connect);
+    let send = timings.pre_transfer.saturating_sub(connected);
+    let wait = timings.start_transfer.saturating_sub(timings.pre_transfer);
+    let receive = timings.total.saturating_sub(timings.start_transfer);
+    json!({
+        "blocked": -1,
+        "dns": to_millis(dns),
+        "connect": to_millis(connect),
+        "ssl": ssl,
+        "send": to_millis(send),
+        "wait": to_millis(wait),
+        "receive": to_millis(receive),
+    })
+}
+
+/// Converts a duration to fractional milliseconds.
+fn to_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Returns the `Content-Type` header value, or an empty string.
+fn content_type(headers: &HeaderVec) -> String {
+    headers
+        .get("Content-Type")
+        .map_or(String::new(), |h| h.value.clone())
+}
+
+/// Returns the note attached to a body omitted from the export.
+fn omitted_body_note(size: usize, body_size_limit: usize) -> String {
+    format!("body of {size} bytes omitted (exceeds the {body_size_limit} bytes limit)")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::http::{Header, HttpVersion, Url};
+
+    fn call() -> Call {
+        let url = Url::from_str("http://localhost:8000/hello?name=toto").unwrap();
+        let mut request_headers = HeaderVec::new();
+        request_headers.push(Header::new("Content-Type", "application/json"));
+        request_headers.push(Header::new("Cookie", "theme=dark"));
+        let request = Request::new(
+            "POST",
+            url.clone(),
+            request_headers,
+            b"{\"id\": 1}".to_vec(),
+        );
+        let mut response_headers = HeaderVec::new();
+        response_headers.push(Header::new("Content-Type", "text/html; charset=utf-8"));
+        let response = Response {
+            version: HttpVersion::Http11,
+            status: 200,
+            headers: response_headers,
+            body: b"Hello World!".to_vec(),
+            duration: Duration::from_millis(100),
+            url,
+            certificate: None,
+            ip_addr: Default::default(),
+            timings: Timings::default(),
+        };
+        Call {
+            request,
+            response,
+            timings: Timings::default(),
+        }
+    }
+
+    #[test]
+    fn export_har_entry() {
+        let entry = har_entry(&call(), DEFAULT_BODY_SIZE_LIMIT);
+
+        let request = &entry["request"];
+        assert_eq!(request["method"], "POST");
+        assert_eq!(request["url"], "http://localhost:8000/hello?name=toto");
+        assert_eq!(request["httpVersion"], "HTTP/1.1");
+        assert_eq!(request["queryString"][0]["name"], "name");
+        assert_eq!(request["queryString"][0]["value"], "toto");
+        assert_eq!(request["cookies"][0]["name"], "theme");
+        assert_eq!(request["cookies"][0]["value"], "dark");
+        assert_eq!(request["postData"]["mimeType"], "application/json");
+        assert_eq!(request["postData"]["text"], "{\"id\": 1}");
+
+        let response = &entry["response"];
+        assert_eq!(response["status"], 200);
+        assert_eq!(response["httpVersion"], "HTTP/1.1");
+        assert_eq!(response["content"]["size"], 12);
+        assert_eq!(response["content"]["mimeType"], "text/html; charset=utf-8");
+        assert_eq!(response["content"]["text"], "Hello World!");
+        assert_eq!(response["bodySize"], 12);
+    }
+
+    #[test]
+    fn export_har_entry_with_big_body() {
+        let entry = har_entry(&call(), 4);
+        let response = &entry["response"];
+        assert!(response["content"]["text"].is_null());
+        assert_eq!(
+            response["content"]["comment"],
+            "body of 12 bytes omitted (exceeds the 4 bytes limit)"
+        );
+        let request = &entry["request"];
+        assert!(request["postData"]["text"] == "");
+        assert_eq!(
+            request["postData"]["comment"],
+            "body of 9 bytes omitted (exceeds the 4 bytes limit)"
+        );
+    }
+}
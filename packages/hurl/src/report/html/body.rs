@@ -0,0 +1,265 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! HTML view of a request or response body.
+//!
+//! Text bodies are displayed inline with a basic syntax coloring for JSON and XML. Bodies bigger
+//! than [`BODY_MAX_DISPLAY_SIZE`] are truncated with a "show more" link to a sidecar file holding
+//! the raw bytes; binary bodies are displayed as a hex dump and always reference their sidecar
+//! file (the report never inlines binary content as base64).
+use crate::http::mimetype;
+use crate::util::redacted::Redact;
+
+/// Maximum number of body bytes displayed inline in the run view.
+pub const BODY_MAX_DISPLAY_SIZE: usize = 10 * 1024;
+
+/// Returns `true` if this `body` needs a sidecar file in the report directory, i.e. if the run
+/// view doesn't display it in full.
+pub fn needs_sidecar(body: &[u8]) -> bool {
+    !body.is_empty() && (body.len() > BODY_MAX_DISPLAY_SIZE || std::str::from_utf8(body).is_err())
+}
+
+/// Returns an HTML view of a request or response `body`, given its optional `content_type`.
+///
+/// `sidecar` is the name of the file holding the raw body, used as a "show more" link when the
+/// inline view is truncated. `secrets` are redacted from text bodies.
+pub fn get_body_html(
+    body: &[u8],
+    content_type: Option<&str>,
+    sidecar: &str,
+    secrets: &[&str],
+) -> String {
+    match std::str::from_utf8(body) {
+        Ok(text) => get_text_body_html(text, content_type, sidecar, secrets),
+        Err(_) => get_binary_body_html(body, sidecar),
+    }
+}
+
+/// Returns an HTML view of a text body, syntax colored for JSON and XML content.
+fn get_text_body_html(
+    text: &str,
+    content_type: Option<&str>,
+    sidecar: &str,
+    secrets: &[&str],
+) -> String {
+    let truncated = text.len() > BODY_MAX_DISPLAY_SIZE;
+    let text = if truncated {
+        let mut end = BODY_MAX_DISPLAY_SIZE;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        &text[..end]
+    } else {
+        text
+    };
+    let text = text.redact(secrets);
+    let text = if content_type.is_some_and(mimetype::is_json) {
+        highlight_json(&text)
+    } else if content_type.is_some_and(mimetype::is_xml)
+        || content_type.is_some_and(mimetype::is_html)
+    {
+        highlight_xml(&text)
+    } else {
+        html_escape(&text)
+    };
+    let mut html = format!("<pre><code>{text}</code></pre>");
+    if truncated {
+        html.push_str(&format!(
+            "<p class=\"truncated\">Body truncated, <a href=\"{sidecar}\">show more</a></p>"
+        ));
+    }
+    html
+}
+
+/// Returns an HTML view of a binary body, as a hex dump of its first [`BODY_MAX_DISPLAY_SIZE`]
+/// bytes, with a link to the `sidecar` file holding the raw bytes.
+fn get_binary_body_html(body: &[u8], sidecar: &str) -> String {
+    let truncated = body.len() > BODY_MAX_DISPLAY_SIZE;
+    let dump = hex_dump(&body[..body.len().min(BODY_MAX_DISPLAY_SIZE)]);
+    let link = if truncated {
+        format!("<p class=\"truncated\">Body truncated, <a href=\"{sidecar}\">show more</a></p>")
+    } else {
+        format!("<p class=\"truncated\"><a href=\"{sidecar}\">Raw bytes</a></p>")
+    };
+    format!("<pre><code>{dump}</code></pre>{link}")
+}
+
+/// Formats `bytes` as a hex dump, 16 bytes per line, with offset and ASCII columns.
+fn hex_dump(bytes: &[u8]) -> String {
+    let mut text = String::new();
+    for (i, chunk) in bytes.chunks(16).enumerate() {
+        text.push_str(&format!("{:08x}  ", i * 16));
+        for j in 0..16 {
+            match chunk.get(j) {
+                Some(byte) => text.push_str(&format!("{byte:02x} ")),
+                None => text.push_str("   "),
+            }
+            if j == 7 {
+                text.push(' ');
+            }
+        }
+        text.push(' ');
+        for byte in chunk {
+            let c = if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            };
+            text.push(c);
+        }
+        text.push('\n');
+    }
+    html_escape(&text)
+}
+
+/// Returns an HTML syntax colored version of a JSON `text`.
+///
+/// This is a lexical coloring (strings, numbers, keywords), the text doesn't have to be valid
+/// JSON: an invalid text just degrades to a partial coloring.
+fn highlight_json(text: &str) -> String {
+    let mut html = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let mut string = String::from('"');
+                let mut escaped = false;
+                for c in chars.by_ref() {
+                    string.push(c);
+                    if c == '"' && !escaped {
+                        break;
+                    }
+                    escaped = c == '\\' && !escaped;
+                }
+                // A string followed by a colon is an object key.
+                let class = if chars.peek() == Some(&':') {
+                    "key"
+                } else {
+                    "string"
+                };
+                html.push_str(&format!(
+                    "<span class=\"{class}\">{}</span>",
+                    html_escape(&string)
+                ));
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                let mut number = String::from(c);
+                while let Some(c) = chars.peek() {
+                    if c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-') {
+                        number.push(*c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                html.push_str(&format!("<span class=\"number\">{number}</span>"));
+            }
+            c if c.is_ascii_alphabetic() => {
+                let mut word = String::from(c);
+                while let Some(c) = chars.peek() {
+                    if c.is_ascii_alphabetic() {
+                        word.push(*c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                if matches!(word.as_str(), "true" | "false" | "null") {
+                    html.push_str(&format!("<span class=\"keyword\">{word}</span>"));
+                } else {
+                    html.push_str(&html_escape(&word));
+                }
+            }
+            c => html.push_str(&html_escape(&c.to_string())),
+        }
+    }
+    html
+}
+
+/// Returns an HTML syntax colored version of an XML (or HTML) `text`: tags and attribute values
+/// are colored, text nodes are left as-is.
+fn highlight_xml(text: &str) -> String {
+    let mut html = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '<' {
+            let mut tag = String::from('<');
+            for c in chars.by_ref() {
+                tag.push(c);
+                if c == '>' {
+                    break;
+                }
+            }
+            html.push_str(&format!("<span class=\"tag\">{}</span>", html_escape(&tag)));
+        } else {
+            html.push_str(&html_escape(&c.to_string()));
+        }
+    }
+    html
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_sidecar() {
+        assert!(!needs_sidecar(b""));
+        assert!(!needs_sidecar(b"Hello World!"));
+        assert!(needs_sidecar(&[0xff, 0xfe]));
+        assert!(needs_sidecar(&vec![b'a'; BODY_MAX_DISPLAY_SIZE + 1]));
+    }
+
+    #[test]
+    fn test_text_body_html() {
+        let html = get_body_html(
+            br#"{"id": 42, "ok": true, "name": "a<b"}"#,
+            Some("application/json"),
+            "sidecar.bin",
+            &[],
+        );
+        assert_eq!(
+            html,
+            "<pre><code>{<span class=\"key\">\"id\"</span>: <span class=\"number\">42</span>, \
+             <span class=\"key\">\"ok\"</span>: <span class=\"keyword\">true</span>, \
+             <span class=\"key\">\"name\"</span>: <span class=\"string\">\"a&lt;b\"</span>}\
+             </code></pre>"
+        );
+    }
+
+    #[test]
+    fn test_truncated_body_html() {
+        let body = vec![b'a'; BODY_MAX_DISPLAY_SIZE + 1];
+        let html = get_body_html(&body, Some("text/plain"), "sidecar.bin", &[]);
+        assert!(html.contains("<a href=\"sidecar.bin\">show more</a>"));
+        assert!(!html.contains(&String::from_utf8(body).unwrap()));
+    }
+
+    #[test]
+    fn test_binary_body_html() {
+        let html = get_body_html(&[0x00, 0x01, b'H', b'i', 0xff], None, "sidecar.bin", &[]);
+        assert!(html.contains("00000000  00 01 48 69 ff"));
+        assert!(html.contains("..Hi."));
+        assert!(html.contains("<a href=\"sidecar.bin\">Raw bytes</a>"));
+    }
+}
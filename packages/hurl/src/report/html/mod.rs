@@ -16,6 +16,7 @@
  *
  */
 //! HTML report.
+mod body;
 mod nav;
 mod report;
 mod run;
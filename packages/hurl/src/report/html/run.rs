@@ -18,6 +18,7 @@
 use hurl_core::ast::HurlFile;
 
 use crate::http::Call;
+use crate::report::html::body::get_body_html;
 use crate::report::html::nav::Tab;
 use crate::report::html::Testcase;
 use crate::runner::EntryResult;
@@ -48,6 +49,9 @@ impl Testcase {
             run.push_str(&info);
 
             for (call_index, c) in e.calls.iter().enumerate() {
+                let request_body_file = self.request_body_filename(entry_index + 1, call_index + 1);
+                let response_body_file =
+                    self.response_body_filename(entry_index + 1, call_index + 1);
                 let info = get_call_html(
                     c,
                     entry_index + 1,
@@ -55,6 +59,8 @@ impl Testcase {
                     &self.filename,
                     &source,
                     line,
+                    &request_body_file,
+                    &response_body_file,
                     secrets,
                 );
                 run.push_str(&info);
@@ -89,7 +95,7 @@ fn get_entry_html(entry: &EntryResult, entry_index: usize, secrets: &[&str]) ->
             .iter()
             .map(|c| (&c.name, c.value.to_string().redact(secrets)))
             .collect::<Vec<(&String, String)>>();
-        values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        values.sort_by_key(|a| a.0.to_lowercase());
         let table = new_table("Captures", &values);
         text.push_str(&table);
     }
@@ -98,6 +104,7 @@ fn get_entry_html(entry: &EntryResult, entry_index: usize, secrets: &[&str]) ->
 }
 
 /// Returns an HTML view of a `call` (source file, request and response headers, certificate etc...)
+#[allow(clippy::too_many_arguments)]
 fn get_call_html(
     call: &Call,
     entry_index: usize,
@@ -105,6 +112,8 @@ fn get_call_html(
     filename: &str,
     source: &str,
     line: usize,
+    request_body_file: &str,
+    response_body_file: &str,
     secrets: &[&str],
 ) -> String {
     let mut text = String::new();
@@ -164,23 +173,49 @@ fn get_call_html(
         .iter()
         .map(|h| (h.name.as_str(), h.value.redact(secrets)))
         .collect::<Vec<(&str, String)>>();
-    values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    values.sort_by_key(|a| a.0.to_lowercase());
     let table = new_table("Request Headers", &values);
     text.push_str(&table);
 
+    if !call.request.body.is_empty() {
+        let content_type = call.request.headers.content_type();
+        let body = get_body_html(&call.request.body, content_type, request_body_file, secrets);
+        let section = new_body_details("Request Body", call.request.body.len(), &body);
+        text.push_str(&section);
+    }
+
     let mut values = call
         .response
         .headers
         .iter()
         .map(|h| (h.name.as_str(), h.value.redact(secrets)))
         .collect::<Vec<(&str, String)>>();
-    values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    values.sort_by_key(|a| a.0.to_lowercase());
     let table = new_table("Response Headers", &values);
     text.push_str(&table);
 
+    // The response body is displayed decompressed, like a browser does. If the body can't be
+    // decompressed, we fall back to the raw bytes (displayed as a hex dump).
+    let body = call
+        .response
+        .uncompress_body()
+        .unwrap_or_else(|_| call.response.body.clone());
+    if !body.is_empty() {
+        let content_type = call.response.headers.content_type();
+        let body_html = get_body_html(&body, content_type, response_body_file, secrets);
+        let section = new_body_details("Response Body", body.len(), &body_html);
+        text.push_str(&section);
+    }
+
     text
 }
 
+/// Returns a collapsible HTML section with a `title`, the body `size` in bytes and the body HTML
+/// view `body`.
+fn new_body_details(title: &str, size: usize, body: &str) -> String {
+    format!("<details class=\"body\"><summary>{title} ({size} bytes)</summary>{body}</details>")
+}
+
 /// Returns an HTML table with a `title` and a list of key/values. Values are redacted using `secrets`.
 fn new_table<T: AsRef<str>, U: AsRef<str> + std::fmt::Display>(
     title: &str,
@@ -18,6 +18,7 @@
 use std::fs;
 use std::path::Path;
 
+use crate::report::html::body::needs_sidecar;
 use crate::report::ReportError;
 use crate::runner::{EntryResult, HurlResult, RunnerError};
 use hurl_core::ast::SourceInfo;
@@ -98,6 +99,31 @@ impl Testcase {
             ReportError::from_io_error(&e, &output_file, "Issue writing HTML report")
         })?;
 
+        // Finally, write the raw request and response bodies that the run view doesn't display
+        // in full (binary bodies and bodies too big to be inlined).
+        for (entry_index, e) in entries.iter().enumerate() {
+            for (call_index, c) in e.calls.iter().enumerate() {
+                if needs_sidecar(&c.request.body) {
+                    let filename = self.request_body_filename(entry_index + 1, call_index + 1);
+                    let output_file = dir.join(filename);
+                    fs::write(&output_file, &c.request.body).map_err(|e| {
+                        ReportError::from_io_error(&e, &output_file, "Issue writing HTML report")
+                    })?;
+                }
+                let body = c
+                    .response
+                    .uncompress_body()
+                    .unwrap_or_else(|_| c.response.body.clone());
+                if needs_sidecar(&body) {
+                    let filename = self.response_body_filename(entry_index + 1, call_index + 1);
+                    let output_file = dir.join(filename);
+                    fs::write(&output_file, &body).map_err(|e| {
+                        ReportError::from_io_error(&e, &output_file, "Issue writing HTML report")
+                    })?;
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -112,4 +138,12 @@ impl Testcase {
     pub fn run_filename(&self) -> String {
         format!("{}-run.html", self.id)
     }
+
+    pub fn request_body_filename(&self, entry_index: usize, call_index: usize) -> String {
+        format!("{}-e{entry_index}-c{call_index}-request.bin", self.id)
+    }
+
+    pub fn response_body_filename(&self, entry_index: usize, call_index: usize) -> String {
+        format!("{}-e{entry_index}-c{call_index}-response.bin", self.id)
+    }
 }
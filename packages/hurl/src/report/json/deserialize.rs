@@ -39,10 +39,18 @@ pub fn parse_json_report(filename: &Path) -> Result<Vec<Value>, ReportError> {
     // TODO: if the existing JSON report is not valid, we consider that there is no
     // existing report to append, without displaying any error or warning. Maybe a better option
     // would be to raise an error here and ask the user to explicitly deal with this error.
-    if let Ok(Some(Value::Array(values))) = serde_json::from_str(&s) {
-        if values.iter().all(HurlResult::is_deserializable) {
-            return Ok(values);
-        }
+    let values = match serde_json::from_str(&s) {
+        // Versioned report: the testcases are wrapped in an object with a `version` field.
+        Ok(Some(Value::Object(mut report))) => match report.remove("testcases") {
+            Some(Value::Array(values)) => values,
+            _ => return Ok(vec![]),
+        },
+        // Legacy report: a bare array of testcases.
+        Ok(Some(Value::Array(values))) => values,
+        _ => return Ok(vec![]),
+    };
+    if values.iter().all(HurlResult::is_deserializable) {
+        return Ok(values);
     }
     Ok(vec![])
 }
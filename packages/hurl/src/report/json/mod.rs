@@ -43,6 +43,9 @@ use hurl_core::input::Input;
 use crate::report::ReportError;
 use crate::runner::HurlResult;
 
+/// Version of the JSON report schema, to be bumped on each breaking format change.
+const REPORT_VERSION: i64 = 1;
+
 /// Exports a list of [`Testcase`] to a JSON file `filename`.
 ///
 /// Response file are saved under the `response_dir` directory and referenced by path in JSON report
@@ -64,6 +67,10 @@ pub fn write_report(
         .map_err(|e| ReportError::from_io_error(&e, filename, "Issue creating JSON report"))?;
     report.extend(json);
 
+    let report = serde_json::json!({
+        "version": REPORT_VERSION,
+        "testcases": report,
+    });
     let serialized = serde_json::to_string(&report)?;
     let bytes = format!("{serialized}\n");
     let bytes = bytes.into_bytes();
@@ -21,6 +21,7 @@
 
 pub mod curl;
 mod error;
+pub mod har;
 pub mod html;
 pub mod json;
 pub mod junit;
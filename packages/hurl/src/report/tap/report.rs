@@ -62,6 +62,9 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
         let number = i + 1;
         let description = &testcase.description;
         s.push_str(format!("{state} {number} - {description}\n").as_str());
+        for diagnostic in &testcase.diagnostics {
+            s.push_str(format!("# {diagnostic}\n").as_str());
+        }
     }
     file.write_all(s.as_bytes())
         .map_err(|e| ReportError::from_io_error(&e, filename, "Issue writing TAP report"))?;
@@ -80,7 +83,7 @@ fn parse_tap_file(filename: &Path) -> Result<Vec<Testcase>, ReportError> {
 
 /// Parse Tap report
 fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
-    let mut testcases = vec![];
+    let mut testcases: Vec<Testcase> = vec![];
     let mut lines: Vec<&str> = s.lines().collect::<Vec<&str>>();
     if !lines.is_empty() {
         let mut header = lines.remove(0);
@@ -96,10 +99,19 @@ fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
         }
         for line in lines {
             let line = line.trim();
-            if !line.is_empty() {
-                let testcase = Testcase::parse(line)?;
-                testcases.push(testcase);
+            if line.is_empty() {
+                continue;
+            }
+            // Diagnostic lines belong to the previous testcase, they're kept so
+            // that appending to an existing report doesn't lose them.
+            if let Some(diagnostic) = line.strip_prefix('#') {
+                if let Some(testcase) = testcases.last_mut() {
+                    testcase.diagnostics.push(diagnostic.trim().to_string());
+                }
+                continue;
             }
+            let testcase = Testcase::parse(line)?;
+            testcases.push(testcase);
         }
     }
     Ok(testcases)
@@ -122,15 +134,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostics: vec![],
                 }
             ]
         );
@@ -150,15 +165,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostics: vec![],
                 }
             ]
         );
@@ -176,23 +194,28 @@ ok 5 - test.5.hurl
             vec![
                 Testcase {
                     description: "test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
                 },
                 Testcase {
                     description: "test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
                 },
                 Testcase {
                     description: "test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostics: vec![],
                 },
                 Testcase {
                     description: "test.4.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostics: vec![],
                 },
                 Testcase {
                     description: "test.5.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
                 }
             ]
         );
@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+use hurl_core::error::DisplaySourceError;
 use hurl_core::input::Input;
 
 use crate::report::ReportError;
@@ -24,16 +25,30 @@ use crate::runner::HurlResult;
 pub struct Testcase {
     pub(crate) description: String,
     pub(crate) success: bool,
+    /// Diagnostic lines (`# ...`) carrying the error details of a failed testcase.
+    pub(crate) diagnostics: Vec<String>,
 }
 
 impl Testcase {
     /// Creates an Tap &lt;testcase&gt; from an [`HurlResult`].
     pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
         let description = filename.to_string();
-        let success = hurl_result.errors().is_empty();
+        let errors = hurl_result.errors();
+        let success = errors.is_empty();
+        let diagnostics = errors
+            .iter()
+            .map(|(error, _)| {
+                format!(
+                    "{} at line {}",
+                    error.description(),
+                    error.source_info.start.line
+                )
+            })
+            .collect();
         Testcase {
             description,
             success,
+            diagnostics,
         }
     }
 
@@ -72,6 +87,7 @@ impl Testcase {
         Ok(Testcase {
             description,
             success,
+            diagnostics: vec![],
         })
     }
 }
@@ -91,7 +107,8 @@ mod tests {
             Testcase::parse("ok 1 - tests_ok/test.1.hurl").unwrap(),
             Testcase {
                 description: "tests_ok/test.1.hurl".to_string(),
-                success: true
+                success: true,
+                diagnostics: vec![],
             }
         );
     }
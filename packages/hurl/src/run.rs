@@ -16,19 +16,31 @@
  *
  */
 use std::cmp::min;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::fs;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{RecursiveMode, Watcher};
 
 use hurl::parallel::job::{Job, JobResult};
 use hurl::parallel::runner::ParallelRunner;
 use hurl::pretty::PrettyMode;
 use hurl::runner::{HurlResult, Output, VariableSet};
-use hurl::util::term::{Stdout, WriteMode};
+use hurl::util::include;
+use hurl::util::logger::Logger;
+use hurl::util::path::ContextDir;
+use hurl::util::term::{Stderr, Stdout, WriteMode};
 use hurl::{output, parallel, runner};
+use hurl_core::ast::visit::Visitor;
+use hurl_core::ast::{EntryOption, Template, TemplateElement};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
-use hurl_core::input::Input;
+use hurl_core::input::{Input, InputKind};
+use hurl_core::parser;
 use hurl_core::types::Count;
 
-use crate::cli::options::CliOptions;
+use crate::cli::options::{CliOptions, CurlExport};
 use crate::cli::CliError;
 use crate::{cli, HurlRun};
 
@@ -58,6 +70,10 @@ pub fn run_seq(
                 return Err(error);
             }
         };
+        let content = match include::expand_includes(&content, &filename, current_dir) {
+            Ok(c) => c,
+            Err(error) => return Err(CliError::InputRead(error)),
+        };
         let mut variables = VariableSet::from(&options.variables);
         options.secrets.iter().for_each(|(name, value)| {
             variables.insert_secret(name.clone(), value.clone());
@@ -105,6 +121,239 @@ pub fn run_seq(
     Ok(runs)
 }
 
+/// Prints the curl commands equivalent to the entries of Hurl `files`, without executing any
+/// HTTP request.
+///
+/// Variables from the command line are substituted before the commands are built; `export`
+/// selects all the entries or a single one. Errors are reported on standard error.
+pub fn to_curl(
+    files: &[Input],
+    current_dir: &Path,
+    options: &CliOptions,
+    export: CurlExport,
+) -> ExitCode {
+    let entry_index = match export {
+        CurlExport::AllEntries => None,
+        CurlExport::Entry(index) => Some(index),
+    };
+    for filename in files {
+        let content = match filename.read_to_string() {
+            Ok(c) => c,
+            Err(error) => {
+                eprintln!("Issue reading from {filename}: {error}");
+                return ExitCode::from(crate::EXIT_ERROR_PARSING);
+            }
+        };
+        let content = match include::expand_includes(&content, filename, current_dir) {
+            Ok(c) => c,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::from(crate::EXIT_ERROR_PARSING);
+            }
+        };
+        let mut variables = VariableSet::from(&options.variables);
+        options.secrets.iter().for_each(|(name, value)| {
+            variables.insert_secret(name.clone(), value.clone());
+        });
+        let runner_options = options.to_runner_options(filename, current_dir);
+        let logger_options = options.to_logger_options();
+        let commands = runner::curl_commands(
+            &content,
+            Some(filename),
+            &runner_options,
+            &variables,
+            &logger_options,
+            entry_index,
+        );
+        match commands {
+            Ok(commands) => {
+                for command in &commands {
+                    println!("{command}");
+                }
+            }
+            Err(_) => return ExitCode::from(crate::EXIT_ERROR_PARSING),
+        }
+    }
+    ExitCode::from(crate::EXIT_OK)
+}
+
+/// Parses and validates Hurl `files` without executing any HTTP request.
+///
+/// Each file is parsed, and the local files referenced by its entries (file bodies, multipart
+/// files...) are checked for existence. Errors are reported on standard error; the exit code is
+/// non-zero if any file is invalid.
+pub fn dry_run(files: &[Input], current_dir: &Path, options: &CliOptions) -> ExitCode {
+    let logger_options = options.to_logger_options();
+    let mut errors_count = 0;
+
+    for filename in files {
+        let content = match filename.read_to_string() {
+            Ok(c) => c,
+            Err(error) => {
+                eprintln!("Issue reading from {filename}: {error}");
+                errors_count += 1;
+                continue;
+            }
+        };
+        let content = match include::expand_includes(&content, filename, current_dir) {
+            Ok(c) => c,
+            Err(error) => {
+                eprintln!("{error}");
+                errors_count += 1;
+                continue;
+            }
+        };
+        let stderr = Stderr::new(WriteMode::Immediate);
+        let mut logger = Logger::new(&logger_options, stderr, &[]);
+
+        // Parse with error recovery so that every syntax error of the file is reported in one
+        // pass. The entries that parsed successfully are still checked for missing files.
+        let (hurl_file, parse_errors) = parser::parse_hurl_file_with_recovery(&content);
+        for error in &parse_errors {
+            let message = error.render(
+                &filename.to_string(),
+                &content,
+                None,
+                OutputFormat::Terminal(options.color_stderr),
+            );
+            logger.error_rich(&message);
+            errors_count += 1;
+        }
+
+        // We use the same file resolution rules as a real run (see `CliOptions::to_runner_options`).
+        let file_root = match &options.file_root {
+            Some(f) => Path::new(f),
+            None => match filename.kind() {
+                InputKind::File(path) => path.parent().unwrap(),
+                InputKind::Stdin(_) => current_dir,
+            },
+        };
+        let mut context_dir = ContextDir::new(current_dir, file_root);
+        context_dir.allow_path_escape(options.allow_path_escape);
+        let mut checker = FileChecker {
+            context_dir,
+            missing: vec![],
+        };
+        checker.visit_hurl_file(&hurl_file);
+        for missing in &checker.missing {
+            logger.error_rich(&format!("file <{missing}> does not exist in {filename}\n"));
+            errors_count += 1;
+        }
+    }
+
+    if errors_count == 0 {
+        ExitCode::from(crate::EXIT_OK)
+    } else {
+        ExitCode::from(crate::EXIT_ERROR_PARSING)
+    }
+}
+
+/// Blocks until one of the `files` (or one of the files they include) changes on disk.
+///
+/// Used by `--watch` mode. The parent directories of the watched files are watched instead of the
+/// files themselves: many editors save by replacing the file, which would make a direct watch
+/// stale after the first change. Once a relevant event has been received, further events are
+/// drained during a short debounce window so that a burst of saves triggers a single rerun.
+pub fn wait_for_change(files: &[Input], current_dir: &Path) -> Result<(), String> {
+    const DEBOUNCE: Duration = Duration::from_millis(300);
+
+    let watched = watched_files(files, current_dir);
+    if watched.is_empty() {
+        return Err("no file to watch, --watch requires file inputs".to_string());
+    }
+    let mut dirs = watched
+        .iter()
+        .filter_map(|file| file.parent())
+        .map(Path::to_path_buf)
+        .collect::<Vec<_>>();
+    dirs.sort();
+    dirs.dedup();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(tx).map_err(|error| format!("Issue watching files: {error}"))?;
+    for dir in &dirs {
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(|error| format!("Issue watching {}: {error}", dir.display()))?;
+    }
+
+    loop {
+        let event = match rx.recv() {
+            Ok(Ok(event)) => event,
+            Ok(Err(error)) => return Err(format!("Issue watching files: {error}")),
+            Err(error) => return Err(format!("Issue watching files: {error}")),
+        };
+        if !(event.kind.is_modify() || event.kind.is_create() || event.kind.is_remove()) {
+            continue;
+        }
+        let relevant = event.paths.iter().any(|path| {
+            let path = fs::canonicalize(path).unwrap_or_else(|_| path.clone());
+            watched.contains(&path)
+        });
+        if !relevant {
+            continue;
+        }
+        // Debounce: coalesce the burst of events an editor save can produce.
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+        return Ok(());
+    }
+}
+
+/// Returns the files to watch for `files` inputs: the input files themselves and, recursively,
+/// the files they include.
+fn watched_files(files: &[Input], current_dir: &Path) -> Vec<PathBuf> {
+    let mut watched = vec![];
+    for file in files {
+        let InputKind::File(path) = file.kind() else {
+            continue;
+        };
+        let Ok(path) = fs::canonicalize(path) else {
+            continue;
+        };
+        if !watched.contains(&path) {
+            watched.push(path);
+        }
+        let Ok(content) = file.read_to_string() else {
+            continue;
+        };
+        for included in include::included_files(&content, file, current_dir) {
+            if !watched.contains(&included) {
+                watched.push(included);
+            }
+        }
+    }
+    watched
+}
+
+/// An AST visitor that checks the existence of the files referenced by a Hurl file.
+struct FileChecker {
+    context_dir: ContextDir,
+    missing: Vec<String>,
+}
+
+impl Visitor for FileChecker {
+    fn visit_filename(&mut self, filename: &Template) {
+        // Templatized filenames can only be resolved at run time, they are not checked.
+        let mut value = String::new();
+        for element in &filename.elements {
+            match element {
+                TemplateElement::String { value: s, .. } => value.push_str(s),
+                TemplateElement::Placeholder(_) => return,
+            }
+        }
+        let path = Path::new(&value);
+        if !self.context_dir.resolved_path(path).exists() {
+            self.missing.push(value);
+        }
+    }
+
+    fn visit_entry_option(&mut self, _option: &EntryOption) {
+        // Filenames in options (output, certificates, netrc file...) are not all input files,
+        // they are not checked.
+    }
+}
+
 /// Prints a `hurl_result` either as a raw HTTP response (last body of the run), or in a structured
 /// JSON way.
 ///
@@ -161,16 +161,19 @@ pub fn eval_explicit_assert(
         query_result
     } else if let Ok(optional_value) = query_result {
         match optional_value {
-            None => Err(RunnerError {
-                source_info: assert
+            None => {
+                let source_info = assert
                     .filters
                     .first()
                     .expect("at least one filter")
                     .1
-                    .source_info,
-                kind: RunnerErrorKind::FilterMissingInput,
-                assert: true,
-            }),
+                    .source_info;
+                Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::FilterMissingInput,
+                    true,
+                ))
+            }
             Some(value) => {
                 let filters = assert.filters.iter().map(|(_, f)| f).collect::<Vec<_>>();
                 match eval_filters(&filters, &value, variables, true) {
@@ -20,8 +20,11 @@ use std::collections::HashMap;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
 
 use hurl_core::ast::{BindingExpr, BindingParam, SourceInfo};
+use hurl_core::reader::Pos;
 
 use crate::util::path::ContextDir;
 
@@ -30,17 +33,73 @@ use super::template;
 use super::value::Value;
 use super::variable::VariableSet;
 
+/// Hint attached to file read errors on a binding.
+const FILE_BINDING_HINT: &str =
+    "the file path is resolved against the directory set by --file-root";
+
+/// Per-path locks serialising accesses to bound files: parallel workers can
+/// bind variables to the same file, updates must not interleave.
+static FILE_LOCKS: OnceLock<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
+
+/// Returns the lock dedicated to `file_path`.
+fn file_lock(file_path: &Path) -> Arc<Mutex<()>> {
+    let locks = FILE_LOCKS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut locks = locks.lock().unwrap();
+    locks
+        .entry(file_path.to_path_buf())
+        .or_insert_with(|| Arc::new(Mutex::new(())))
+        .clone()
+}
+
+/// Target of a variable binding.
+#[derive(Clone, Debug)]
+pub enum BoundTarget {
+    /// The variable is synced to a whole file.
+    File(PathBuf),
+    /// The variable is a directory binding: every file of the directory is
+    /// synced to a variable named `<binding>.<filename>`.
+    Dir(PathBuf),
+    /// The variable is synced to the key named after the variable, inside a
+    /// shared JSON object file.
+    JsonFile(PathBuf),
+    /// The variable is synced to the key named after the variable, inside a
+    /// shared YAML mapping file.
+    YamlFile(PathBuf),
+    /// The variable is kept in memory only, without touching the filesystem.
+    Memory,
+    /// The variable is synced to the OS environment variable of this name.
+    Env(String),
+}
+
+/// Sentinel filename selecting the in-memory binding mode.
+const MEMORY_SENTINEL: &str = ":memory:";
+
 /// Tracks which variables are synced to which files
 #[derive(Clone, Debug, Default)]
 pub struct BoundVariables {
-    /// Maps variable name to file path
-    pub mappings: HashMap<String, String>,
+    /// Maps variable name to its binding target
+    pub mappings: HashMap<String, BoundTarget>,
+    /// Values of variables bound in memory (`:memory:` target)
+    memory: HashMap<String, Value>,
+    /// When set, the previous content of a bound file is saved to `<path>.bak`
+    /// before being overwritten
+    pub backup: bool,
+    /// When set, a bound file modified by another process between two writes of
+    /// this runner raises an error instead of being silently overwritten
+    pub detect_external_changes: bool,
+    /// Modification times of bound files after our own writes, used to detect
+    /// external changes
+    mtimes: HashMap<PathBuf, SystemTime>,
 }
 
 impl BoundVariables {
     pub fn new() -> Self {
         BoundVariables {
             mappings: HashMap::new(),
+            memory: HashMap::new(),
+            backup: false,
+            detect_external_changes: false,
+            mtimes: HashMap::new(),
         }
     }
 
@@ -54,18 +113,72 @@ impl BoundVariables {
         for param in binding_params {
             // Render the variable name
             let var_name = template::eval_template(&param.name, variables)?;
+            let source_info = param.name.source_info;
 
             match &param.value {
                 BindingExpr::File { filename, .. } => {
                     // Render the filename (supports template variables like {env})
                     let filename = template::eval_template(filename, variables)?;
 
+                    // `:memory:` selects the in-memory mode: no filesystem access
+                    if filename == MEMORY_SENTINEL {
+                        self.mappings.insert(var_name.clone(), BoundTarget::Memory);
+                        if let Some(value) = self.memory.get(&var_name) {
+                            variables.insert(var_name, value.clone());
+                        }
+                        continue;
+                    }
+
                     // Convert to path relative to context_dir
                     let file_path = context_dir.resolved_path(Path::new(&filename));
 
+                    // A directory binds every file it contains: each file becomes
+                    // a variable named `<binding>.<filename>`, synced back to
+                    // that file.
+                    if file_path.is_dir() {
+                        self.mappings
+                            .insert(var_name.clone(), BoundTarget::Dir(file_path.clone()));
+                        let read_error = || {
+                            RunnerError::new(
+                                source_info,
+                                RunnerErrorKind::FileReadAccess {
+                                    path: file_path.clone(),
+                                },
+                                false,
+                            )
+                            .with_hint(FILE_BINDING_HINT)
+                        };
+                        let mut paths = fs::read_dir(&file_path)
+                            .map_err(|_| read_error())?
+                            .filter_map(|entry| entry.ok().map(|e| e.path()))
+                            .filter(|path| path.is_file())
+                            .collect::<Vec<_>>();
+                        paths.sort();
+                        for path in paths {
+                            let Some(filename) = path.file_name().and_then(|f| f.to_str())
+                            else {
+                                continue;
+                            };
+                            let name = format!("{var_name}.{filename}");
+                            self.mappings
+                                .insert(name.clone(), BoundTarget::File(path.clone()));
+                            let content = fs::read_to_string(&path).map_err(|_| {
+                                RunnerError::new(
+                                    source_info,
+                                    RunnerErrorKind::FileReadAccess { path: path.clone() },
+                                    false,
+                                )
+                                .with_hint(FILE_BINDING_HINT)
+                            })?;
+                            let content = content.trim_end_matches('\n').to_string();
+                            variables.insert(name, Value::String(content));
+                        }
+                        continue;
+                    }
+
                     // Always store/update the mapping
                     self.mappings
-                        .insert(var_name.clone(), file_path.to_string_lossy().to_string());
+                        .insert(var_name.clone(), BoundTarget::File(file_path.clone()));
 
                     // Try to load the file content into the variable (only if file exists)
                     if file_path.exists() {
@@ -75,117 +188,427 @@ impl BoundVariables {
                                 variables.insert(var_name, Value::String(content));
                             }
                             Err(_e) => {
-                                let source_info = param.name.source_info;
                                 return Err(RunnerError::new(
                                     source_info,
                                     RunnerErrorKind::FileReadAccess {
                                         path: file_path.clone(),
                                     },
                                     false,
-                                ));
+                                )
+                                .with_hint(FILE_BINDING_HINT));
                             }
                         }
                     }
                 }
+                BindingExpr::JsonFile { filename, .. } => {
+                    let filename = template::eval_template(filename, variables)?;
+                    let file_path = context_dir.resolved_path(Path::new(&filename));
+
+                    // The binding name itself is always synced, even when the file
+                    // doesn't exist yet (first run).
+                    self.mappings
+                        .insert(var_name.clone(), BoundTarget::JsonFile(file_path.clone()));
+
+                    let object = read_json_object(&file_path, source_info)?;
+                    for (key, value) in &object {
+                        // Every key of the JSON object becomes a variable, synced
+                        // back to the same file.
+                        self.mappings
+                            .insert(key.clone(), BoundTarget::JsonFile(file_path.clone()));
+                        variables.insert(key.clone(), Value::from_json(value));
+                    }
+                }
+                BindingExpr::YamlFile { filename, .. } => {
+                    let filename = template::eval_template(filename, variables)?;
+                    let file_path = context_dir.resolved_path(Path::new(&filename));
+
+                    self.mappings
+                        .insert(var_name.clone(), BoundTarget::YamlFile(file_path.clone()));
+
+                    let mapping = read_yaml_mapping(&file_path, source_info)?;
+                    for (key, value) in &mapping {
+                        // Every key of the YAML mapping becomes a variable, synced
+                        // back to the same file.
+                        self.mappings
+                            .insert(key.clone(), BoundTarget::YamlFile(file_path.clone()));
+                        variables.insert(key.clone(), Value::from_json(value));
+                    }
+                }
+                BindingExpr::Env {
+                    var_name: env_name, ..
+                } => {
+                    let env_name = template::eval_template(env_name, variables)?;
+                    self.mappings
+                        .insert(var_name.clone(), BoundTarget::Env(env_name.clone()));
+
+                    // An unset environment variable leaves the binding empty, like
+                    // a file binding on a missing file.
+                    if let Ok(value) = std::env::var(&env_name) {
+                        variables.insert(var_name, Value::String(value));
+                    }
+                }
             }
         }
         Ok(())
     }
 
+    /// Checks that every bound file path is writable, returning one error per problematic path.
+    ///
+    /// An existing file is probed with an append-mode open so it's not truncated; a missing file
+    /// is created then removed, checking that the parent directory allows creation.
+    pub fn validate_writable(&self) -> Vec<RunnerError> {
+        let mut paths = self
+            .mappings
+            .values()
+            .filter_map(|target| match target {
+                BoundTarget::File(path)
+                | BoundTarget::JsonFile(path)
+                | BoundTarget::YamlFile(path) => Some(path.clone()),
+                // Files under a directory binding are registered individually.
+                BoundTarget::Dir(_) | BoundTarget::Memory | BoundTarget::Env(_) => None,
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths.dedup();
+
+        let mut errors = vec![];
+        for path in paths {
+            let exists = path.exists();
+            match fs::OpenOptions::new().append(true).create(true).open(&path) {
+                Ok(file) => {
+                    drop(file);
+                    // The probe created the file, remove it.
+                    if !exists {
+                        let _ = fs::remove_file(&path);
+                    }
+                }
+                Err(error) => {
+                    // There is no per-path source information in the mappings, errors point to
+                    // the start of the file (the global `[Bindings]` section).
+                    let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+                    let kind = RunnerErrorKind::FileWriteAccess {
+                        path: path.clone(),
+                        error: error.to_string(),
+                    };
+                    errors.push(
+                        RunnerError::new(source_info, kind, false).with_hint(FILE_BINDING_HINT),
+                    );
+                }
+            }
+        }
+        errors
+    }
+
     /// Writes a variable to its synced file if it's registered
+    ///
+    /// A secret variable (`is_secret`) is never backed up: the `.bak` file would keep a plain
+    /// text copy of the previous secret around after the run.
     pub fn bind_variable(
-        &self,
+        &mut self,
         var_name: &str,
         value: &Value,
+        is_secret: bool,
         source_info: SourceInfo,
     ) -> Result<(), RunnerError> {
-        if let Some(file_path) = self.mappings.get(var_name) {
-            let value_str = match value {
-                Value::String(s) => s.clone(),
-                other => other.to_string(),
-            };
-
-            // Create parent directories if needed
-            let path = Path::new(file_path);
-            if let Some(parent) = path.parent() {
-                if !parent.exists() {
-                    fs::create_dir_all(parent).map_err(|e| {
-                        RunnerError::new(
-                            source_info,
-                            RunnerErrorKind::FileWriteAccess {
-                                path: PathBuf::from(file_path),
-                                error: e.to_string(),
-                            },
-                            false,
-                        )
-                    })?;
+        match self.mappings.get(var_name) {
+            Some(BoundTarget::File(file_path)) => {
+                let file_path = file_path.clone();
+                self.write_bound_file(&file_path, value, is_secret, source_info)?;
+            }
+            Some(BoundTarget::Dir(_)) => {
+                // The directory binding itself holds no value: only the
+                // `<binding>.<filename>` variables are synced.
+            }
+            Some(BoundTarget::JsonFile(file_path)) => {
+                // Read the current object, update the key for this variable and
+                // write the whole object back: keys bound by other entries are
+                // left untouched.
+                let file_path = file_path.clone();
+                let lock = file_lock(&file_path);
+                let _guard = lock.lock().unwrap();
+                self.check_external_change(&file_path, source_info)?;
+                let mut object = read_json_object(&file_path, source_info)?;
+                object.insert(var_name.to_string(), value.to_json(&[]));
+                let mut content = serde_json::to_string_pretty(&object).unwrap();
+                content.push('\n');
+                if self.backup && !is_secret {
+                    backup_file(&file_path, source_info)?;
                 }
+                write_file_atomic(&file_path, content.as_bytes(), source_info)?;
+                self.record_mtime(&file_path);
             }
-
-            // Atomic write: write to temp file, then rename
-            let temp_path = format!("{}.tmp", file_path);
-            let mut file = fs::File::create(&temp_path).map_err(|e| {
-                RunnerError::new(
-                    source_info,
-                    RunnerErrorKind::FileWriteAccess {
-                        path: PathBuf::from(file_path),
-                        error: e.to_string(),
-                    },
-                    false,
-                )
-            })?;
-
-            file.write_all(value_str.as_bytes()).map_err(|e| {
-                RunnerError::new(
-                    source_info,
-                    RunnerErrorKind::FileWriteAccess {
-                        path: PathBuf::from(file_path),
-                        error: e.to_string(),
-                    },
-                    false,
-                )
-            })?;
-
-            // Ensure data is written to disk
-            file.sync_all().map_err(|e| {
-                RunnerError::new(
-                    source_info,
-                    RunnerErrorKind::FileWriteAccess {
-                        path: PathBuf::from(file_path),
-                        error: e.to_string(),
-                    },
-                    false,
-                )
-            })?;
-
-            drop(file);
-
-            // Atomic rename
-            fs::rename(&temp_path, file_path).map_err(|e| {
-                RunnerError::new(
-                    source_info,
-                    RunnerErrorKind::FileWriteAccess {
-                        path: PathBuf::from(file_path),
-                        error: e.to_string(),
-                    },
-                    false,
-                )
-            })?;
-
-            // Set restrictive permissions (600 - owner read/write only)
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::PermissionsExt;
-                let mut perms = fs::metadata(file_path).unwrap().permissions();
-                perms.set_mode(0o600);
-                let _ = fs::set_permissions(file_path, perms);
+            Some(BoundTarget::YamlFile(file_path)) => {
+                let file_path = file_path.clone();
+                let lock = file_lock(&file_path);
+                let _guard = lock.lock().unwrap();
+                self.check_external_change(&file_path, source_info)?;
+                let mut mapping = read_yaml_mapping(&file_path, source_info)?;
+                mapping.insert(var_name.to_string(), value.to_json(&[]));
+                let mapping = json_to_yaml(&serde_json::Value::Object(mapping));
+                let content = serde_yaml::to_string(&mapping).unwrap();
+                if self.backup && !is_secret {
+                    backup_file(&file_path, source_info)?;
+                }
+                write_file_atomic(&file_path, content.as_bytes(), source_info)?;
+                self.record_mtime(&file_path);
+            }
+            Some(BoundTarget::Memory) => {
+                self.memory.insert(var_name.to_string(), value.clone());
+            }
+            Some(BoundTarget::Env(env_name)) => {
+                // Binary values are written as text, like non-string values.
+                let content = match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                std::env::set_var(env_name, content);
+            }
+            None => {
+                // A variable under a directory binding (`<binding>.<filename>`)
+                // is synced to the matching file, created on first write.
+                if let Some(file_path) = self.dir_file(var_name) {
+                    self.write_bound_file(&file_path, value, is_secret, source_info)?;
+                }
             }
         }
         Ok(())
     }
 
+    /// Writes `value` to the bound file `file_path`.
+    fn write_bound_file(
+        &mut self,
+        file_path: &Path,
+        value: &Value,
+        is_secret: bool,
+        source_info: SourceInfo,
+    ) -> Result<(), RunnerError> {
+        // Binary values are written verbatim, everything else as text.
+        let bytes = match value {
+            Value::Bytes(bytes) => bytes.clone(),
+            Value::String(s) => s.clone().into_bytes(),
+            other => other.to_string().into_bytes(),
+        };
+        let lock = file_lock(file_path);
+        let _guard = lock.lock().unwrap();
+        self.check_external_change(file_path, source_info)?;
+        if self.backup && !is_secret {
+            backup_file(file_path, source_info)?;
+        }
+        write_file_atomic(file_path, &bytes, source_info)?;
+        self.record_mtime(file_path);
+        Ok(())
+    }
+
+    /// Returns an error if `file_path` was modified by another process since our last write.
+    fn check_external_change(
+        &self,
+        file_path: &Path,
+        source_info: SourceInfo,
+    ) -> Result<(), RunnerError> {
+        if !self.detect_external_changes {
+            return Ok(());
+        }
+        let Some(last_mtime) = self.mtimes.get(file_path) else {
+            // No write of ours yet, nothing to compare against.
+            return Ok(());
+        };
+        // A deleted file (no readable mtime) counts as an external change too.
+        let mtime = fs::metadata(file_path).and_then(|m| m.modified()).ok();
+        if mtime != Some(*last_mtime) {
+            let kind = RunnerErrorKind::ConcurrentFileModification {
+                path: file_path.to_path_buf(),
+            };
+            return Err(RunnerError::new(source_info, kind, false));
+        }
+        Ok(())
+    }
+
+    /// Records the on-disk modification time of `file_path` after our own write.
+    ///
+    /// The mtime is read back after [`write_file_atomic`] has synced and renamed the file, so
+    /// the stored value can't race with our own write.
+    fn record_mtime(&mut self, file_path: &Path) {
+        if !self.detect_external_changes {
+            return;
+        }
+        if let Ok(mtime) = fs::metadata(file_path).and_then(|m| m.modified()) {
+            self.mtimes.insert(file_path.to_path_buf(), mtime);
+        }
+    }
+
+    /// Resolves a variable named `<binding>.<filename>` to its file, when
+    /// `<binding>` is bound to a directory.
+    fn dir_file(&self, var_name: &str) -> Option<PathBuf> {
+        let (prefix, filename) = var_name.split_once('.')?;
+        match self.mappings.get(prefix) {
+            Some(BoundTarget::Dir(dir)) => Some(dir.join(filename)),
+            _ => None,
+        }
+    }
+
     /// Returns true if a variable is registered for syncing
     pub fn is_bound(&self, var_name: &str) -> bool {
-        self.mappings.contains_key(var_name)
+        self.mappings.contains_key(var_name) || self.dir_file(var_name).is_some()
+    }
+}
+
+/// Reads `file_path` as a JSON object, a missing file is treated as an empty object.
+fn read_json_object(
+    file_path: &Path,
+    source_info: SourceInfo,
+) -> Result<serde_json::Map<String, serde_json::Value>, RunnerError> {
+    if !file_path.exists() {
+        return Ok(serde_json::Map::new());
+    }
+    let content = fs::read_to_string(file_path).map_err(|_| {
+        RunnerError::new(
+            source_info,
+            RunnerErrorKind::FileReadAccess {
+                path: file_path.to_path_buf(),
+            },
+            false,
+        )
+        .with_hint(FILE_BINDING_HINT)
+    })?;
+    match serde_json::from_str::<serde_json::Value>(&content) {
+        Ok(serde_json::Value::Object(object)) => Ok(object),
+        _ => Err(RunnerError::new(
+            source_info,
+            RunnerErrorKind::InvalidJson { value: content },
+            false,
+        )),
+    }
+}
+
+/// Reads `file_path` as a YAML mapping, a missing file is treated as an empty mapping.
+///
+/// The mapping is deserialized to JSON values so keys and values follow the same
+/// conversion rules as JSON object files (see [`read_json_object`]).
+fn read_yaml_mapping(
+    file_path: &Path,
+    source_info: SourceInfo,
+) -> Result<serde_json::Map<String, serde_json::Value>, RunnerError> {
+    if !file_path.exists() {
+        return Ok(serde_json::Map::new());
+    }
+    let content = fs::read_to_string(file_path).map_err(|_| {
+        RunnerError::new(
+            source_info,
+            RunnerErrorKind::FileReadAccess {
+                path: file_path.to_path_buf(),
+            },
+            false,
+        )
+        .with_hint(FILE_BINDING_HINT)
+    })?;
+    match serde_yaml::from_str::<serde_json::Value>(&content) {
+        Ok(serde_json::Value::Object(mapping)) => Ok(mapping),
+        _ => Err(RunnerError::new(
+            source_info,
+            RunnerErrorKind::InvalidYaml { value: content },
+            false,
+        )),
+    }
+}
+
+/// Converts a JSON value to a YAML value.
+///
+/// The conversion is explicit because serde_json numbers (with the `arbitrary_precision`
+/// feature) don't serialize transparently through the YAML serializer.
+fn json_to_yaml(value: &serde_json::Value) -> serde_yaml::Value {
+    match value {
+        serde_json::Value::Null => serde_yaml::Value::Null,
+        serde_json::Value::Bool(value) => serde_yaml::Value::Bool(*value),
+        serde_json::Value::Number(number) => {
+            if let Some(value) = number.as_i64() {
+                serde_yaml::Value::Number(value.into())
+            } else if let Some(value) = number.as_f64() {
+                serde_yaml::Value::Number(value.into())
+            } else {
+                serde_yaml::Value::String(number.to_string())
+            }
+        }
+        serde_json::Value::String(value) => serde_yaml::Value::String(value.clone()),
+        serde_json::Value::Array(items) => {
+            serde_yaml::Value::Sequence(items.iter().map(json_to_yaml).collect())
+        }
+        serde_json::Value::Object(map) => {
+            let mut mapping = serde_yaml::Mapping::new();
+            for (key, value) in map {
+                mapping.insert(serde_yaml::Value::String(key.clone()), json_to_yaml(value));
+            }
+            serde_yaml::Value::Mapping(mapping)
+        }
+    }
+}
+
+/// Saves the current content of `file_path` to `<file_path>.bak`.
+///
+/// A missing file needs no backup. The backup itself goes through the temp-rename strategy of
+/// [`write_file_atomic`] so a concurrent process watching the directory can't see a torn `.bak`
+/// file. An error leaves the original file untouched.
+fn backup_file(file_path: &Path, source_info: SourceInfo) -> Result<(), RunnerError> {
+    if !file_path.exists() {
+        return Ok(());
+    }
+    let content = fs::read(file_path).map_err(|_| {
+        RunnerError::new(
+            source_info,
+            RunnerErrorKind::FileReadAccess {
+                path: file_path.to_path_buf(),
+            },
+            false,
+        )
+        .with_hint(FILE_BINDING_HINT)
+    })?;
+    let backup_path = PathBuf::from(format!("{}.bak", file_path.display()));
+    write_file_atomic(&backup_path, &content, source_info)
+}
+
+/// Writes `data` to `file_path`, using a temp file then rename so a crashed run
+/// can't leave a half-written file behind.
+fn write_file_atomic(
+    file_path: &Path,
+    data: &[u8],
+    source_info: SourceInfo,
+) -> Result<(), RunnerError> {
+    let write_error = |e: std::io::Error| {
+        RunnerError::new(
+            source_info,
+            RunnerErrorKind::FileWriteAccess {
+                path: file_path.to_path_buf(),
+                error: e.to_string(),
+            },
+            false,
+        )
+    };
+
+    // Create parent directories if needed
+    if let Some(parent) = file_path.parent() {
+        if !parent.exists() {
+            fs::create_dir_all(parent).map_err(write_error)?;
+        }
+    }
+
+    // Atomic write: write to temp file, then rename
+    let temp_path = PathBuf::from(format!("{}.tmp", file_path.display()));
+    let mut file = fs::File::create(&temp_path).map_err(write_error)?;
+    file.write_all(data).map_err(write_error)?;
+
+    // Ensure data is written to disk
+    file.sync_all().map_err(write_error)?;
+    drop(file);
+
+    // Atomic rename
+    fs::rename(&temp_path, file_path).map_err(write_error)?;
+
+    // Set restrictive permissions (600 - owner read/write only)
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(file_path).unwrap().permissions();
+        perms.set_mode(0o600);
+        let _ = fs::set_permissions(file_path, perms);
     }
+    Ok(())
 }
@@ -27,6 +27,12 @@ pub struct BodyCache {
     xml: Option<xpath::Document>,
     /// The parsed JSON body
     json: Option<serde_json::Value>,
+    /// The parsed CSV body, as a list of records.
+    csv: Option<Vec<Vec<String>>>,
+    /// Additional XPath namespaces, as (prefix, URI) pairs, from the `xpath-namespace` option.
+    xpath_namespaces: Vec<(String, String)>,
+    /// The CSV field separator, from the `csv-separator` option.
+    csv_separator: Option<u8>,
 }
 
 impl BodyCache {
@@ -45,6 +51,16 @@ impl BodyCache {
         self.xml = Some(xml);
     }
 
+    /// Returns the additional XPath namespaces used for XPath queries.
+    pub fn xpath_namespaces(&self) -> &[(String, String)] {
+        &self.xpath_namespaces
+    }
+
+    /// Sets the additional XPath namespaces, as (prefix, URI) pairs.
+    pub fn set_xpath_namespaces(&mut self, namespaces: &[(String, String)]) {
+        self.xpath_namespaces = namespaces.to_vec();
+    }
+
     /// Returns a reference to a cached JSON response.
     pub fn json(&self) -> Option<&serde_json::Value> {
         self.json.as_ref()
@@ -54,6 +70,26 @@ impl BodyCache {
     pub fn set_json(&mut self, json: serde_json::Value) {
         self.json = Some(json);
     }
+
+    /// Returns a reference to a cached CSV response.
+    pub fn csv(&self) -> Option<&Vec<Vec<String>>> {
+        self.csv.as_ref()
+    }
+
+    /// Caches a parsed CSV, as a list of `records`.
+    pub fn set_csv(&mut self, records: Vec<Vec<String>>) {
+        self.csv = Some(records);
+    }
+
+    /// Returns the CSV field separator used for CSV queries (a comma by default).
+    pub fn csv_separator(&self) -> u8 {
+        self.csv_separator.unwrap_or(b',')
+    }
+
+    /// Sets the CSV field separator.
+    pub fn set_csv_separator(&mut self, separator: Option<u8>) {
+        self.csv_separator = separator;
+    }
 }
 
 #[cfg(test)]
@@ -73,7 +109,7 @@ mod tests {
                     </html>";
         let doc = Document::parse(html, Format::Html).unwrap();
         assert_eq!(
-            doc.eval_xpath("string(//h1)").unwrap(),
+            doc.eval_xpath("string(//h1)", &[]).unwrap(),
             Value::String("My First Heading".to_string())
         );
 
@@ -83,7 +119,7 @@ mod tests {
         cache.set_xml(doc);
         let doc = cache.xml().unwrap();
         assert_eq!(
-            doc.eval_xpath("string(//h1)").unwrap(),
+            doc.eval_xpath("string(//h1)", &[]).unwrap(),
             Value::String("My First Heading".to_string())
         );
     }
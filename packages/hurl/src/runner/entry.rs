@@ -21,10 +21,11 @@ use hurl_core::ast::{
 use hurl_core::types::Index;
 
 use crate::http;
-use crate::http::{ClientOptions, CurlCmd};
+use crate::http::{ClientOptions, CurlCmd, HttpError};
 use crate::util::logger::{Logger, Verbosity};
 use crate::util::term::WriteMode;
 
+use super::auth;
 use super::cache::BodyCache;
 use super::error::{RunnerError, RunnerErrorKind};
 use super::request;
@@ -40,6 +41,7 @@ use super::variable::VariableSet;
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
 /// `variables` are used to render values at runtime, and can be updated by captures.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     entry: &Entry,
     entry_index: Index,
@@ -47,6 +49,7 @@ pub fn run(
     variables: &mut VariableSet,
     bound_variables: &mut BoundVariables,
     runner_options: &RunnerOptions,
+    previous_url: Option<&http::Url>,
     logger: &mut Logger,
 ) -> EntryResult {
     let compressed = runner_options.compressed;
@@ -77,20 +80,34 @@ pub fn run(
     }
 
     // Evaluates our source requests given our set of variables
-    let http_request = match request::eval_request(&entry.request, variables, context_dir) {
-        Ok(r) => r,
-        Err(error) => {
-            return EntryResult {
-                entry_index,
-                source_info,
-                errors: vec![error],
-                compressed,
-                ..Default::default()
-            };
+    let mut http_request =
+        match request::eval_request(&entry.request, variables, context_dir, previous_url) {
+            Ok(r) => r,
+            Err(error) => {
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    errors: vec![error],
+                    compressed,
+                    ..Default::default()
+                };
+            }
+        };
+
+    let mut client_options = ClientOptions::from(runner_options, logger.verbosity);
+
+    // When AWS signature V4 is requested without explicit credentials (`--user`), credentials
+    // are resolved from variables or from the environment.
+    if client_options.aws_sigv4.is_some() && client_options.user.is_none() {
+        if let Some(credentials) = auth::aws::resolve_credentials(variables) {
+            client_options.user = Some(credentials.to_user());
+            if let Some(session_token) = &credentials.session_token {
+                http_request
+                    .headers
+                    .push(http::Header::new("X-Amz-Security-Token", session_token));
+            }
         }
-    };
-
-    let client_options = ClientOptions::from(runner_options, logger.verbosity);
+    }
 
     // Experimental features with cookie storage
     if let Some(s) = request::get_cmd_cookie_storage_set(&entry.request) {
@@ -121,8 +138,13 @@ pub fn run(
             let start = entry.request.url.source_info.start;
             let end = entry.request.url.source_info.end;
             let error_source_info = SourceInfo::new(start, end);
-            let error =
-                RunnerError::new(error_source_info, RunnerErrorKind::Http(http_error), false);
+            let kind = match http_error {
+                HttpError::AllowedResponseSizeExceeded { limit, actual } => {
+                    RunnerErrorKind::ResponseBodyTooLarge { limit, actual }
+                }
+                _ => RunnerErrorKind::Http(http_error),
+            };
+            let error = RunnerError::new(error_source_info, kind, false);
             return EntryResult {
                 entry_index,
                 source_info,
@@ -146,6 +168,8 @@ pub fn run(
     // 2. then, we compute captures, we might need them in asserts
     // 3. finally, run the remaining asserts
     let mut cache = BodyCache::new();
+    cache.set_xpath_namespaces(&runner_options.xpath_namespaces);
+    cache.set_csv_separator(runner_options.csv_separator);
     let mut asserts = vec![];
 
     if !runner_options.ignore_asserts {
@@ -269,13 +293,15 @@ fn asserts_to_errors(asserts: &[AssertResult]) -> Vec<RunnerError> {
 }
 
 impl ClientOptions {
-    fn from(runner_options: &RunnerOptions, verbosity: Option<Verbosity>) -> Self {
+    pub(crate) fn from(runner_options: &RunnerOptions, verbosity: Option<Verbosity>) -> Self {
         ClientOptions {
             allow_reuse: runner_options.allow_reuse,
             aws_sigv4: runner_options.aws_sigv4.clone(),
+            cacert_append_file: runner_options.cacert_append_file.clone(),
             cacert_file: runner_options.cacert_file.clone(),
             client_cert_file: runner_options.client_cert_file.clone(),
             client_key_file: runner_options.client_key_file.clone(),
+            compress: runner_options.compress,
             compressed: runner_options.compressed,
             connect_timeout: runner_options.connect_timeout,
             connects_to: runner_options.connects_to.clone(),
@@ -461,7 +487,12 @@ fn write_bound_captures(
         let var_name = &capture.name;
         if bound_variables.is_bound(var_name) {
             if let Some(variable) = variables.get(var_name) {
-                bound_variables.bind_variable(var_name, variable.value(), source_info)?;
+                bound_variables.bind_variable(
+                    var_name,
+                    variable.value(),
+                    variable.is_secret(),
+                    source_info,
+                )?;
             }
         }
     }
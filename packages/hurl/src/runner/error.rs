@@ -36,16 +36,67 @@ pub struct RunnerError {
     pub source_info: SourceInfo,
     pub kind: RunnerErrorKind,
     pub assert: bool,
+    pub severity: Severity,
+    /// Optional suggestion to fix the error (like a close variable name, or the CLI option to
+    /// use), displayed under the main error message. Boxed to keep the error, carried in many
+    /// `Result` returns, small.
+    pub hint: Option<Box<str>>,
+}
+
+/// Severity of a [`RunnerError`]: warnings are reported but don't abort the run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
 }
 
 impl RunnerError {
     pub fn new(source_info: SourceInfo, kind: RunnerErrorKind, assert: bool) -> RunnerError {
         RunnerError {
-            source_info,
+            source_info: well_formed(source_info),
             kind,
             assert,
+            severity: Severity::Error,
+            hint: None,
         }
     }
+
+    /// Creates a new non-fatal error: it's reported to the user but the run carries on,
+    /// and the exit code is not affected.
+    pub fn new_warning(source_info: SourceInfo, kind: RunnerErrorKind) -> RunnerError {
+        RunnerError {
+            source_info: well_formed(source_info),
+            kind,
+            assert: false,
+            severity: Severity::Warning,
+            hint: None,
+        }
+    }
+
+    /// Attaches a `hint` to this error, a suggestion to fix it displayed under the main
+    /// error message.
+    pub fn with_hint(mut self, hint: &str) -> RunnerError {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Returns `true` if this error is a non-fatal warning.
+    pub fn is_warning(&self) -> bool {
+        self.severity == Severity::Warning
+    }
+}
+
+/// Returns a well-formed source range, where `end` is never before `start`.
+///
+/// Consumers of runner errors (LSP diagnostics, reports) rely on `start..end` being a valid
+/// range to highlight the offending span: a reversed range is clamped to the single `start`
+/// position.
+fn well_formed(source_info: SourceInfo) -> SourceInfo {
+    if source_info.end < source_info.start {
+        SourceInfo::new(source_info.start, source_info.start)
+    } else {
+        source_info
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -72,6 +123,10 @@ pub enum RunnerErrorKind {
     AssertVersion {
         actual: String,
     },
+    /// A bound file was modified by another process between two writes of this runner.
+    ConcurrentFileModification {
+        path: PathBuf,
+    },
     ExpressionInvalidType {
         value: String,
         expecting: String,
@@ -100,12 +155,22 @@ pub enum RunnerErrorKind {
         value: String,
     },
     InvalidRegex,
+    InvalidSemver {
+        value: String,
+    },
     InvalidUrl {
         url: String,
         message: String,
     },
     /// A XPath expression evaluation raised an error.
     InvalidXPathEval,
+    InvalidYaml {
+        value: String,
+    },
+    /// All the retries triggered by a `retry-on-status` option are exhausted.
+    MaxRetriesExceeded {
+        status: u32,
+    },
     /// One filter in the filter chains doesn't return value.
     NoFilterResult,
     /// A query on response doesn't return value.
@@ -117,6 +182,12 @@ pub enum RunnerErrorKind {
     },
     QueryInvalidXml,
     QueryInvalidJson,
+    QueryInvalidCsv,
+    /// The response body size exceeds the maximum allowed size (see `--max-filesize`).
+    ResponseBodyTooLarge {
+        limit: u64,
+        actual: u64,
+    },
     TemplateVariableNotDefined {
         name: String,
     },
@@ -129,6 +200,61 @@ pub enum RunnerErrorKind {
     UnrenderableExpression {
         value: String,
     },
+    /// Two variable scopes define the same variables, see [`crate::runner::MergePolicy`].
+    VariableConflict {
+        names: Vec<String>,
+    },
+}
+
+impl RunnerErrorKind {
+    /// Returns the stable error code of this error kind, for programmatic consumption (see
+    /// `--error-format json`).
+    ///
+    /// Codes are grouped by family (`E1xxx` file access, `E2xxx` asserts, `E3xxx` queries,
+    /// `E4xxx` filters, `E5xxx` templates and variables, `E6xxx` values, `E7xxx` HTTP). They're
+    /// frozen once published: a new variant gets a new code, existing codes are never reused.
+    pub fn code(&self) -> &'static str {
+        match self {
+            RunnerErrorKind::FileReadAccess { .. } => "E1001",
+            RunnerErrorKind::FileWriteAccess { .. } => "E1002",
+            RunnerErrorKind::UnauthorizedFileAccess { .. } => "E1003",
+            RunnerErrorKind::ConcurrentFileModification { .. } => "E1004",
+            RunnerErrorKind::AssertFailure { .. } => "E2001",
+            RunnerErrorKind::AssertBodyValueError { .. } => "E2002",
+            RunnerErrorKind::AssertBodyDiffError { .. } => "E2003",
+            RunnerErrorKind::AssertHeaderValueError { .. } => "E2004",
+            RunnerErrorKind::AssertStatus { .. } => "E2005",
+            RunnerErrorKind::AssertVersion { .. } => "E2006",
+            RunnerErrorKind::NoQueryResult => "E3001",
+            RunnerErrorKind::QueryHeaderNotFound => "E3002",
+            RunnerErrorKind::QueryInvalidJsonpathExpression { .. } => "E3003",
+            RunnerErrorKind::QueryInvalidJson => "E3004",
+            RunnerErrorKind::QueryInvalidXml => "E3005",
+            RunnerErrorKind::InvalidXPathEval => "E3006",
+            RunnerErrorKind::QueryInvalidCsv => "E3007",
+            RunnerErrorKind::NoFilterResult => "E4001",
+            RunnerErrorKind::FilterMissingInput => "E4002",
+            RunnerErrorKind::FilterInvalidInput { .. } => "E4003",
+            RunnerErrorKind::FilterDecode { .. } => "E4004",
+            RunnerErrorKind::FilterInvalidEncoding { .. } => "E4005",
+            RunnerErrorKind::FilterDateParsingError { .. } => "E4006",
+            RunnerErrorKind::FilterInvalidFormatSpecifier { .. } => "E4007",
+            RunnerErrorKind::TemplateVariableNotDefined { .. } => "E5001",
+            RunnerErrorKind::UnrenderableExpression { .. } => "E5002",
+            RunnerErrorKind::ExpressionInvalidType { .. } => "E5003",
+            RunnerErrorKind::VariableConflict { .. } => "E5004",
+            RunnerErrorKind::UnsupportedSecretType(_) => "E5005",
+            RunnerErrorKind::PossibleLoggedSecret => "E5006",
+            RunnerErrorKind::InvalidJson { .. } => "E6001",
+            RunnerErrorKind::InvalidYaml { .. } => "E6002",
+            RunnerErrorKind::InvalidRegex => "E6003",
+            RunnerErrorKind::InvalidSemver { .. } => "E6004",
+            RunnerErrorKind::InvalidUrl { .. } => "E6005",
+            RunnerErrorKind::Http(_) => "E7001",
+            RunnerErrorKind::MaxRetriesExceeded { .. } => "E7002",
+            RunnerErrorKind::ResponseBodyTooLarge { .. } => "E7003",
+        }
+    }
 }
 
 /// Textual Output for runner errors
@@ -145,6 +271,9 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::AssertHeaderValueError { .. } => "Assert header value".to_string(),
             RunnerErrorKind::AssertStatus { .. } => "Assert status code".to_string(),
             RunnerErrorKind::AssertVersion { .. } => "Assert HTTP version".to_string(),
+            RunnerErrorKind::ConcurrentFileModification { .. } => {
+                "Concurrent file modification".to_string()
+            }
             RunnerErrorKind::ExpressionInvalidType { .. } => "Invalid expression type".to_string(),
             RunnerErrorKind::FileReadAccess { .. } => "File read access".to_string(),
             RunnerErrorKind::FileWriteAccess { .. } => "File write access".to_string(),
@@ -157,8 +286,11 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::Http(http_error) => http_error.description(),
             RunnerErrorKind::InvalidJson { .. } => "Invalid JSON".to_string(),
             RunnerErrorKind::InvalidRegex => "Invalid regex".to_string(),
+            RunnerErrorKind::InvalidSemver { .. } => "Invalid semver".to_string(),
             RunnerErrorKind::InvalidUrl { .. } => "Invalid URL".to_string(),
             RunnerErrorKind::InvalidXPathEval => "Invalid XPath expression".to_string(),
+            RunnerErrorKind::InvalidYaml { .. } => "Invalid YAML".to_string(),
+            RunnerErrorKind::MaxRetriesExceeded { .. } => "Max retries exceeded".to_string(),
             RunnerErrorKind::NoFilterResult => "Filter error".to_string(),
             RunnerErrorKind::NoQueryResult => "No query result".to_string(),
             RunnerErrorKind::PossibleLoggedSecret => "Invalid redacted secret".to_string(),
@@ -168,12 +300,15 @@ impl DisplaySourceError for RunnerError {
                 "Invalid JSONPath".to_string()
             }
             RunnerErrorKind::QueryInvalidXml => "Invalid XML".to_string(),
+            RunnerErrorKind::QueryInvalidCsv => "Invalid CSV".to_string(),
+            RunnerErrorKind::ResponseBodyTooLarge { .. } => "Response body too large".to_string(),
             RunnerErrorKind::TemplateVariableNotDefined { .. } => "Undefined variable".to_string(),
             RunnerErrorKind::UnauthorizedFileAccess { .. } => {
                 "Unauthorized file access".to_string()
             }
             RunnerErrorKind::UnrenderableExpression { .. } => "Unrenderable expression".to_string(),
             RunnerErrorKind::UnsupportedSecretType(_) => "Invalid secret type".to_string(),
+            RunnerErrorKind::VariableConflict { .. } => "Variable conflict".to_string(),
         }
     }
 
@@ -229,6 +364,14 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::ConcurrentFileModification { path } => {
+                let message = &format!(
+                    "file {} was modified by another process",
+                    path.to_string_lossy()
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FileReadAccess { path } => {
                 let message = &format!("file {} can not be read", path.to_string_lossy());
                 let message = error::add_carets(message, self.source_info, content);
@@ -284,6 +427,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::InvalidSemver { value } => {
+                let message = &format!("<{value}> is not a valid semantic version");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::InvalidUrl { url, message } => {
                 let message = &format!("invalid URL <{url}> ({message})");
                 let message = error::add_carets(message, self.source_info, content);
@@ -294,6 +442,17 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::InvalidYaml { value } => {
+                let message = &format!("actual value is <{value}>");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::MaxRetriesExceeded { status } => {
+                let message =
+                    &format!("max retries exceeded, last response status code was <{status}>");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::NoFilterResult => {
                 let message = "a filter didn't return any result";
                 let message = error::add_carets(message, self.source_info, content);
@@ -329,6 +488,18 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::QueryInvalidCsv => {
+                let message = "HTTP response is not a valid CSV";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::ResponseBodyTooLarge { limit, actual } => {
+                let message = &format!(
+                    "response body size ({actual} bytes) exceeds the maximum allowed size ({limit} bytes)"
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::TemplateVariableNotDefined { name } => {
                 let message = &format!("you must set the variable {name}");
                 let message = error::add_carets(message, self.source_info, content);
@@ -352,12 +523,20 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::VariableConflict { names } => {
+                let message = &format!(
+                    "variables <{}> are defined in several scopes",
+                    names.join(", ")
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
         }
     }
 
     fn message(&self, content: &[&str]) -> StyledString {
         let mut text = StyledString::new();
-        if let RunnerErrorKind::AssertBodyDiffError {
+        let mut message = if let RunnerErrorKind::AssertBodyDiffError {
             hunks,
             body_source_info,
         } = &self.kind
@@ -382,7 +561,15 @@ impl DisplaySourceError for RunnerError {
 
             let error_line = self.source_info().start.line;
             error::add_line_info_prefix(&text, content, error_line)
+        };
+        if let Some(hint) = &self.hint {
+            let loc_max_width = max(content.len().to_string().len(), 2);
+            let spaces = " ".repeat(loc_max_width);
+            message.push("\n");
+            message.push_with(&format!("{spaces} ="), Style::new().blue().bold());
+            message.push_with(&format!(" hint: {hint}"), Style::new().bright_black());
         }
+        message
     }
 }
 
@@ -446,6 +633,41 @@ mod tests {
     use crate::runner::diff::diff;
     use crate::runner::{RunnerError, RunnerErrorKind};
 
+    #[test]
+    fn runner_error_range_is_well_formed() {
+        // Whatever the kind, an error built with a reversed range is clamped so that
+        // `end >= start` always holds.
+        let kinds = vec![
+            RunnerErrorKind::NoQueryResult,
+            RunnerErrorKind::QueryHeaderNotFound,
+            RunnerErrorKind::AssertStatus {
+                actual: "404".to_string(),
+            },
+            RunnerErrorKind::FileReadAccess {
+                path: std::path::PathBuf::from("data.bin"),
+            },
+            RunnerErrorKind::TemplateVariableNotDefined {
+                name: "count".to_string(),
+            },
+        ];
+        let source_info = SourceInfo::new(Pos::new(3, 14), Pos::new(3, 4));
+        for kind in kinds {
+            let error = RunnerError::new(source_info, kind.clone(), false);
+            assert!(error.source_info.end >= error.source_info.start);
+            assert_eq!(
+                error.source_info,
+                SourceInfo::new(Pos::new(3, 14), Pos::new(3, 14))
+            );
+            let warning = RunnerError::new_warning(source_info, kind);
+            assert!(warning.source_info.end >= warning.source_info.start);
+        }
+
+        // A proper range is kept untouched.
+        let source_info = SourceInfo::new(Pos::new(3, 4), Pos::new(3, 14));
+        let error = RunnerError::new(source_info, RunnerErrorKind::NoQueryResult, true);
+        assert_eq!(error.source_info, source_info);
+    }
+
     #[test]
     fn test_error_timeout() {
         let content = "GET http://unknown";
@@ -481,6 +703,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_undefined_variable_hint() {
+        let content = "GET http://localhost/{{pathh}}";
+        let filename = "test.hurl";
+        let kind = RunnerErrorKind::TemplateVariableNotDefined {
+            name: "pathh".to_string(),
+        };
+        let error_source_info = SourceInfo::new(Pos::new(1, 22), Pos::new(1, 27));
+        let entry_source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 31));
+        let error = RunnerError::new(error_source_info, kind, false)
+            .with_hint("did you mean {{path}}?");
+
+        assert_eq!(
+            error.render(
+                filename,
+                content,
+                Some(entry_source_info),
+                OutputFormat::Terminal(false)
+            ),
+            r#"Undefined variable
+  --> test.hurl:1:22
+   |
+ 1 | GET http://localhost/{{pathh}}
+   |                      ^^^^^ you must set the variable pathh
+   |
+   = hint: did you mean {{path}}?"#
+        );
+    }
+
     #[test]
     fn test_assert_error_status() {
         // For the crate colored to output ANSI escape code in test environment.
@@ -569,15 +820,15 @@ jsonpath "$.count" >= 5
         let filename = "test.hurl";
         let error_source_info = SourceInfo::new(Pos::new(4, 0), Pos::new(4, 0));
         let entry_source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14));
-        let error = RunnerError {
-            source_info: error_source_info,
-            kind: RunnerErrorKind::AssertFailure {
+        let error = RunnerError::new(
+            error_source_info,
+            RunnerErrorKind::AssertFailure {
                 actual: "integer <2>".to_string(),
                 expected: "greater than integer <5>".to_string(),
                 type_mismatch: false,
             },
-            assert: true,
-        };
+            true,
+        );
 
         assert_eq!(
             error.message(&lines).to_string(Format::Plain),
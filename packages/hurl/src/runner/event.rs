@@ -17,11 +17,45 @@
  */
 use hurl_core::types::Index;
 
+use super::error::RunnerError;
+use super::result::{CaptureResult, EntryResult};
+
 /// This trait is implemented by run event observers, during the execution of one Hurl file.
+///
+/// Apart from [`EventListener::on_entry_running`], every method has an empty default
+/// implementation: implementors (GUIs, custom reporters) only override the notifications they
+/// need, and receive typed values instead of pre-formatted strings.
 pub trait EventListener {
     /// Call when running an entry.
     /// `current` is the entry index in the Hurl file,
     /// `last` is the last entry index (may be less that the total number of entries).
     /// `retry_count` is the current number of retries (i.e. 0 for a first run)
     fn on_entry_running(&self, current: Index, last: Index, retry_count: usize);
+
+    /// Call before an entry is executed (once per entry, whatever the number of retries).
+    fn on_entry_start(&self, _entry: &EntryStart) {}
+
+    /// Call after an entry execution attempt, with the complete typed [`EntryResult`]
+    /// (calls, captures, asserts, errors). On retries, it's called once per attempt.
+    fn on_entry_end(&self, _result: &EntryResult) {}
+
+    /// Call for each failed assert of an entry execution attempt.
+    fn on_assert_fail(&self, _error: &RunnerError) {}
+
+    /// Call for each capture of an entry execution attempt.
+    fn on_capture(&self, _capture: &CaptureResult) {}
+
+    /// Call for each runtime error of an entry execution attempt that is not an assert failure.
+    fn on_error(&self, _error: &RunnerError) {}
+}
+
+/// Typed description of an entry about to be executed, sent on [`EventListener::on_entry_start`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryStart {
+    /// Index of the entry in the Hurl file.
+    pub entry_index: Index,
+    /// HTTP method of the entry request.
+    pub method: String,
+    /// URL of the entry request, as written in the source file (templates are not evaluated yet).
+    pub url: String,
 }
@@ -32,10 +32,21 @@ pub fn eval(expr: &Expr, variables: &VariableSet) -> Result<Value, RunnerError>
                 let kind = RunnerErrorKind::TemplateVariableNotDefined {
                     name: variable.name.clone(),
                 };
-                Err(RunnerError::new(variable.source_info, kind, false))
+                let hint = undefined_variable_hint(&variable.name, variables);
+                Err(RunnerError::new(variable.source_info, kind, false).with_hint(&hint))
             }
         }
-        ExprKind::Function(fct) => function::eval(fct),
+        ExprKind::Function(fct) => function::eval(fct, variables, expr.source_info),
+    }
+}
+
+/// Returns a hint for an undefined variable `name`: either a variable with a close name exists
+/// in `variables`, or the variable can be passed on the command line.
+fn undefined_variable_hint(name: &str, variables: &VariableSet) -> String {
+    let names = variables.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    match hurl_core::error::suggestion(&names, name) {
+        Some(suggest) => format!("did you mean {{{{{suggest}}}}}?"),
+        None => format!("the variable can be set with --variable {name}=value"),
     }
 }
 
@@ -29,6 +29,9 @@ pub fn eval_count(
         Value::List(values) => Ok(Some(Value::Number(Number::Integer(values.len() as i64)))),
         Value::Bytes(values) => Ok(Some(Value::Number(Number::Integer(values.len() as i64)))),
         Value::Nodeset(size) => Ok(Some(Value::Number(Number::Integer(*size as i64)))),
+        Value::String(value) => Ok(Some(Value::Number(Number::Integer(
+            value.chars().count() as i64,
+        )))),
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
             Err(RunnerError::new(source_info, kind, assert))
@@ -69,6 +72,18 @@ mod tests {
             Value::Number(Number::Integer(3))
         );
 
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("caf\u{e9}".to_string()),
+                &variables,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(4))
+        );
+
         let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
             .err()
             .unwrap();
@@ -30,6 +30,7 @@ use crate::runner::filter::format::eval_date_format;
 use crate::runner::filter::html_escape::eval_html_escape;
 use crate::runner::filter::html_unescape::eval_html_unescape;
 use crate::runner::filter::jsonpath::eval_jsonpath;
+use crate::runner::filter::jwt_claim::eval_jwt_claim;
 use crate::runner::filter::last::eval_last;
 use crate::runner::filter::location::eval_location;
 use crate::runner::filter::nth::eval_nth;
@@ -111,6 +112,9 @@ pub fn eval_filter(
         FilterValue::JsonPath { expr, .. } => {
             eval_jsonpath(value, expr, variables, source_info, in_assert)
         }
+        FilterValue::JwtClaim { name, .. } => {
+            eval_jwt_claim(value, name, variables, source_info, in_assert)
+        }
         FilterValue::Last => eval_last(value, source_info, in_assert),
         FilterValue::Location => eval_location(value, source_info, in_assert),
         FilterValue::Regex {
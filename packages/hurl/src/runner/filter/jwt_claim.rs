@@ -0,0 +1,145 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Extracts the claim `name` from the payload of a JWT string `value`.
+///
+/// The payload is base64url decoded without any signature verification: this filter asserts on
+/// the token's content, it does not prove the token is authentic. An optional `Bearer ` prefix
+/// (as found in `Authorization` headers) is accepted. Returns `None` if the claim is missing.
+pub fn eval_jwt_claim(
+    value: &Value,
+    name: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let token = match value {
+        Value::String(value) => value.strip_prefix("Bearer ").unwrap_or(value),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    let mut parts = token.split('.');
+    let payload = match (parts.next(), parts.next()) {
+        (Some(_), Some(payload)) => payload,
+        _ => {
+            let kind = RunnerErrorKind::FilterInvalidInput("value is not a valid JWT".to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    let payload = match BASE64_URL_SAFE_NO_PAD.decode(payload) {
+        Ok(decoded) => decoded,
+        Err(_) => {
+            let kind = RunnerErrorKind::FilterInvalidInput("value is not a valid JWT".to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    let claims: serde_json::Value = match serde_json::from_slice(&payload) {
+        Ok(json) => json,
+        Err(_) => {
+            let kind =
+                RunnerErrorKind::FilterInvalidInput("JWT payload is not valid JSON".to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    let name = eval_template(name, variables)?;
+    match claims.get(&name) {
+        Some(claim) => Ok(Some(Value::from_json(claim))),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, Value, VariableSet};
+
+    /// A static unsigned token whose payload is `{"sub":"user123","iat":42}`.
+    const TOKEN: &str = "eyJhbGciOiJub25lIn0.eyJzdWIiOiJ1c2VyMTIzIiwiaWF0Ijo0Mn0.";
+
+    fn new_jwt_claim_filter(name: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JwtClaim {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                name: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: name.to_string(),
+                        source: name.to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_jwt_claim() {
+        let variables = VariableSet::new();
+
+        let ret = eval_filter(
+            &new_jwt_claim_filter("sub"),
+            &Value::String(TOKEN.to_string()),
+            &variables,
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::String("user123".to_string()));
+
+        // A `Bearer ` prefix (from an `Authorization` header) is stripped.
+        let ret = eval_filter(
+            &new_jwt_claim_filter("iat"),
+            &Value::String(format!("Bearer {TOKEN}")),
+            &variables,
+            false,
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Number(Number::Integer(42)));
+
+        // A missing claim returns no value.
+        let ret = eval_filter(
+            &new_jwt_claim_filter("exp"),
+            &Value::String(TOKEN.to_string()),
+            &variables,
+            false,
+        );
+        assert!(ret.unwrap().is_none());
+
+        // Not a JWT at all.
+        let ret = eval_filter(
+            &new_jwt_claim_filter("sub"),
+            &Value::String("Hello World!".to_string()),
+            &variables,
+            false,
+        );
+        assert!(ret.is_err());
+    }
+}
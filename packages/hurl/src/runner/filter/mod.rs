@@ -34,6 +34,7 @@ mod format;
 mod html_escape;
 mod html_unescape;
 mod jsonpath;
+mod jwt_claim;
 mod last;
 mod location;
 mod nth;
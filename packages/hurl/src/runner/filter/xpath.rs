@@ -39,7 +39,9 @@ pub fn eval_xpath(
                     false,
                 ));
             };
-            eval_xpath_doc(&doc, expr, variables)
+            // No custom namespaces for the filter form: the document's own
+            // namespaces are registered when parsing as XML.
+            eval_xpath_doc(&doc, expr, variables, &[])
         }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
@@ -52,9 +54,10 @@ pub fn eval_xpath_doc(
     doc: &Document,
     expr: &Template,
     variables: &VariableSet,
+    namespaces: &[(String, String)],
 ) -> Result<Option<Value>, RunnerError> {
     let expr_str = eval_template(expr, variables)?;
-    let result = doc.eval_xpath(&expr_str);
+    let result = doc.eval_xpath(&expr_str, namespaces);
     match result {
         Ok(value) => Ok(Some(value)),
         Err(XPathError::Eval) => Err(RunnerError::new(
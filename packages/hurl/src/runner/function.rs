@@ -15,15 +15,38 @@
  * limitations under the License.
  *
  */
-use chrono::Utc;
-use hurl_core::ast::Function;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use chrono::{TimeDelta, Utc};
+use hmac::{Hmac, Mac};
+use hurl_core::ast::{Expr, Function, SourceInfo, TimeOffsetUnit};
+use percent_encoding::AsciiSet;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sha2::Sha256;
 use uuid::Uuid;
 
-use super::error::RunnerError;
+use crate::jsonpath;
+
+use super::error::{RunnerError, RunnerErrorKind};
+use super::expr;
+use super::xpath::{Document, Format};
+use super::number::Number;
 use super::value::Value;
+use super::variable::VariableSet;
 
 /// Evaluates the function `function`, returns a [`Value`] on success or an [`RunnerError`] .
-pub fn eval(function: &Function) -> Result<Value, RunnerError> {
+///
+/// `source_info` is the location of the calling expression, used for error reporting.
+pub fn eval(
+    function: &Function,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+) -> Result<Value, RunnerError> {
     match &function {
         Function::NewDate => {
             let now = Utc::now();
@@ -33,5 +56,582 @@ pub fn eval(function: &Function) -> Result<Value, RunnerError> {
             let uuid = Uuid::new_v4();
             Ok(Value::String(uuid.to_string()))
         }
+        Function::Base64Encode(arg) => {
+            let value = expr::eval(arg, variables)?;
+            let bytes = match value {
+                Value::String(s) => s.into_bytes(),
+                Value::Bytes(bytes) => bytes,
+                value => {
+                    let kind = RunnerErrorKind::ExpressionInvalidType {
+                        value: value.repr(),
+                        expecting: "string or bytes".to_string(),
+                    };
+                    return Err(RunnerError::new(arg.source_info, kind, false));
+                }
+            };
+            Ok(Value::String(general_purpose::STANDARD.encode(bytes)))
+        }
+        Function::Base64Decode(arg) => {
+            let value = expr::eval(arg, variables)?;
+            let Value::String(s) = value else {
+                let kind = RunnerErrorKind::ExpressionInvalidType {
+                    value: value.repr(),
+                    expecting: "string".to_string(),
+                };
+                return Err(RunnerError::new(arg.source_info, kind, false));
+            };
+            let Ok(bytes) = general_purpose::STANDARD.decode(s) else {
+                let kind = RunnerErrorKind::FilterInvalidInput("invalid Base64".to_string());
+                return Err(RunnerError::new(source_info, kind, false));
+            };
+            // Decoded content is a string when valid UTF-8, raw bytes otherwise.
+            match String::from_utf8(bytes) {
+                Ok(s) => Ok(Value::String(s)),
+                Err(e) => Ok(Value::Bytes(e.into_bytes())),
+            }
+        }
+        Function::Now { format, offset } => {
+            let mut now = Utc::now();
+            if let Some(offset) = offset {
+                now += match offset.unit {
+                    TimeOffsetUnit::Second => TimeDelta::seconds(offset.value),
+                    TimeOffsetUnit::Minute => TimeDelta::minutes(offset.value),
+                    TimeOffsetUnit::Hour => TimeDelta::hours(offset.value),
+                    TimeOffsetUnit::Day => TimeDelta::days(offset.value),
+                };
+            }
+            let mut formatted = String::new();
+            match write!(formatted, "{}", now.format(format)) {
+                Ok(_) => Ok(Value::String(formatted)),
+                Err(_) => {
+                    let kind = RunnerErrorKind::FilterInvalidFormatSpecifier(format.clone());
+                    Err(RunnerError::new(source_info, kind, false))
+                }
+            }
+        }
+        Function::HmacSha256 { key, message } => {
+            let digest = hmac_sha256(key, message, variables)?;
+            let mut hex = String::new();
+            for byte in digest {
+                write!(hex, "{byte:02x}").unwrap();
+            }
+            Ok(Value::String(hex))
+        }
+        Function::HmacSha256Base64 { key, message } => {
+            let digest = hmac_sha256(key, message, variables)?;
+            Ok(Value::String(general_purpose::STANDARD.encode(digest)))
+        }
+        Function::UrlEncode { arg, form } => {
+            let value = expr::eval(arg, variables)?;
+            let Value::String(s) = value else {
+                let kind = RunnerErrorKind::ExpressionInvalidType {
+                    value: value.repr(),
+                    expecting: "string".to_string(),
+                };
+                return Err(RunnerError::new(arg.source_info, kind, false));
+            };
+            // Everything but the RFC 3986 unreserved characters is percent-encoded,
+            // non-ASCII characters as their UTF-8 bytes.
+            const UNRESERVED: &AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+                .remove(b'-')
+                .remove(b'.')
+                .remove(b'_')
+                .remove(b'~');
+            let encoded = percent_encoding::percent_encode(s.as_bytes(), UNRESERVED).to_string();
+            let encoded = if *form {
+                encoded.replace("%20", "+")
+            } else {
+                encoded
+            };
+            Ok(Value::String(encoded))
+        }
+        Function::UrlDecode(arg) => {
+            let value = expr::eval(arg, variables)?;
+            let Value::String(s) = value else {
+                let kind = RunnerErrorKind::ExpressionInvalidType {
+                    value: value.repr(),
+                    expecting: "string".to_string(),
+                };
+                return Err(RunnerError::new(arg.source_info, kind, false));
+            };
+            // `+` is decoded to a space so that form-encoded strings round-trip too.
+            let s = s.replace('+', " ");
+            let decoded = percent_encoding::percent_decode_str(&s).decode_utf8_lossy();
+            Ok(Value::String(decoded.to_string()))
+        }
+        Function::Jsonpath { arg, expr } => {
+            let value = eval_string_arg(arg, variables)?;
+            let Ok(json) = serde_json::from_str(&value) else {
+                let kind =
+                    RunnerErrorKind::FilterInvalidInput("value is not a valid JSON".to_string());
+                return Err(RunnerError::new(arg.source_info, kind, false));
+            };
+            let Ok(query) = jsonpath::parse(expr) else {
+                let kind = RunnerErrorKind::QueryInvalidJsonpathExpression {
+                    value: expr.clone(),
+                };
+                return Err(RunnerError::new(source_info, kind, false));
+            };
+            // When the expression matches multiple nodes, the first one is returned.
+            match query.eval(&json).into_iter().next() {
+                Some(result) => Ok(Value::from_json(&result)),
+                None => Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::NoQueryResult,
+                    false,
+                )),
+            }
+        }
+        Function::Xpath { arg, expr } => {
+            let value = eval_string_arg(arg, variables)?;
+            // The HTML parser should also work with XML input.
+            let Ok(doc) = Document::parse(&value, Format::Html) else {
+                let kind =
+                    RunnerErrorKind::FilterInvalidInput("value is not a valid XML".to_string());
+                return Err(RunnerError::new(arg.source_info, kind, false));
+            };
+            match doc.eval_xpath(expr, &[]) {
+                Ok(Value::Nodeset(0)) => Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::NoQueryResult,
+                    false,
+                )),
+                // A node-set can't be embedded in a template: the string value of
+                // the first matching node is returned instead.
+                Ok(Value::Nodeset(_)) => {
+                    match doc.eval_xpath(&format!("string(({expr})[1])"), &[]) {
+                        Ok(value) => Ok(value),
+                        Err(_) => Err(RunnerError::new(
+                            source_info,
+                            RunnerErrorKind::InvalidXPathEval,
+                            false,
+                        )),
+                    }
+                }
+                Ok(value) => Ok(value),
+                Err(_) => Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::InvalidXPathEval,
+                    false,
+                )),
+            }
+        }
+        Function::RandomInt { min, max } => {
+            let (min, max) = (min.as_i64(), max.as_i64());
+            if min > max {
+                let kind = RunnerErrorKind::ExpressionInvalidType {
+                    value: format!("random_int({min}, {max})"),
+                    expecting: "min lower than or equal to max".to_string(),
+                };
+                return Err(RunnerError::new(source_info, kind, false));
+            }
+            let value = with_rng(|rng| rng.random_range(min..=max));
+            Ok(Value::Number(Number::Integer(value)))
+        }
+        Function::RandomFloat { min, max } => {
+            let (min, max) = (number_to_f64(min), number_to_f64(max));
+            if min > max {
+                let kind = RunnerErrorKind::ExpressionInvalidType {
+                    value: format!("random_float({min}, {max})"),
+                    expecting: "min lower than or equal to max".to_string(),
+                };
+                return Err(RunnerError::new(source_info, kind, false));
+            }
+            let value = with_rng(|rng| rng.random_range(min..=max));
+            Ok(Value::Number(Number::Float(value)))
+        }
+        Function::Counter { name, start, step } => {
+            let start = start.as_ref().map_or(0, |value| value.as_i64());
+            let step = step.as_ref().map_or(1, |value| value.as_i64());
+            let mut counters = COUNTERS.lock().unwrap();
+            let value = counters
+                .entry(name.clone())
+                .and_modify(|value| *value += step)
+                .or_insert(start);
+            Ok(Value::Number(Number::Integer(*value)))
+        }
+    }
+}
+
+/// The global RNG backing the `random_int`/`random_float` functions.
+///
+/// It's seeded once at runner start (see [`seed_rng`]) so that a run using random values can be
+/// replayed with `--seed`.
+static RNG: Mutex<Option<StdRng>> = Mutex::new(None);
+
+/// Seeds the global RNG used by the `random_int`/`random_float` functions with `seed`.
+pub fn seed_rng(seed: u64) {
+    *RNG.lock().unwrap() = Some(StdRng::seed_from_u64(seed));
+}
+
+fn with_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    let mut rng = RNG.lock().unwrap();
+    let rng = rng.get_or_insert_with(StdRng::from_os_rng);
+    f(rng)
+}
+
+/// The named counters backing the `counter` function.
+///
+/// Counters are shared by every entry of a run and reset between runs (see [`reset_counters`]).
+static COUNTERS: Mutex<BTreeMap<String, i64>> = Mutex::new(BTreeMap::new());
+
+/// Resets the counters used by the `counter` function, called at the start of each run.
+pub fn reset_counters() {
+    COUNTERS.lock().unwrap().clear();
+}
+
+/// Converts a literal number from the AST to a `f64` bound.
+fn number_to_f64(number: &hurl_core::ast::Number) -> f64 {
+    match number {
+        hurl_core::ast::Number::Float(value) => value.as_f64(),
+        hurl_core::ast::Number::Integer(value) => value.as_i64() as f64,
+        hurl_core::ast::Number::BigInteger(value) => value.parse().unwrap_or(f64::MAX),
+    }
+}
+
+/// Computes the HMAC-SHA256 digest of `message` with `key`, both expressions being evaluated
+/// against `variables` first.
+fn hmac_sha256(
+    key: &Expr,
+    message: &Expr,
+    variables: &VariableSet,
+) -> Result<Vec<u8>, RunnerError> {
+    let key = eval_bytes(key, variables)?;
+    let message = eval_bytes(message, variables)?;
+    // HMAC accepts keys of any size.
+    let mut mac = Hmac::<Sha256>::new_from_slice(&key).unwrap();
+    mac.update(&message);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// Evaluates the expression `arg` to a string.
+fn eval_string_arg(arg: &Expr, variables: &VariableSet) -> Result<String, RunnerError> {
+    let value = expr::eval(arg, variables)?;
+    match value {
+        Value::String(s) => Ok(s),
+        value => {
+            let kind = RunnerErrorKind::ExpressionInvalidType {
+                value: value.repr(),
+                expecting: "string".to_string(),
+            };
+            Err(RunnerError::new(arg.source_info, kind, false))
+        }
+    }
+}
+
+/// Evaluates the expression `arg` to a byte sequence.
+fn eval_bytes(arg: &Expr, variables: &VariableSet) -> Result<Vec<u8>, RunnerError> {
+    let value = expr::eval(arg, variables)?;
+    match value {
+        Value::String(s) => Ok(s.into_bytes()),
+        Value::Bytes(bytes) => Ok(bytes),
+        value => {
+            let kind = RunnerErrorKind::ExpressionInvalidType {
+                value: value.repr(),
+                expecting: "string or bytes".to_string(),
+            };
+            Err(RunnerError::new(arg.source_info, kind, false))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{ExprKind, TimeOffset, Variable, I64};
+    use hurl_core::reader::Pos;
+    use hurl_core::types::ToSource;
+    use regex::Regex;
+
+    use super::*;
+
+    #[test]
+    fn eval_uuid() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let value = eval(&Function::NewUuid, &variables, source_info).unwrap();
+        let Value::String(uuid) = value else {
+            panic!("expecting a string value");
+        };
+        let format =
+            Regex::new("^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[0-9a-f]{4}-[0-9a-f]{12}$").unwrap();
+        assert!(format.is_match(&uuid));
+
+        // Two evaluations produce different values: there is no caching.
+        let other = eval(&Function::NewUuid, &variables, source_info).unwrap();
+        assert_ne!(Value::String(uuid), other);
+    }
+
+    #[test]
+    fn eval_random() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let random_int = Function::RandomInt {
+            min: Box::new(I64::new(1, "1".to_source())),
+            max: Box::new(I64::new(1000, "1000".to_source())),
+        };
+
+        // Values are in [min, max] inclusive.
+        seed_rng(42);
+        let mut values = vec![];
+        for _ in 0..16 {
+            let value = eval(&random_int, &variables, source_info).unwrap();
+            let Value::Number(Number::Integer(value)) = value else {
+                panic!("expecting an integer value");
+            };
+            assert!((1..=1000).contains(&value));
+            values.push(value);
+        }
+
+        // Reseeding with the same seed replays the same sequence.
+        seed_rng(42);
+        let replayed = (0..16)
+            .map(|_| eval(&random_int, &variables, source_info).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            replayed
+                .iter()
+                .map(|v| {
+                    let Value::Number(Number::Integer(value)) = v else {
+                        panic!("expecting an integer value");
+                    };
+                    *value
+                })
+                .collect::<Vec<_>>()
+        );
+
+        let random_float = Function::RandomFloat {
+            min: Box::new(hurl_core::ast::Number::Integer(I64::new(0, "0".to_source()))),
+            max: Box::new(hurl_core::ast::Number::Integer(I64::new(1, "1".to_source()))),
+        };
+        let value = eval(&random_float, &variables, source_info).unwrap();
+        let Value::Number(Number::Float(value)) = value else {
+            panic!("expecting a float value");
+        };
+        assert!((0.0..=1.0).contains(&value));
+
+        // min must be lower than or equal to max.
+        let function = Function::RandomInt {
+            min: Box::new(I64::new(10, "10".to_source())),
+            max: Box::new(I64::new(1, "1".to_source())),
+        };
+        assert!(eval(&function, &variables, source_info).is_err());
+    }
+
+    #[test]
+    fn eval_now() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let function = Function::Now {
+            format: "%Y-%m-%dT%H:%M:%SZ".to_string(),
+            offset: None,
+        };
+        let value = eval(&function, &variables, source_info).unwrap();
+        let Value::String(date) = value else {
+            panic!("expecting a string value");
+        };
+        let format = Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z$").unwrap();
+        assert!(format.is_match(&date));
+
+        // An offset shifts the formatted date.
+        let function = Function::Now {
+            format: "%Y-%m-%dT%H:%M:%SZ".to_string(),
+            offset: Some(TimeOffset {
+                value: -1,
+                unit: TimeOffsetUnit::Day,
+            }),
+        };
+        let shifted = eval(&function, &variables, source_info).unwrap();
+        let Value::String(shifted) = shifted else {
+            panic!("expecting a string value");
+        };
+        assert!(shifted < date);
+
+        // An invalid format specifier is an error.
+        let function = Function::Now {
+            format: "%%%".to_string(),
+            offset: None,
+        };
+        assert!(eval(&function, &variables, source_info).is_err());
+    }
+
+    #[test]
+    fn eval_counter() {
+        reset_counters();
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let counter = |name: &str| Function::Counter {
+            name: name.to_string(),
+            start: None,
+            step: None,
+        };
+
+        // Each call increments the counter, like two entries referencing the same name.
+        let value = eval(&counter("page"), &variables, source_info).unwrap();
+        assert_eq!(value, Value::Number(Number::Integer(0)));
+        let value = eval(&counter("page"), &variables, source_info).unwrap();
+        assert_eq!(value, Value::Number(Number::Integer(1)));
+
+        // Named counters are independent.
+        let value = eval(&counter("id"), &variables, source_info).unwrap();
+        assert_eq!(value, Value::Number(Number::Integer(0)));
+
+        // `start` and `step` set the initial value and the increment.
+        let function = Function::Counter {
+            name: "n".to_string(),
+            start: Some(Box::new(I64::new(10, "10".to_source()))),
+            step: Some(Box::new(I64::new(2, "2".to_source()))),
+        };
+        let value = eval(&function, &variables, source_info).unwrap();
+        assert_eq!(value, Value::Number(Number::Integer(10)));
+        let value = eval(&function, &variables, source_info).unwrap();
+        assert_eq!(value, Value::Number(Number::Integer(12)));
+
+        // Counters are reset between runs.
+        reset_counters();
+        let value = eval(&counter("page"), &variables, source_info).unwrap();
+        assert_eq!(value, Value::Number(Number::Integer(0)));
+    }
+
+    fn variable_expr(name: &str) -> Box<Expr> {
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        Box::new(Expr {
+            kind: ExprKind::Variable(Variable {
+                name: name.to_string(),
+                source_info,
+            }),
+            source_info,
+        })
+    }
+
+    #[test]
+    fn eval_hmac_sha256() {
+        // Test case 2 of RFC 4231 <https://www.rfc-editor.org/rfc/rfc4231#section-4.3>.
+        let mut variables = VariableSet::new();
+        variables.insert("key".to_string(), Value::String("Jefe".to_string()));
+        variables.insert(
+            "message".to_string(),
+            Value::String("what do ya want for nothing?".to_string()),
+        );
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+
+        let function = Function::HmacSha256 {
+            key: variable_expr("key"),
+            message: variable_expr("message"),
+        };
+        let value = eval(&function, &variables, source_info).unwrap();
+        assert_eq!(
+            value,
+            Value::String(
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843".to_string()
+            )
+        );
+
+        let function = Function::HmacSha256Base64 {
+            key: variable_expr("key"),
+            message: variable_expr("message"),
+        };
+        let value = eval(&function, &variables, source_info).unwrap();
+        assert_eq!(
+            value,
+            Value::String("W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM=".to_string())
+        );
+
+        // Signing a non-string value is an error.
+        variables.insert("key".to_string(), Value::Bool(true));
+        let function = Function::HmacSha256 {
+            key: variable_expr("key"),
+            message: variable_expr("message"),
+        };
+        assert!(eval(&function, &variables, source_info).is_err());
+    }
+
+    #[test]
+    fn eval_jsonpath_xpath() {
+        let mut variables = VariableSet::new();
+        variables.insert(
+            "prev_body".to_string(),
+            Value::String(r#"{"id":"abc","tags":["x","y"]}"#.to_string()),
+        );
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+
+        let jsonpath = |expr: &str| Function::Jsonpath {
+            arg: variable_expr("prev_body"),
+            expr: expr.to_string(),
+        };
+        let value = eval(&jsonpath("$.id"), &variables, source_info).unwrap();
+        assert_eq!(value, Value::String("abc".to_string()));
+
+        // When the expression matches multiple nodes, the first one is returned.
+        let value = eval(&jsonpath("$.tags[*]"), &variables, source_info).unwrap();
+        assert_eq!(value, Value::String("x".to_string()));
+
+        // No match is an error.
+        assert!(eval(&jsonpath("$.missing"), &variables, source_info).is_err());
+
+        variables.insert(
+            "doc".to_string(),
+            Value::String("<root><id>42</id><id>43</id></root>".to_string()),
+        );
+        let xpath = |expr: &str| Function::Xpath {
+            arg: variable_expr("doc"),
+            expr: expr.to_string(),
+        };
+        let value = eval(&xpath("//id"), &variables, source_info).unwrap();
+        assert_eq!(value, Value::String("42".to_string()));
+
+        let value = eval(&xpath("count(//id)"), &variables, source_info).unwrap();
+        assert_eq!(value, Value::Number(Number::from(2.0)));
+
+        assert!(eval(&xpath("//missing"), &variables, source_info).is_err());
+    }
+
+    #[test]
+    fn eval_url_encode_decode() {
+        let mut variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+
+        fn url_encode(form: bool) -> Function {
+            Function::UrlEncode {
+                arg: variable_expr("q"),
+                form,
+            }
+        }
+
+        // Spaces are encoded as `%20`, non-ASCII characters as their UTF-8 bytes.
+        variables.insert("q".to_string(), Value::String("a b&c=шеллы".to_string()));
+        let encoded = eval(&url_encode(false), &variables, source_info).unwrap();
+        assert_eq!(
+            encoded,
+            Value::String("a%20b%26c%3D%D1%88%D0%B5%D0%BB%D0%BB%D1%8B".to_string())
+        );
+
+        // With `form=true`, spaces are encoded as `+`.
+        let form_encoded = eval(&url_encode(true), &variables, source_info).unwrap();
+        assert_eq!(
+            form_encoded,
+            Value::String("a+b%26c%3D%D1%88%D0%B5%D0%BB%D0%BB%D1%8B".to_string())
+        );
+
+        // Both encodings round-trip through `url_decode`.
+        for encoded in [encoded, form_encoded] {
+            variables.insert("q".to_string(), encoded);
+            let decoded = eval(
+                &Function::UrlDecode(variable_expr("q")),
+                &variables,
+                source_info,
+            )
+            .unwrap();
+            assert_eq!(decoded, Value::String("a b&c=шеллы".to_string()));
+        }
+
+        // The empty string is unchanged.
+        variables.insert("q".to_string(), Value::String(String::new()));
+        let encoded = eval(&url_encode(false), &variables, source_info).unwrap();
+        assert_eq!(encoded, Value::String(String::new()));
+
+        // Encoding a non-string value is an error.
+        variables.insert("q".to_string(), Value::Bool(true));
+        assert!(eval(&url_encode(false), &variables, source_info).is_err());
     }
 }
@@ -66,6 +66,9 @@ pub fn eval_json_value(
                 Ok(format!("{{{}}}", elems_string.join(",")))
             }
         }
+        // `exact(...)` only carries a meaning for the `matches_json` predicate (where it is
+        // interpreted by the predicate evaluation itself), it renders as the wrapped value.
+        JsonValue::Exact(value) => eval_json_value(value, variables, keep_whitespace),
         JsonValue::Placeholder(Placeholder { expr, .. }) => {
             let s = expr::render(expr, variables)?;
 
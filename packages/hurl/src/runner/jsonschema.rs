@@ -0,0 +1,297 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! A JSON Schema validator used by the `jsonschema` assert predicate.
+//!
+//! A core subset of the specification is supported: `type`, `enum`, `const`, `required`,
+//! `properties`, `additionalProperties`, `items`, `minItems`/`maxItems`, `minLength`/`maxLength`,
+//! `minimum`/`maximum`, `exclusiveMinimum`/`exclusiveMaximum`, `pattern` and local `$ref`
+//! (`#/...` JSON pointers). Remote `$ref` URIs are rejected: schemas are always resolved
+//! offline so that running a Hurl file can not trigger hidden network accesses.
+use regex::Regex;
+use serde_json::Value;
+
+/// A single validation failure, located by the JSON pointer of the failing field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON pointer (RFC 6901) to the invalid part of the instance, `""` being the root.
+    pub pointer: String,
+    /// Human readable reason of the failure.
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(pointer: &str, message: String) -> ValidationError {
+        ValidationError {
+            pointer: pointer.to_string(),
+            message,
+        }
+    }
+}
+
+/// Validates a JSON `instance` against a JSON Schema `schema`.
+///
+/// Returns the list of validation errors, empty when the instance is valid.
+pub fn validate(instance: &Value, schema: &Value) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    check(instance, schema, schema, "", &mut errors);
+    errors
+}
+
+fn check(instance: &Value, schema: &Value, root: &Value, pointer: &str, errors: &mut Vec<ValidationError>) {
+    // A boolean schema accepts (`true`) or rejects (`false`) everything.
+    let schema = match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => {
+            errors.push(ValidationError::new(pointer, "schema rejects any value".to_string()));
+            return;
+        }
+        Value::Object(schema) => schema,
+        _ => return,
+    };
+
+    if let Some(Value::String(reference)) = schema.get("$ref") {
+        match resolve_ref(reference, root) {
+            Some(target) => check(instance, target, root, pointer, errors),
+            None => errors.push(ValidationError::new(
+                pointer,
+                format!("unresolvable $ref <{reference}> (remote URIs are not supported)"),
+            )),
+        }
+        return;
+    }
+
+    if let Some(type_) = schema.get("type") {
+        check_type(instance, type_, pointer, errors);
+    }
+    if let Some(Value::Array(values)) = schema.get("enum") {
+        if !values.contains(instance) {
+            errors.push(ValidationError::new(pointer, "value is not one of the enum values".to_string()));
+        }
+    }
+    if let Some(value) = schema.get("const") {
+        if instance != value {
+            errors.push(ValidationError::new(pointer, format!("value is not the constant <{value}>")));
+        }
+    }
+
+    match instance {
+        Value::Object(object) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for name in required.iter().filter_map(|r| r.as_str()) {
+                    if !object.contains_key(name) {
+                        errors.push(ValidationError::new(
+                            pointer,
+                            format!("missing required property \"{name}\""),
+                        ));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            if let Some(properties) = properties {
+                for (name, subschema) in properties {
+                    if let Some(value) = object.get(name) {
+                        let pointer = format!("{pointer}/{}", escape_pointer_token(name));
+                        check(value, subschema, root, &pointer, errors);
+                    }
+                }
+            }
+            if let Some(Value::Bool(false)) = schema.get("additionalProperties") {
+                for name in object.keys() {
+                    if !properties.is_some_and(|p| p.contains_key(name)) {
+                        errors.push(ValidationError::new(
+                            &format!("{pointer}/{}", escape_pointer_token(name)),
+                            "additional property is not allowed".to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+        Value::Array(values) => {
+            if let Some(items) = schema.get("items") {
+                for (index, value) in values.iter().enumerate() {
+                    let pointer = format!("{pointer}/{index}");
+                    check(value, items, root, &pointer, errors);
+                }
+            }
+            if let Some(min) = schema.get("minItems").and_then(|v| v.as_u64()) {
+                if (values.len() as u64) < min {
+                    errors.push(ValidationError::new(pointer, format!("array has less than {min} items")));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(|v| v.as_u64()) {
+                if (values.len() as u64) > max {
+                    errors.push(ValidationError::new(pointer, format!("array has more than {max} items")));
+                }
+            }
+        }
+        Value::String(value) => {
+            let len = value.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(|v| v.as_u64()) {
+                if len < min {
+                    errors.push(ValidationError::new(pointer, format!("string is shorter than {min} characters")));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(|v| v.as_u64()) {
+                if len > max {
+                    errors.push(ValidationError::new(pointer, format!("string is longer than {max} characters")));
+                }
+            }
+            if let Some(pattern) = schema.get("pattern").and_then(|v| v.as_str()) {
+                match Regex::new(pattern) {
+                    Ok(re) => {
+                        if !re.is_match(value) {
+                            errors.push(ValidationError::new(
+                                pointer,
+                                format!("string does not match pattern <{pattern}>"),
+                            ));
+                        }
+                    }
+                    Err(_) => errors.push(ValidationError::new(pointer, format!("invalid pattern <{pattern}>"))),
+                }
+            }
+        }
+        Value::Number(number) => {
+            if let Some(value) = number.as_f64() {
+                check_bounds(value, schema, pointer, errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_type(instance: &Value, type_: &Value, pointer: &str, errors: &mut Vec<ValidationError>) {
+    let matches = |name: &str| match name {
+        "null" => instance.is_null(),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "number" => instance.is_number(),
+        "integer" => instance.as_f64().is_some_and(|f| f.fract() == 0.0),
+        "string" => instance.is_string(),
+        _ => false,
+    };
+    let valid = match type_ {
+        Value::String(name) => matches(name),
+        Value::Array(names) => names.iter().filter_map(|n| n.as_str()).any(matches),
+        _ => true,
+    };
+    if !valid {
+        errors.push(ValidationError::new(
+            pointer,
+            format!("value has not the expected type <{type_}>"),
+        ));
+    }
+}
+
+fn check_bounds(value: f64, schema: &serde_json::Map<String, Value>, pointer: &str, errors: &mut Vec<ValidationError>) {
+    if let Some(min) = schema.get("minimum").and_then(|v| v.as_f64()) {
+        if value < min {
+            errors.push(ValidationError::new(pointer, format!("number is less than {min}")));
+        }
+    }
+    if let Some(max) = schema.get("maximum").and_then(|v| v.as_f64()) {
+        if value > max {
+            errors.push(ValidationError::new(pointer, format!("number is greater than {max}")));
+        }
+    }
+    if let Some(min) = schema.get("exclusiveMinimum").and_then(|v| v.as_f64()) {
+        if value <= min {
+            errors.push(ValidationError::new(pointer, format!("number is not greater than {min}")));
+        }
+    }
+    if let Some(max) = schema.get("exclusiveMaximum").and_then(|v| v.as_f64()) {
+        if value >= max {
+            errors.push(ValidationError::new(pointer, format!("number is not less than {max}")));
+        }
+    }
+}
+
+/// Resolves a local `$ref` JSON pointer (`#/...`) in the `root` schema.
+///
+/// Remote URIs return `None`: they are never followed.
+fn resolve_ref<'a>(reference: &str, root: &'a Value) -> Option<&'a Value> {
+    let pointer = reference.strip_prefix('#')?;
+    if pointer.is_empty() {
+        return Some(root);
+    }
+    root.pointer(pointer)
+}
+
+/// Escapes a property name used inside a JSON pointer (see RFC 6901).
+fn escape_pointer_token(name: &str) -> String {
+    name.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_simple_schema() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "age": { "type": "integer", "minimum": 0 }
+            }
+        });
+
+        let instance = serde_json::json!({ "name": "Bob", "age": 30 });
+        assert!(validate(&instance, &schema).is_empty());
+
+        let instance = serde_json::json!({ "age": -1 });
+        let errors = validate(&instance, &schema);
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::new("", "missing required property \"name\"".to_string()),
+                ValidationError::new("/age", "number is less than 0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_local_ref() {
+        let schema = serde_json::json!({
+            "type": "array",
+            "items": { "$ref": "#/definitions/id" },
+            "definitions": {
+                "id": { "type": "string", "pattern": "^[0-9]+$" }
+            }
+        });
+
+        let instance = serde_json::json!(["123", "abc"]);
+        let errors = validate(&instance, &schema);
+        assert_eq!(
+            errors,
+            vec![ValidationError::new(
+                "/1",
+                "string does not match pattern <^[0-9]+$>".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn remote_ref_is_rejected() {
+        let schema = serde_json::json!({ "$ref": "https://example.org/user.json" });
+        let errors = validate(&serde_json::json!({}), &schema);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].pointer, "");
+    }
+}
@@ -20,20 +20,23 @@
 
 pub use self::error::{RunnerError, RunnerErrorKind};
 #[doc(hidden)]
-pub use self::event::EventListener;
+pub use self::event::{EntryStart, EventListener};
+pub use self::function::seed_rng;
 pub use self::http_response::HttpResponse;
+pub use self::hurl_file::curl_commands;
 pub use self::hurl_file::run;
 #[doc(hidden)]
 pub use self::hurl_file::run_entries;
 pub use self::number::Number;
 pub use self::output::Output;
-pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult};
+pub use self::result::{AssertResult, CaptureResult, EntryFailure, EntryResult, HurlResult};
 pub use self::runner_options::{RunnerOptions, RunnerOptionsBuilder};
 pub use self::bindings::BoundVariables;
 pub use self::value::{EvalError, Value};
-pub use self::variable::{Variable, VariableSet, Visibility};
+pub use self::variable::{MergePolicy, Variable, VariableSet, Visibility};
 
 mod assert;
+mod auth;
 mod bindings;
 mod body;
 mod cache;
@@ -49,6 +52,7 @@ pub mod hex;
 mod http_response;
 mod hurl_file;
 mod json;
+mod jsonschema;
 mod multiline;
 mod multipart;
 mod number;
@@ -15,7 +15,7 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{MultilineString, MultilineStringKind};
+use hurl_core::ast::{GraphQlSection, MultilineString, MultilineStringKind};
 use hurl_core::types::ToSource;
 use serde_json::json;
 
@@ -55,6 +55,26 @@ pub fn eval_multiline(
     }
 }
 
+/// Renders to string the JSON body of a `[GraphQL]` section, given a set of variables.
+pub fn eval_graphql_section(
+    graphql: &GraphQlSection,
+    variables: &VariableSet,
+) -> Result<String, RunnerError> {
+    let query = eval_template(&graphql.query, variables)?;
+    let query = json!(query.trim());
+    let mut body = format!(r#"{{"query":{query}"#);
+    if let Some(vars) = &graphql.variables {
+        let s = eval_json_value(&vars.value, variables, false)?;
+        body.push_str(&format!(r#","variables":{s}"#));
+    }
+    if let Some(name) = &graphql.operation_name {
+        let name = eval_template(name, variables)?;
+        body.push_str(&format!(r#","operationName":{}"#, json!(name)));
+    }
+    body.push('}');
+    Ok(body)
+}
+
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::{
@@ -65,9 +65,14 @@ fn eval_file_param(
     let filename = eval_template(&filename_param.value.filename, variables)?;
     let data = eval_file(&filename_param.value.filename, variables, context_dir)?;
     let content_type = eval_content_type(&filename_param.value, variables)?;
+    let filename_override = match &filename_param.value.filename_override {
+        Some(filename) => Some(eval_template(filename, variables)?),
+        None => None,
+    };
     Ok(http::FileParam {
         name,
         filename,
+        filename_override,
         data,
         content_type,
     })
@@ -160,6 +165,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: None,
+                    filename_override: None,
                 },
                 line_terminator0: line_terminator,
             },
@@ -172,6 +178,7 @@ mod tests {
             http::FileParam {
                 name: "upload1".to_string(),
                 filename: "hello.txt".to_string(),
+                filename_override: None,
                 data: b"Hello World!".to_vec(),
                 content_type: "text/plain".to_string(),
             }
@@ -204,6 +211,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: None,
+                    filename_override: None,
                 },
                 &variables
             )
@@ -229,6 +237,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: None,
+                    filename_override: None,
                 },
                 &variables
             )
@@ -260,7 +269,8 @@ mod tests {
                             source: "text/html".to_source()
                         }],
                         SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-                    ))
+                    )),
+                    filename_override: None,
                 },
                 &variables
             )
@@ -286,6 +296,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: None,
+                    filename_override: None,
                 },
                 &variables
             )
@@ -330,7 +341,8 @@ mod tests {
                             },
                         })],
                         SourceInfo::new(Pos::new(1, 9), Pos::new(1, 15)),
-                    ))
+                    )),
+                    filename_override: None,
                 },
                 &variables
             )
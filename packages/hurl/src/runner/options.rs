@@ -15,13 +15,16 @@
  * limitations under the License.
  *
  */
+use std::path::Path;
+
 use hurl_core::ast::{
-    BooleanOption, CountOption, DurationOption, Entry, NaturalOption, Number as AstNumber,
-    OptionKind, Placeholder, VariableDefinition, VariableValue, VerbosityOption,
+    BooleanOption, CompressOption, CountOption, DurationOption, Entry, IpVersionOption,
+    NaturalOption, Number as AstNumber, OptionKind, Placeholder, VariableDefinition, VariableValue,
+    VerbosityOption,
 };
 use hurl_core::types::{BytesPerSec, Count, DurationUnit};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{ContentEncoding, IpResolve, RequestedHttpVersion};
 use crate::util::logger::{Logger, Verbosity};
 
 use super::error::{RunnerError, RunnerErrorKind};
@@ -65,15 +68,33 @@ pub fn get_entry_options(
             }
             OptionKind::CaCertificate(filename) => {
                 let value = eval_template(filename, variables)?;
-                entry_options.cacert_file = Some(value);
+                let path = entry_options.context_dir.resolved_path(Path::new(&value));
+                entry_options.cacert_file = Some(path.to_string_lossy().to_string());
+            }
+            OptionKind::CaCertificateAppend(filename) => {
+                let value = eval_template(filename, variables)?;
+                let path = entry_options.context_dir.resolved_path(Path::new(&value));
+                entry_options.cacert_append_file = Some(path.to_string_lossy().to_string());
             }
             OptionKind::ClientCert(filename) => {
                 let value = eval_template(filename, variables)?;
-                entry_options.client_cert_file = Some(value);
+                // An entry cert is resolved against the context dir, like files in a request
+                // body. A potential `:password` suffix survives the join untouched.
+                let path = entry_options.context_dir.resolved_path(Path::new(&value));
+                entry_options.client_cert_file = Some(path.to_string_lossy().to_string());
             }
             OptionKind::ClientKey(filename) => {
                 let value = eval_template(filename, variables)?;
-                entry_options.client_key_file = Some(value);
+                let path = entry_options.context_dir.resolved_path(Path::new(&value));
+                entry_options.client_key_file = Some(path.to_string_lossy().to_string());
+            }
+            OptionKind::Compress(value) => {
+                let value = match value {
+                    CompressOption::Brotli => ContentEncoding::Brotli,
+                    CompressOption::Deflate => ContentEncoding::Deflate,
+                    CompressOption::Gzip => ContentEncoding::Gzip,
+                };
+                entry_options.compress = Some(value);
             }
             OptionKind::Compressed(value) => {
                 let value = eval_boolean_option(value, variables)?;
@@ -87,6 +108,26 @@ pub fn get_entry_options(
                 let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                 entry_options.connect_timeout = value;
             }
+            OptionKind::CsvSeparator(value) => {
+                let source_info = value.source_info;
+                let value = eval_template(value, variables)?;
+                let mut chars = value.chars();
+                let (Some(separator), None) = (chars.next(), chars.next()) else {
+                    let kind = RunnerErrorKind::ExpressionInvalidType {
+                        value: format!("string <{value}>"),
+                        expecting: "a single ASCII character".to_string(),
+                    };
+                    return Err(RunnerError::new(source_info, kind, false));
+                };
+                if !separator.is_ascii() {
+                    let kind = RunnerErrorKind::ExpressionInvalidType {
+                        value: format!("string <{value}>"),
+                        expecting: "a single ASCII character".to_string(),
+                    };
+                    return Err(RunnerError::new(source_info, kind, false));
+                }
+                entry_options.csv_separator = Some(separator as u8);
+            }
             OptionKind::Delay(value) => {
                 let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                 entry_options.delay = value;
@@ -178,6 +219,9 @@ pub fn get_entry_options(
             }
             OptionKind::Insecure(value) => {
                 let value = eval_boolean_option(value, variables)?;
+                if value && entry_options.warn_insecure {
+                    logger.warning("SSL certificate verification is disabled for this entry");
+                }
                 entry_options.insecure = value;
             }
             OptionKind::IpV4(value) => {
@@ -196,11 +240,21 @@ pub fn get_entry_options(
                     IpResolve::IpV4
                 }
             }
+            OptionKind::IpVersion(value) => {
+                entry_options.ip_resolve = match value {
+                    IpVersionOption::V4 => IpResolve::IpV4,
+                    IpVersionOption::V6 => IpResolve::IpV6,
+                }
+            }
             OptionKind::LimitRate(value) => {
                 let value = eval_natural_option(value, variables)?;
                 entry_options.max_send_speed = Some(BytesPerSec(value));
                 entry_options.max_recv_speed = Some(BytesPerSec(value));
             }
+            OptionKind::MaxFilesize(value) => {
+                let value = eval_natural_option(value, variables)?;
+                entry_options.max_filesize = Some(value);
+            }
             OptionKind::MaxRedirect(value) => {
                 let value = eval_count_option(value, variables)?;
                 entry_options.max_redirect = value;
@@ -262,6 +316,13 @@ pub fn get_entry_options(
                 let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                 entry_options.retry_interval = value;
             }
+            OptionKind::RetryMaxInterval(value) => {
+                let value = eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
+                entry_options.retry_max_interval = value;
+            }
+            OptionKind::RetryOnStatus(value) => {
+                entry_options.retry_on_status = value.iter().map(|s| s.as_u64() as u32).collect();
+            }
             OptionKind::Skip(value) => {
                 let value = eval_boolean_option(value, variables)?;
                 entry_options.skip = value;
@@ -290,8 +351,28 @@ pub fn get_entry_options(
             OptionKind::VeryVerbose(value) => {
                 eval_boolean_option(value, variables)?;
             }
+            OptionKind::XpathNamespace(value) => {
+                let source_info = value.source_info;
+                let value = eval_template(value, variables)?;
+                let Some((prefix, uri)) = value.split_once('=') else {
+                    let kind = RunnerErrorKind::ExpressionInvalidType {
+                        value: format!("string <{value}>"),
+                        expecting: "string <prefix=uri>".to_string(),
+                    };
+                    return Err(RunnerError::new(source_info, kind, false));
+                };
+                entry_options
+                    .xpath_namespaces
+                    .push((prefix.to_string(), uri.to_string()));
+            }
+        }
+        // The private key path is not echoed in verbose output, it's a hint to where
+        // sensitive material lives on disk.
+        if let OptionKind::ClientKey(_) = &option.kind {
+            logger.debug("key: ***");
+        } else {
+            logger.debug(&option.kind.to_string());
         }
-        logger.debug(&option.kind.to_string());
     }
 
     Ok(entry_options)
@@ -16,17 +16,28 @@
  *
  */
 use std::cmp::Ordering;
-
-use hurl_core::ast::{Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use hurl_core::ast::{
+    JsonValue, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SemverOperator,
+    SortOrder, SourceInfo,
+};
 use hurl_core::reader::Pos;
+use hurl_core::types::ToSource;
 
 use crate::util::path::ContextDir;
 
 use super::error::{RunnerError, RunnerErrorKind};
+use super::json::eval_json_value;
+use super::jsonschema;
 use super::number::Number;
 use super::predicate_value::{eval_predicate_value, eval_predicate_value_template};
+use super::template::eval_template;
 use super::value::{EvalError, Value};
 use super::variable::VariableSet;
+use super::xpath;
 
 /// Evaluates a `predicate` against an actual `value`.
 ///
@@ -154,6 +165,10 @@ fn expected_no_value(
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(value.format())
         }
+        PredicateFuncValue::EqualIgnoreCase { value, .. } => {
+            let value = eval_predicate_value(value, variables, context_dir)?;
+            Ok(format!("equals (ignoring case) {}", value.format()))
+        }
         PredicateFuncValue::GreaterThan { value, .. } => {
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("greater than <{}>", value.format()))
@@ -170,18 +185,44 @@ fn expected_no_value(
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("less than or equals to <{}>", value.format()))
         }
+        PredicateFuncValue::Approximately { value, delta, .. } => {
+            let value = eval_predicate_value(value, variables, context_dir)?;
+            let delta = eval_predicate_value(delta, variables, context_dir)?;
+            Ok(format!(
+                "approximately {} delta {}",
+                value.format(),
+                delta.format()
+            ))
+        }
+        PredicateFuncValue::Between { min, max, .. } => {
+            let min = eval_predicate_value(min, variables, context_dir)?;
+            let max = eval_predicate_value(max, variables, context_dir)?;
+            Ok(format!("between {} and {}", min.format(), max.format()))
+        }
         PredicateFuncValue::StartWith { value, .. } => {
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("starts with {}", value.format()))
         }
+        PredicateFuncValue::StartWithIgnoreCase { value, .. } => {
+            let value = eval_predicate_value(value, variables, context_dir)?;
+            Ok(format!("starts with (ignoring case) {}", value.format()))
+        }
         PredicateFuncValue::EndWith { value, .. } => {
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("ends with {}", value.format()))
         }
+        PredicateFuncValue::EndWithIgnoreCase { value, .. } => {
+            let value = eval_predicate_value(value, variables, context_dir)?;
+            Ok(format!("ends with (ignoring case) {}", value.format()))
+        }
         PredicateFuncValue::Contain { value, .. } => {
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("contains {}", value.format()))
         }
+        PredicateFuncValue::ContainIgnoreCase { value, .. } => {
+            let value = eval_predicate_value(value, variables, context_dir)?;
+            Ok(format!("contains (ignoring case) {}", value.format()))
+        }
         PredicateFuncValue::Include { value, .. } => {
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("include {}", value.format()))
@@ -192,8 +233,32 @@ fn expected_no_value(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("matches regex <{expected}>"))
         }
+        PredicateFuncValue::JsonSchema {
+            value: expected, ..
+        }
+        | PredicateFuncValue::MatchesSchema {
+            value: expected, ..
+        } => {
+            let expected = eval_predicate_value_template(expected, variables)?;
+            Ok(format!("matches JSON schema <{expected}>"))
+        }
+        PredicateFuncValue::MatchesJson { value, .. } => {
+            Ok(format!("matches JSON <{}>", value.to_source()))
+        }
+        PredicateFuncValue::Semver {
+            operator,
+            value: expected,
+            ..
+        } => {
+            let expected = eval_predicate_value_template(expected, variables)?;
+            Ok(format!("semver {} <{expected}>", operator.identifier()))
+        }
+        PredicateFuncValue::IsSorted { order, .. } => {
+            Ok(format!("list sorted {}", order.identifier()))
+        }
         PredicateFuncValue::Exist => Ok("something".to_string()),
         PredicateFuncValue::IsBoolean => Ok("boolean".to_string()),
+        PredicateFuncValue::IsClientError => Ok("4xx client error status".to_string()),
         PredicateFuncValue::IsCollection => Ok("collection".to_string()),
         PredicateFuncValue::IsDate => Ok("date".to_string()),
         PredicateFuncValue::IsEmpty => Ok("empty".to_string()),
@@ -202,11 +267,17 @@ fn expected_no_value(
         PredicateFuncValue::IsIpv4 => Ok("ipv4".to_string()),
         PredicateFuncValue::IsIpv6 => Ok("ipv6".to_string()),
         PredicateFuncValue::IsIsoDate => Ok("date".to_string()),
+        PredicateFuncValue::IsJson => Ok("valid JSON".to_string()),
         PredicateFuncValue::IsList => Ok("list".to_string()),
         PredicateFuncValue::IsNumber => Ok("number".to_string()),
         PredicateFuncValue::IsObject => Ok("object".to_string()),
+        PredicateFuncValue::IsRedirect => Ok("3xx redirect status".to_string()),
+        PredicateFuncValue::IsServerError => Ok("5xx server error status".to_string()),
         PredicateFuncValue::IsString => Ok("string".to_string()),
+        PredicateFuncValue::IsSuccess => Ok("2xx success status".to_string()),
+        PredicateFuncValue::IsUnique => Ok("list with unique elements".to_string()),
         PredicateFuncValue::IsUuid => Ok("uuid".to_string()),
+        PredicateFuncValue::IsXml => Ok("valid XML".to_string()),
     }
 }
 
@@ -236,6 +307,9 @@ fn eval_predicate_func(
         PredicateFuncValue::Equal {
             value: expected, ..
         } => eval_equal(expected, variables, value, context_dir),
+        PredicateFuncValue::EqualIgnoreCase {
+            value: expected, ..
+        } => eval_equal_ignorecase(expected, variables, value, context_dir),
         PredicateFuncValue::NotEqual {
             value: expected, ..
         } => eval_not_equal(expected, variables, value, context_dir),
@@ -251,15 +325,32 @@ fn eval_predicate_func(
         PredicateFuncValue::LessThanOrEqual {
             value: expected, ..
         } => eval_less_than_or_equal(expected, variables, value, context_dir),
+        PredicateFuncValue::Approximately {
+            value: expected,
+            delta,
+            ..
+        } => eval_approximately(expected, delta, variables, value, context_dir),
+        PredicateFuncValue::Between { min, max, .. } => {
+            eval_between(min, max, variables, value, context_dir)
+        }
         PredicateFuncValue::StartWith {
             value: expected, ..
         } => eval_start_with(expected, variables, value, context_dir),
+        PredicateFuncValue::StartWithIgnoreCase {
+            value: expected, ..
+        } => eval_start_with_ignorecase(expected, variables, value, context_dir),
         PredicateFuncValue::EndWith {
             value: expected, ..
         } => eval_end_with(expected, variables, value, context_dir),
+        PredicateFuncValue::EndWithIgnoreCase {
+            value: expected, ..
+        } => eval_end_with_ignorecase(expected, variables, value, context_dir),
         PredicateFuncValue::Contain {
             value: expected, ..
         } => eval_contain(expected, variables, value, context_dir),
+        PredicateFuncValue::ContainIgnoreCase {
+            value: expected, ..
+        } => eval_contain_ignorecase(expected, variables, value, context_dir),
         PredicateFuncValue::Include {
             value: expected, ..
         } => eval_include(expected, variables, value, context_dir),
@@ -272,8 +363,36 @@ fn eval_predicate_func(
             value,
             context_dir,
         ),
+        PredicateFuncValue::JsonSchema {
+            value: expected, ..
+        }
+        | PredicateFuncValue::MatchesSchema {
+            value: expected, ..
+        } => eval_json_schema(
+            expected,
+            predicate_func.source_info,
+            variables,
+            value,
+            context_dir,
+        ),
+        PredicateFuncValue::MatchesJson {
+            value: expected, ..
+        } => eval_matches_json(expected, variables, value),
+        PredicateFuncValue::Semver {
+            operator,
+            value: expected,
+            ..
+        } => eval_semver(
+            expected,
+            *operator,
+            predicate_func.source_info,
+            variables,
+            value,
+        ),
+        PredicateFuncValue::IsSorted { order, .. } => eval_is_sorted(value, *order),
         PredicateFuncValue::Exist => eval_exist(value),
         PredicateFuncValue::IsBoolean => eval_is_boolean(value),
+        PredicateFuncValue::IsClientError => eval_status_class(value, 400, "4xx client error"),
         PredicateFuncValue::IsCollection => eval_is_collection(value),
         PredicateFuncValue::IsDate => eval_is_date(value),
         PredicateFuncValue::IsEmpty => eval_is_empty(value),
@@ -282,11 +401,17 @@ fn eval_predicate_func(
         PredicateFuncValue::IsIpv4 => eval_is_ipv4(value),
         PredicateFuncValue::IsIpv6 => eval_is_ipv6(value),
         PredicateFuncValue::IsIsoDate => eval_is_iso_date(value),
+        PredicateFuncValue::IsJson => eval_is_json(value),
         PredicateFuncValue::IsList => eval_is_list(value),
         PredicateFuncValue::IsNumber => eval_is_number(value),
         PredicateFuncValue::IsObject => eval_is_object(value),
+        PredicateFuncValue::IsRedirect => eval_status_class(value, 300, "3xx redirect"),
+        PredicateFuncValue::IsServerError => eval_status_class(value, 500, "5xx server error"),
         PredicateFuncValue::IsString => eval_is_string(value),
+        PredicateFuncValue::IsSuccess => eval_status_class(value, 200, "2xx success"),
+        PredicateFuncValue::IsUnique => eval_is_unique(value),
         PredicateFuncValue::IsUuid => eval_is_uuid(value),
+        PredicateFuncValue::IsXml => eval_is_xml(value),
     }
 }
 
@@ -301,6 +426,33 @@ fn eval_equal(
     Ok(assert_values_equal(actual, &expected))
 }
 
+/// Evaluates if an `expected` string (using a `variables` set) is equal to an `actual` value,
+/// ignoring case: both strings are lowercased before comparison.
+fn eval_equal_ignorecase(
+    expected: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected_display = format!("equals (ignoring case) {}", expected.repr());
+    let actual_display = actual.repr();
+    match (actual, &expected) {
+        (Value::String(actual), Value::String(expected)) => Ok(PredicateResult {
+            success: actual.to_lowercase() == expected.to_lowercase(),
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: false,
+        }),
+        _ => Ok(PredicateResult {
+            success: false,
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: true,
+        }),
+    }
+}
+
 /// Evaluates if an `expected` value (using a `variables` set) is not equal to an `actual` value.
 fn eval_not_equal(
     expected: &PredicateValue,
@@ -356,6 +508,76 @@ fn eval_less_than_or_equal(
     Ok(assert_values_less_or_equal(actual, &expected))
 }
 
+/// Evaluates if an `actual` value is within a `delta` tolerance of an `expected` value (using a
+/// `variables` set).
+///
+/// The predicate succeeds if `|actual - expected| <= delta`. It works with integers and floats
+/// only; other types raise a type mismatch.
+fn eval_approximately(
+    expected: &PredicateValue,
+    delta: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let delta = eval_predicate_value(delta, variables, context_dir)?;
+    let expected_display = format!("approximately {} delta {}", expected.repr(), delta.repr());
+    let (Some(expected), Some(delta), Some(actual_number)) =
+        (as_float(&expected), as_float(&delta), as_float(actual))
+    else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+    Ok(PredicateResult {
+        success: (actual_number - expected).abs() <= delta,
+        actual: actual.repr(),
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
+/// Returns a `value` as a float if it's an integer or a float, `None` otherwise.
+fn as_float(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(Number::Float(f)) => Some(*f),
+        Value::Number(Number::Integer(i)) => Some(*i as f64),
+        _ => None,
+    }
+}
+
+/// Evaluates if an `actual` value is between a `min` and a `max` value (using a `variables`
+/// set), inclusive on both ends.
+fn eval_between(
+    min: &PredicateValue,
+    max: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let min = eval_predicate_value(min, variables, context_dir)?;
+    let max = eval_predicate_value(max, variables, context_dir)?;
+    let expected = format!("between {} and {}", min.repr(), max.repr());
+    match (actual.compare(&min), actual.compare(&max)) {
+        (Ok(min_ordering), Ok(max_ordering)) => Ok(PredicateResult {
+            success: min_ordering != Ordering::Less && max_ordering != Ordering::Greater,
+            actual: actual.repr(),
+            expected,
+            type_mismatch: false,
+        }),
+        _ => Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected,
+            type_mismatch: true,
+        }),
+    }
+}
+
 /// Evaluates if an `expected` value (using a `variables` set) starts with an `actual` value.
 /// This predicate works with string and bytes.
 fn eval_start_with(
@@ -383,6 +605,33 @@ fn eval_start_with(
     }
 }
 
+/// Evaluates if an `actual` string starts with an `expected` string (using a `variables` set),
+/// ignoring case: both strings are lowercased before comparison.
+fn eval_start_with_ignorecase(
+    expected: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected_display = format!("starts with (ignoring case) {}", expected.repr());
+    let actual_display = actual.repr();
+    match (actual, &expected) {
+        (Value::String(actual), Value::String(expected)) => Ok(PredicateResult {
+            success: actual.to_lowercase().starts_with(&expected.to_lowercase()),
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: false,
+        }),
+        _ => Ok(PredicateResult {
+            success: false,
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: true,
+        }),
+    }
+}
+
 /// Evaluates if an `expected` value (using a `variables` set) ends with an `actual` value.
 /// This predicate works with string and bytes.
 fn eval_end_with(
@@ -410,6 +659,33 @@ fn eval_end_with(
     }
 }
 
+/// Evaluates if an `actual` string ends with an `expected` string (using a `variables` set),
+/// ignoring case: both strings are lowercased before comparison.
+fn eval_end_with_ignorecase(
+    expected: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected_display = format!("ends with (ignoring case) {}", expected.repr());
+    let actual_display = actual.repr();
+    match (actual, &expected) {
+        (Value::String(actual), Value::String(expected)) => Ok(PredicateResult {
+            success: actual.to_lowercase().ends_with(&expected.to_lowercase()),
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: false,
+        }),
+        _ => Ok(PredicateResult {
+            success: false,
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: true,
+        }),
+    }
+}
+
 /// Evaluates if an `expected` value (using a `variables` set) contains an `actual` value.
 /// This predicate works with string and bytes.
 fn eval_contain(
@@ -437,6 +713,33 @@ fn eval_contain(
     }
 }
 
+/// Evaluates if an `actual` string contains an `expected` string (using a `variables` set),
+/// ignoring case: both strings are lowercased before comparison.
+fn eval_contain_ignorecase(
+    expected: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let expected_display = format!("contains (ignoring case) {}", expected.repr());
+    let actual_display = actual.repr();
+    match (actual, &expected) {
+        (Value::String(actual), Value::String(expected)) => Ok(PredicateResult {
+            success: actual.to_lowercase().contains(&expected.to_lowercase()),
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: false,
+        }),
+        _ => Ok(PredicateResult {
+            success: false,
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: true,
+        }),
+    }
+}
+
 /// Evaluates if an `expected` value (using a `variables` set) includes an `actual` value.
 /// This predicate works with list (maybe we should merge it with `eval_contains`?)
 fn eval_include(
@@ -481,6 +784,271 @@ fn eval_match(
     }
 }
 
+/// Parsed JSON Schema files, cached so that a schema referenced by several assertions in the
+/// same run is read and parsed only once. The cache is reset between runs (see
+/// [`reset_schemas`]): an edited schema file is picked up by the next run of the same process
+/// (e.g. with `--watch`).
+static SCHEMAS: Mutex<BTreeMap<PathBuf, serde_json::Value>> = Mutex::new(BTreeMap::new());
+
+/// Resets the JSON Schema cache used by the `jsonschema` and `matches-schema` predicates, called
+/// at the start of each run.
+pub(crate) fn reset_schemas() {
+    SCHEMAS.lock().unwrap().clear();
+}
+
+/// Loads and parses the JSON Schema file `file` (a filename, relative to the context dir
+/// `context_dir`), using the run-wide schema cache.
+fn load_schema(
+    file: &str,
+    source_info: SourceInfo,
+    context_dir: &ContextDir,
+) -> Result<serde_json::Value, RunnerError> {
+    // In order not to leak any private data, we check that the user provided schema
+    // file is a child of the context directory.
+    let path = PathBuf::from(file);
+    if !context_dir.is_access_allowed(&path) {
+        let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
+        return Err(RunnerError::new(source_info, kind, false));
+    }
+    let resolved_file = context_dir.resolved_path(&path);
+    let mut schemas = SCHEMAS.lock().unwrap();
+    if let Some(schema) = schemas.get(&resolved_file) {
+        return Ok(schema.clone());
+    }
+    let Ok(bytes) = std::fs::read(&resolved_file) else {
+        let kind = RunnerErrorKind::FileReadAccess { path };
+        return Err(RunnerError::new(source_info, kind, false));
+    };
+    let Ok(schema) = serde_json::from_slice::<serde_json::Value>(&bytes) else {
+        let kind = RunnerErrorKind::InvalidJson {
+            value: file.to_string(),
+        };
+        return Err(RunnerError::new(source_info, kind, false));
+    };
+    schemas.insert(resolved_file, schema.clone());
+    Ok(schema)
+}
+
+/// Evaluates if an `actual` value validates against the JSON Schema file referenced by
+/// `expected` (a filename, relative to the context dir `context_dir`).
+///
+/// This implements both the `jsonschema` and the `matches-schema` predicates.
+fn eval_json_schema(
+    expected: &PredicateValue,
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let file = eval_predicate_value_template(expected, variables)?;
+    let schema = load_schema(&file, source_info, context_dir)?;
+
+    let expected_display = format!("matches JSON schema <{file}>");
+    // String and bytes actual values hold a JSON document as text, other values are
+    // structured data already.
+    let instance = match actual {
+        Value::String(text) => serde_json::from_str::<serde_json::Value>(text).ok(),
+        Value::Bytes(bytes) => serde_json::from_slice::<serde_json::Value>(bytes).ok(),
+        value => Some(value.to_json(&[])),
+    };
+    let Some(instance) = instance else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+
+    let errors = jsonschema::validate(&instance, &schema);
+    let actual_display = if errors.is_empty() {
+        actual.format()
+    } else {
+        errors
+            .iter()
+            .map(|e| format!("invalid value at \"{}\": {}", e.pointer, e.message))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    Ok(PredicateResult {
+        success: errors.is_empty(),
+        actual: actual_display,
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
+/// Evaluates if an `actual` JSON value partially matches an `expected` JSON pattern (using a
+/// `variables` set).
+///
+/// Objects match if the actual object holds at least the expected keys with matching values,
+/// extra fields are ignored; lists require the same elements in the same order. A pattern value
+/// wrapped in `exact(...)` switches to exact matching for that value.
+fn eval_matches_json(
+    expected: &JsonValue,
+    variables: &VariableSet,
+    actual: &Value,
+) -> Result<PredicateResult, RunnerError> {
+    let expected_display = format!("matches JSON <{}>", expected.to_source());
+    // String and bytes actual values hold a JSON document as text, other values are
+    // structured data already.
+    let instance = match actual {
+        Value::String(text) => serde_json::from_str::<serde_json::Value>(text).ok(),
+        Value::Bytes(bytes) => serde_json::from_slice::<serde_json::Value>(bytes).ok(),
+        value => Some(value.to_json(&[])),
+    };
+    let Some(instance) = instance else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+    let success = matches_json(expected, &instance, variables)?;
+    Ok(PredicateResult {
+        success,
+        actual: actual.format(),
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
+/// Returns `true` if the `actual` JSON value matches the `expected` pattern, objects matching
+/// partially unless wrapped in `exact(...)`.
+fn matches_json(
+    expected: &JsonValue,
+    actual: &serde_json::Value,
+    variables: &VariableSet,
+) -> Result<bool, RunnerError> {
+    match expected {
+        JsonValue::Exact(expected) => {
+            let text = eval_json_value(expected, variables, false)?;
+            match serde_json::from_str::<serde_json::Value>(&text) {
+                Ok(expected) => Ok(json_values_equal(&expected, actual)),
+                Err(_) => Ok(false),
+            }
+        }
+        JsonValue::Object { elements, .. } => {
+            let Some(object) = actual.as_object() else {
+                return Ok(false);
+            };
+            for element in elements {
+                let name = eval_template(&element.name, variables)?;
+                match object.get(&name) {
+                    Some(value) => {
+                        if !matches_json(&element.value, value, variables)? {
+                            return Ok(false);
+                        }
+                    }
+                    None => return Ok(false),
+                }
+            }
+            Ok(true)
+        }
+        JsonValue::List { elements, .. } => {
+            let Some(list) = actual.as_array() else {
+                return Ok(false);
+            };
+            if list.len() != elements.len() {
+                return Ok(false);
+            }
+            for (element, value) in elements.iter().zip(list) {
+                if !matches_json(&element.value, value, variables)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        // Scalars are rendered then compared on their JSON value.
+        _ => {
+            let text = eval_json_value(expected, variables, false)?;
+            match serde_json::from_str::<serde_json::Value>(&text) {
+                Ok(expected) => Ok(json_values_equal(&expected, actual)),
+                Err(_) => Ok(false),
+            }
+        }
+    }
+}
+
+/// Compares two JSON values, numbers being compared on their value (`1.0` equals `1`).
+fn json_values_equal(expected: &serde_json::Value, actual: &serde_json::Value) -> bool {
+    match (expected, actual) {
+        (serde_json::Value::Number(expected), serde_json::Value::Number(actual)) => {
+            expected.as_f64() == actual.as_f64()
+        }
+        (serde_json::Value::Array(expected), serde_json::Value::Array(actual)) => {
+            expected.len() == actual.len()
+                && expected
+                    .iter()
+                    .zip(actual)
+                    .all(|(e, a)| json_values_equal(e, a))
+        }
+        (serde_json::Value::Object(expected), serde_json::Value::Object(actual)) => {
+            expected.len() == actual.len()
+                && expected
+                    .iter()
+                    .all(|(key, value)| actual.get(key).is_some_and(|a| json_values_equal(value, a)))
+        }
+        _ => expected == actual,
+    }
+}
+
+/// Evaluates if an `actual` value compares to an `expected` semantic version (using a `variables`
+/// set), with a given comparison `operator`.
+///
+/// Both sides are parsed as semantic versions (pre-release and build metadata are handled per the
+/// semver spec); a version that can't be parsed raises an error instead of a failed assert.
+fn eval_semver(
+    expected: &PredicateValue,
+    operator: SemverOperator,
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    actual: &Value,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = eval_predicate_value_template(expected, variables)?;
+    let expected_display = format!("semver {} <{expected}>", operator.identifier());
+    let Value::String(actual) = actual else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+    let expected_version = parse_semver(&expected, source_info)?;
+    let actual_version = parse_semver(actual, source_info)?;
+    let success = match operator {
+        SemverOperator::Equal => actual_version == expected_version,
+        SemverOperator::NotEqual => actual_version != expected_version,
+        SemverOperator::GreaterThan => actual_version > expected_version,
+        SemverOperator::GreaterThanOrEqual => actual_version >= expected_version,
+        SemverOperator::LessThan => actual_version < expected_version,
+        SemverOperator::LessThanOrEqual => actual_version <= expected_version,
+    };
+    Ok(PredicateResult {
+        success,
+        actual: format!("string <{actual}>"),
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
+/// Parses a `value` as a semantic version.
+///
+/// `source_info` is the source position of the predicate, used if an error is returned.
+fn parse_semver(value: &str, source_info: SourceInfo) -> Result<semver::Version, RunnerError> {
+    match semver::Version::parse(value.trim()) {
+        Ok(version) => Ok(version),
+        Err(_) => {
+            let kind = RunnerErrorKind::InvalidSemver {
+                value: value.to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, false))
+        }
+    }
+}
+
 /// Evaluates if an `actual` value is an integer.
 fn eval_is_integer(actual: &Value) -> Result<PredicateResult, RunnerError> {
     Ok(PredicateResult {
@@ -624,6 +1192,81 @@ fn eval_is_empty(actual: &Value) -> Result<PredicateResult, RunnerError> {
     }
 }
 
+/// Evaluates if an `actual` list is sorted in a given `order`.
+///
+/// Elements are compared pairwise, the failure reports the first out-of-order pair. Lists with
+/// elements of mixed types produce a type error.
+fn eval_is_sorted(actual: &Value, order: SortOrder) -> Result<PredicateResult, RunnerError> {
+    let expected_display = format!("list sorted {}", order.identifier());
+    let Value::List(values) = actual else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+    for pair in values.windows(2) {
+        let Ok(ordering) = pair[0].compare(&pair[1]) else {
+            return Ok(PredicateResult {
+                success: false,
+                actual: format!("{} and {} are not comparable", pair[0].repr(), pair[1].repr()),
+                expected: expected_display,
+                type_mismatch: true,
+            });
+        };
+        let out_of_order = match order {
+            SortOrder::Asc => ordering == Ordering::Greater,
+            SortOrder::Desc => ordering == Ordering::Less,
+        };
+        if out_of_order {
+            return Ok(PredicateResult {
+                success: false,
+                actual: format!("{} before {}", pair[0].repr(), pair[1].repr()),
+                expected: expected_display,
+                type_mismatch: false,
+            });
+        }
+    }
+    Ok(PredicateResult {
+        success: true,
+        actual: actual.repr(),
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
+/// Evaluates if an `actual` list contains no duplicated element.
+///
+/// The failure reports the first duplicated element.
+fn eval_is_unique(actual: &Value) -> Result<PredicateResult, RunnerError> {
+    let expected_display = "list with unique elements".to_string();
+    let Value::List(values) = actual else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+    for (index, value) in values.iter().enumerate() {
+        if values[index + 1..].contains(value) {
+            return Ok(PredicateResult {
+                success: false,
+                actual: format!("{} appears more than once", value.repr()),
+                expected: expected_display,
+                type_mismatch: false,
+            });
+        }
+    }
+    Ok(PredicateResult {
+        success: true,
+        actual: actual.repr(),
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
 /// Evaluates if an `actual` value is a number.
 fn eval_is_number(actual: &Value) -> Result<PredicateResult, RunnerError> {
     Ok(PredicateResult {
@@ -670,6 +1313,102 @@ fn eval_is_ipv6(actual: &Value) -> Result<PredicateResult, RunnerError> {
     }
 }
 
+/// Evaluates if an `actual` status code belongs to the class starting at `first` (e.g. 200 for
+/// 2xx success statuses), `class` being the display name of the class.
+fn eval_status_class(
+    actual: &Value,
+    first: i64,
+    class: &str,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = format!("{class} status");
+    match actual {
+        Value::Number(Number::Integer(status)) => Ok(PredicateResult {
+            success: (first..first + 100).contains(status),
+            actual: actual.repr(),
+            expected,
+            type_mismatch: false,
+        }),
+        _ => Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: "integer".to_string(),
+            type_mismatch: true,
+        }),
+    }
+}
+
+/// Evaluates if an `actual` value parses as a JSON document.
+fn eval_is_json(actual: &Value) -> Result<PredicateResult, RunnerError> {
+    let expected = "valid JSON".to_string();
+    let parsed = match actual {
+        Value::String(text) => serde_json::from_str::<serde_json::Value>(text).map(|_| ()),
+        Value::Bytes(bytes) => serde_json::from_slice::<serde_json::Value>(bytes).map(|_| ()),
+        _ => {
+            return Ok(PredicateResult {
+                success: false,
+                actual: actual.repr(),
+                expected: "string".to_string(),
+                type_mismatch: true,
+            })
+        }
+    };
+    match parsed {
+        Ok(()) => Ok(PredicateResult {
+            success: true,
+            actual: actual.format(),
+            expected,
+            type_mismatch: false,
+        }),
+        Err(error) => Ok(PredicateResult {
+            success: false,
+            actual: format!("invalid JSON: {error}"),
+            expected,
+            type_mismatch: false,
+        }),
+    }
+}
+
+/// Evaluates if an `actual` value parses as an XML document.
+fn eval_is_xml(actual: &Value) -> Result<PredicateResult, RunnerError> {
+    let expected = "valid XML".to_string();
+    let text = match actual {
+        Value::String(text) => text.clone(),
+        Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(text) => text.to_string(),
+            Err(error) => {
+                return Ok(PredicateResult {
+                    success: false,
+                    actual: format!("invalid XML: {error}"),
+                    expected,
+                    type_mismatch: false,
+                })
+            }
+        },
+        _ => {
+            return Ok(PredicateResult {
+                success: false,
+                actual: actual.repr(),
+                expected: "string".to_string(),
+                type_mismatch: true,
+            })
+        }
+    };
+    match xpath::Document::parse(&text, xpath::Format::Xml) {
+        Ok(_) => Ok(PredicateResult {
+            success: true,
+            actual: actual.format(),
+            expected,
+            type_mismatch: false,
+        }),
+        Err(error) => Ok(PredicateResult {
+            success: false,
+            actual: format!("invalid XML: {error}"),
+            expected,
+            type_mismatch: false,
+        }),
+    }
+}
+
 /// Evaluates if an `actual` value is a UUID.
 fn eval_is_uuid(actual: &Value) -> Result<PredicateResult, RunnerError> {
     match actual.is_uuid() {
@@ -1474,6 +2213,164 @@ mod tests {
         assert_eq!(result.expected, "matches regex </a{3}/>");
     }
 
+    #[test]
+    fn test_predicate_semver() {
+        let variables = VariableSet::new();
+
+        // predicate: `semver >= "2.0.0"`
+        let expected = PredicateValue::String(Template::new(
+            Some('"'),
+            vec![TemplateElement::String {
+                value: "2.0.0".to_string(),
+                source: "2.0.0".to_source(),
+            }],
+            SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        ));
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+
+        // value: 2.3.1
+        let value = Value::String("2.3.1".to_string());
+        let result = eval_semver(
+            &expected,
+            SemverOperator::GreaterThanOrEqual,
+            source_info,
+            &variables,
+            &value,
+        )
+        .unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.actual, "string <2.3.1>");
+        assert_eq!(result.expected, "semver >= <2.0.0>");
+
+        // value: 2.0.0-alpha, a pre-release is lower than the release
+        let value = Value::String("2.0.0-alpha".to_string());
+        let result = eval_semver(
+            &expected,
+            SemverOperator::GreaterThanOrEqual,
+            source_info,
+            &variables,
+            &value,
+        )
+        .unwrap();
+        assert!(!result.success);
+
+        // value: oops, not a semantic version
+        let value = Value::String("oops".to_string());
+        let error = eval_semver(
+            &expected,
+            SemverOperator::Equal,
+            source_info,
+            &variables,
+            &value,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::InvalidSemver {
+                value: "oops".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_predicate_approximately() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(&current_dir, file_root);
+
+        // predicate: `approximately 19.99 delta 0.01`
+        let expected = PredicateValue::Number(hurl_core::ast::Number::Float(Float::new(
+            19.99,
+            "19.99".to_source(),
+        )));
+        let delta = PredicateValue::Number(hurl_core::ast::Number::Float(Float::new(
+            0.01,
+            "0.01".to_source(),
+        )));
+
+        // value: 19.981
+        let value = Value::Number(Number::Float(19.981));
+        let result =
+            eval_approximately(&expected, &delta, &variables, &value, &context_dir).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.actual, "float <19.981>");
+        assert_eq!(result.expected, "approximately float <19.99> delta float <0.01>");
+
+        // value: 19.97, outside the tolerance
+        let value = Value::Number(Number::Float(19.97));
+        let result =
+            eval_approximately(&expected, &delta, &variables, &value, &context_dir).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+
+        // value: 20, an integer is compared as a float
+        let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(I64::new(
+            20,
+            "20".to_source(),
+        )));
+        let value = Value::Number(Number::Integer(20));
+        let result =
+            eval_approximately(&expected, &delta, &variables, &value, &context_dir).unwrap();
+        assert!(result.success);
+
+        // value: "19.99", a string is a type mismatch
+        let value = Value::String("19.99".to_string());
+        let result =
+            eval_approximately(&expected, &delta, &variables, &value, &context_dir).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_between() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(&current_dir, file_root);
+
+        // predicate: `between 18 65`
+        let min = PredicateValue::Number(hurl_core::ast::Number::Integer(I64::new(
+            18,
+            "18".to_source(),
+        )));
+        let max = PredicateValue::Number(hurl_core::ast::Number::Integer(I64::new(
+            65,
+            "65".to_source(),
+        )));
+
+        // value: 42, inside the range
+        let value = Value::Number(Number::Integer(42));
+        let result = eval_between(&min, &max, &variables, &value, &context_dir).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.actual, "integer <42>");
+        assert_eq!(result.expected, "between integer <18> and integer <65>");
+
+        // value: 18 and 65, the range is inclusive on both ends
+        let value = Value::Number(Number::Integer(18));
+        let result = eval_between(&min, &max, &variables, &value, &context_dir).unwrap();
+        assert!(result.success);
+        let value = Value::Number(Number::Integer(65));
+        let result = eval_between(&min, &max, &variables, &value, &context_dir).unwrap();
+        assert!(result.success);
+
+        // value: 66, outside the range
+        let value = Value::Number(Number::Integer(66));
+        let result = eval_between(&min, &max, &variables, &value, &context_dir).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+
+        // value: "42", a string is a type mismatch
+        let value = Value::String("42".to_string());
+        let result = eval_between(&min, &max, &variables, &value, &context_dir).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_is_iso_date() {
         let value = Value::String("2020-03-09T22:18:26.625Z".to_string());
@@ -1487,6 +2384,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_predicate_status_class() {
+        // value: 200, a success
+        let value = Value::Number(Number::Integer(200));
+        let result = eval_status_class(&value, 200, "2xx success").unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.expected, "2xx success status");
+
+        // value: 404, not a success
+        let value = Value::Number(Number::Integer(404));
+        let result = eval_status_class(&value, 200, "2xx success").unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.actual, "integer <404>");
+
+        // value: 404, a client error
+        let result = eval_status_class(&value, 400, "4xx client error").unwrap();
+        assert!(result.success);
+
+        // value: "200", a string is a type mismatch
+        let value = Value::String("200".to_string());
+        let result = eval_status_class(&value, 200, "2xx success").unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_is_json() {
+        let value = Value::String("{\"id\": 1}".to_string());
+        let result = eval_is_json(&value).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.expected, "valid JSON");
+
+        let value = Value::String("{\"id\":".to_string());
+        let result = eval_is_json(&value).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(
+            result.actual,
+            "invalid JSON: EOF while parsing a value at line 1 column 6"
+        );
+
+        let value = Value::Bool(true);
+        let result = eval_is_json(&value).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+        assert_eq!(result.expected, "string");
+    }
+
+    #[test]
+    fn test_predicate_is_xml() {
+        let value = Value::String("<root><child/></root>".to_string());
+        let result = eval_is_xml(&value).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.expected, "valid XML");
+
+        let value = Value::String("Hello World!".to_string());
+        let result = eval_is_xml(&value).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+        assert!(result.actual.starts_with("invalid XML:"));
+    }
+
     #[test]
     fn test_predicate_is_number() {
         let value = Value::Number(Number::Integer(1));
@@ -1503,4 +2466,145 @@ mod tests {
         assert_eq!(result.actual, "float <1.0>");
         assert_eq!(result.expected, "number");
     }
+
+    #[test]
+    fn test_predicate_is_sorted() {
+        let value = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(2)),
+            Value::Number(Number::Integer(2)),
+        ]);
+        let result = eval_is_sorted(&value, SortOrder::Asc).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.expected, "list sorted asc");
+
+        let result = eval_is_sorted(&value, SortOrder::Desc).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.actual, "integer <1> before integer <2>");
+
+        // Mixed types are not comparable
+        let value = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::String("2".to_string()),
+        ]);
+        let result = eval_is_sorted(&value, SortOrder::Asc).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+
+        // Not a list
+        let value = Value::String("abc".to_string());
+        let result = eval_is_sorted(&value, SortOrder::Asc).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_is_unique() {
+        let value = Value::List(vec![
+            Value::String("a".to_string()),
+            Value::String("b".to_string()),
+        ]);
+        let result = eval_is_unique(&value).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.expected, "list with unique elements");
+
+        let value = Value::List(vec![
+            Value::String("a".to_string()),
+            Value::String("b".to_string()),
+            Value::String("a".to_string()),
+        ]);
+        let result = eval_is_unique(&value).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.actual, "string <a> appears more than once");
+
+        // Not a list
+        let value = Value::Bool(true);
+        let result = eval_is_unique(&value).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_matches_json() {
+        fn json(s: &str) -> JsonValue {
+            let mut reader = hurl_core::reader::Reader::new(s);
+            hurl_core::parser::parse_json(&mut reader).unwrap()
+        }
+
+        let variables = VariableSet::new();
+        let actual = Value::String(
+            r#"{"id": 1, "name": "Alice", "meta": {"page": 1, "total": 3}, "tags": ["a", "b"]}"#
+                .to_string(),
+        );
+
+        // Objects are matched partially, extra fields in the actual value are ignored
+        let expected = json(r#"{"id": 1}"#);
+        let result = eval_matches_json(&expected, &variables, &actual).unwrap();
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        assert_eq!(result.expected, r#"matches JSON <{"id": 1}>"#);
+
+        // Nested objects are also matched partially...
+        let expected = json(r#"{"meta": {"page": 1}}"#);
+        let result = eval_matches_json(&expected, &variables, &actual).unwrap();
+        assert!(result.success);
+
+        // ...unless wrapped in `exact(...)`
+        let expected = json(r#"{"meta": exact({"page": 1})}"#);
+        let result = eval_matches_json(&expected, &variables, &actual).unwrap();
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+
+        let expected = json(r#"{"meta": exact({"page": 1, "total": 3})}"#);
+        let result = eval_matches_json(&expected, &variables, &actual).unwrap();
+        assert!(result.success);
+
+        // Lists are matched exactly, in order
+        let expected = json(r#"{"tags": ["a", "b"]}"#);
+        let result = eval_matches_json(&expected, &variables, &actual).unwrap();
+        assert!(result.success);
+
+        let expected = json(r#"{"tags": ["b", "a"]}"#);
+        let result = eval_matches_json(&expected, &variables, &actual).unwrap();
+        assert!(!result.success);
+
+        // A missing key is a failure
+        let expected = json(r#"{"missing": 1}"#);
+        let result = eval_matches_json(&expected, &variables, &actual).unwrap();
+        assert!(!result.success);
+
+        // An actual value that is not valid JSON is a type mismatch
+        let actual = Value::String("Hello World!".to_string());
+        let expected = json(r#"{"id": 1}"#);
+        let result = eval_matches_json(&expected, &variables, &actual).unwrap();
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+    }
+
+    #[test]
+    fn test_load_schema_cache_reset() {
+        let dir = std::env::temp_dir().join("hurl_load_schema_cache");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("s.json"), r#"{"type": "integer"}"#).unwrap();
+        let context_dir = ContextDir::new(&dir, &dir);
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+
+        let schema = load_schema("s.json", source_info, &context_dir).unwrap();
+        assert_eq!(schema, serde_json::json!({"type": "integer"}));
+
+        // The parsed schema is cached: an edited file is only re-read after a reset.
+        std::fs::write(dir.join("s.json"), r#"{"type": "string"}"#).unwrap();
+        let schema = load_schema("s.json", source_info, &context_dir).unwrap();
+        assert_eq!(schema, serde_json::json!({"type": "integer"}));
+
+        reset_schemas();
+        let schema = load_schema("s.json", source_info, &context_dir).unwrap();
+        assert_eq!(schema, serde_json::json!({"type": "string"}));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -15,10 +15,13 @@
  * limitations under the License.
  *
  */
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use chrono::Utc;
 use hurl_core::ast::{
-    CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, Query, QueryValue,
-    RegexValue, SourceInfo, Template,
+    CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, CsvColumn,
+    DigestAlgorithm, DurationPhaseName, NdjsonSelector, Query, QueryValue, RegexValue, SourceInfo,
+    Template,
 };
 use regex::Regex;
 use sha2::Digest;
@@ -28,6 +31,7 @@ use crate::http::{HttpError, Response, ResponseCookie};
 use super::cache::BodyCache;
 use super::error::{RunnerError, RunnerErrorKind};
 use super::filter;
+use super::hex;
 use super::http_response::HttpResponse;
 use super::number::Number;
 use super::template::eval_template;
@@ -50,22 +54,36 @@ pub fn eval_query(
         QueryValue::Version => eval_query_version(last_response),
         QueryValue::Url => eval_query_url(last_response),
         QueryValue::Header { name, .. } => eval_query_header(last_response, name, variables),
+        QueryValue::LinkHeader { rel, .. } => {
+            eval_query_link_header(last_response, rel, variables)
+        }
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
             ..
         } => eval_query_cookie(last_response, name, attribute, variables),
         QueryValue::Body => eval_query_body(last_response, query.source_info),
+        QueryValue::BodyDigest { algorithm, .. } => {
+            eval_query_body_digest(last_response, *algorithm)
+        }
+        QueryValue::Ndjson { selector, .. } => {
+            eval_query_ndjson(last_response, selector, variables, query.source_info)
+        }
         QueryValue::Xpath { expr, .. } => {
             eval_query_xpath(last_response, cache, expr, variables, query.source_info)
         }
         QueryValue::Jsonpath { expr, .. } => {
             eval_query_jsonpath(last_response, cache, expr, variables, query.source_info)
         }
+        QueryValue::Csv { column, row, .. } => {
+            eval_query_csv(last_response, cache, column, row.as_u64(), variables, query.source_info)
+        }
         QueryValue::Regex { value, .. } => {
             eval_query_regex(last_response, value, variables, query.source_info)
         }
         QueryValue::Variable { name, .. } => eval_query_variable(name, variables),
-        QueryValue::Duration => eval_query_duration(last_response),
+        QueryValue::Duration { phase } => {
+            eval_query_duration(last_response, phase.as_ref().map(|p| p.name))
+        }
         QueryValue::Bytes => eval_query_bytes(last_response, query.source_info),
         QueryValue::RawBytes => eval_query_rawbytes(last_response),
         QueryValue::Sha256 => eval_query_sha256(last_response, query.source_info),
@@ -121,6 +139,101 @@ fn eval_query_header(response: &Response, name: &Template, variables: &VariableS
     }
 }
 
+/// Evaluates a `link-header` query `rel` on the HTTP `response` given a set of `variables`.
+///
+/// The `Link` response headers are parsed as RFC 5988 link relations, and the URL of the first
+/// entry whose `rel` parameter matches is returned.
+fn eval_query_link_header(
+    response: &Response,
+    rel: &Template,
+    variables: &VariableSet,
+) -> QueryResult {
+    let rel = eval_template(rel, variables)?;
+    for value in response.headers.values("Link") {
+        for (url, rels) in parse_link_header(value) {
+            if rels.iter().any(|r| r == &rel) {
+                return Ok(Some(Value::String(url)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Parses a `Link` header `value` into a list of `(url, relation types)` entries.
+///
+/// Entries are comma-separated; a `rel` parameter value can be quoted (possibly holding several
+/// space-separated relation types) or a bare token.
+fn parse_link_header(value: &str) -> Vec<(String, Vec<String>)> {
+    let mut entries = vec![];
+    let mut chars = value.chars().peekable();
+    loop {
+        while matches!(chars.peek(), Some(' ' | '\t' | ',')) {
+            chars.next();
+        }
+        if chars.peek() != Some(&'<') {
+            break;
+        }
+        chars.next();
+        let mut url = String::new();
+        for c in chars.by_ref() {
+            if c == '>' {
+                break;
+            }
+            url.push(c);
+        }
+        let mut rels = vec![];
+        loop {
+            while matches!(chars.peek(), Some(' ' | '\t')) {
+                chars.next();
+            }
+            if chars.peek() != Some(&';') {
+                break;
+            }
+            chars.next();
+            while matches!(chars.peek(), Some(' ' | '\t')) {
+                chars.next();
+            }
+            let mut name = String::new();
+            while let Some(&c) = chars.peek() {
+                if c == '=' || c == ';' || c == ',' {
+                    break;
+                }
+                name.push(c);
+                chars.next();
+            }
+            let mut param_value = String::new();
+            if chars.peek() == Some(&'=') {
+                chars.next();
+                while matches!(chars.peek(), Some(' ' | '\t')) {
+                    chars.next();
+                }
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    for c in chars.by_ref() {
+                        if c == '"' {
+                            break;
+                        }
+                        param_value.push(c);
+                    }
+                } else {
+                    while let Some(&c) = chars.peek() {
+                        if c == ';' || c == ',' || c == ' ' || c == '\t' {
+                            break;
+                        }
+                        param_value.push(c);
+                        chars.next();
+                    }
+                }
+            }
+            if name.trim().eq_ignore_ascii_case("rel") {
+                rels.extend(param_value.split_ascii_whitespace().map(String::from));
+            }
+        }
+        entries.push((url, rels));
+    }
+    entries
+}
+
 /// Evaluates a cookie query `name` with optional attributes, on the HTTP `response` given a set of `variables`.
 fn eval_query_cookie(
     response: &Response,
@@ -168,11 +281,12 @@ fn eval_query_xpath(
     variables: &VariableSet,
     query_source_info: SourceInfo,
 ) -> QueryResult {
+    let namespaces = cache.xpath_namespaces().to_vec();
     let doc = match cache.xml() {
         Some(d) => d,
         None => parse_cache_xml(response, cache, query_source_info)?,
     };
-    filter::eval_xpath_doc(doc, expr, variables)
+    filter::eval_xpath_doc(doc, expr, variables, &namespaces)
 }
 
 /// Parse this HTTP `response` body to a structured XML document, and store the document to the
@@ -263,6 +377,82 @@ fn parse_cache_json<'cache>(
     Ok(cache.json().unwrap())
 }
 
+/// Evaluates a `csv` query on the HTTP `response`: the cell at `row` and `column` is returned
+/// as a string.
+///
+/// When the column is selected by name, the first record is treated as a header row and `row`
+/// indexes the remaining records.
+fn eval_query_csv(
+    response: &Response,
+    cache: &mut BodyCache,
+    column: &CsvColumn,
+    row: u64,
+    variables: &VariableSet,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let records = match cache.csv() {
+        Some(r) => r,
+        None => parse_cache_csv(response, cache, query_source_info)?,
+    };
+    let (column, row) = match column {
+        CsvColumn::Index(index) => (index.as_u64() as usize, row as usize),
+        CsvColumn::Name(name) => {
+            let name = eval_template(name, variables)?;
+            let Some(header) = records.first() else {
+                return Ok(None);
+            };
+            match header.iter().position(|h| h == &name) {
+                Some(index) => (index, row as usize + 1),
+                None => return Ok(None),
+            }
+        }
+    };
+    match records.get(row).and_then(|record| record.get(column)) {
+        Some(cell) => Ok(Some(Value::String(cell.clone()))),
+        None => Ok(None),
+    }
+}
+
+/// Parse this HTTP `response` body to CSV records, and store them to the response `cache`.
+///
+/// `query_source_info` is used for error reporting.
+fn parse_cache_csv<'cache>(
+    response: &Response,
+    cache: &'cache mut BodyCache,
+    query_source_info: SourceInfo,
+) -> Result<&'cache Vec<Vec<String>>, RunnerError> {
+    // Get the response as text if possible
+    let text = match response.text() {
+        Ok(t) => t,
+        Err(e) => {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::Http(e),
+                false,
+            ))
+        }
+    };
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(cache.csv_separator())
+        .from_reader(text.as_bytes());
+    let mut records = vec![];
+    for record in reader.records() {
+        let Ok(record) = record else {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::QueryInvalidCsv,
+                false,
+            ));
+        };
+        records.push(record.iter().map(str::to_string).collect());
+    }
+    // Everything is ok, we can put the response in the cache
+    cache.set_csv(records);
+    Ok(cache.csv().unwrap())
+}
+
 /// Evaluates a regex query on the HTTP `response` body, given a set of `variables`.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -282,22 +472,7 @@ fn eval_query_regex(
             ))
         }
     };
-    let re = match regex {
-        RegexValue::Template(t) => {
-            let value = eval_template(t, variables)?;
-            match Regex::new(value.as_str()) {
-                Ok(re) => re,
-                Err(_) => {
-                    return Err(RunnerError::new(
-                        t.source_info,
-                        RunnerErrorKind::InvalidRegex,
-                        false,
-                    ))
-                }
-            }
-        }
-        RegexValue::Regex(re) => re.inner.clone(),
-    };
+    let re = eval_regex_value(regex, variables)?;
     match re.captures(s.as_str()) {
         Some(captures) => match captures.get(1) {
             Some(v) => Ok(Some(Value::String(v.as_str().to_string()))),
@@ -307,6 +482,65 @@ fn eval_query_regex(
     }
 }
 
+/// Evaluates a `regex` AST node to a compiled [`Regex`], given a set of `variables`.
+fn eval_regex_value(regex: &RegexValue, variables: &VariableSet) -> Result<Regex, RunnerError> {
+    match regex {
+        RegexValue::Template(t) => {
+            let value = eval_template(t, variables)?;
+            match Regex::new(value.as_str()) {
+                Ok(re) => Ok(re),
+                Err(_) => Err(RunnerError::new(
+                    t.source_info,
+                    RunnerErrorKind::InvalidRegex,
+                    false,
+                )),
+            }
+        }
+        RegexValue::Regex(re) => Ok(re.inner.clone()),
+    }
+}
+
+/// Evaluates the named groups of a `regex` query on the HTTP `response` body, given a set of
+/// `variables`.
+///
+/// Each named group `(?<name>...)` of the pattern produces a `(name, value)` pair. A group that
+/// doesn't participate in the match produces an empty string, so that its variable is always
+/// defined when the pattern matches. An empty list is returned when the pattern doesn't match.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+pub fn eval_regex_named_groups(
+    response: &Response,
+    regex: &RegexValue,
+    variables: &VariableSet,
+    query_source_info: SourceInfo,
+) -> Result<Vec<(String, String)>, RunnerError> {
+    let s = match response.text() {
+        Ok(v) => v,
+        Err(inner) => {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::Http(inner),
+                false,
+            ))
+        }
+    };
+    let re = eval_regex_value(regex, variables)?;
+    let Some(captures) = re.captures(s.as_str()) else {
+        return Ok(vec![]);
+    };
+    let groups = re
+        .capture_names()
+        .flatten()
+        .map(|name| {
+            let value = captures
+                .name(name)
+                .map_or(String::new(), |m| m.as_str().to_string());
+            (name.to_string(), value)
+        })
+        .collect();
+    Ok(groups)
+}
+
 /// Evaluates a variable, given a set of `variables`.
 fn eval_query_variable(name: &Template, variables: &VariableSet) -> QueryResult {
     let name = eval_template(name, variables)?;
@@ -319,9 +553,15 @@ fn eval_query_variable(name: &Template, variables: &VariableSet) -> QueryResult
 
 /// Evaluates the effective duration of the HTTP `response` (only transfer time, assert and captures
 /// are not taken into account).
-fn eval_query_duration(response: &Response) -> QueryResult {
+fn eval_query_duration(response: &Response, phase: Option<DurationPhaseName>) -> QueryResult {
+    let duration = match phase {
+        None | Some(DurationPhaseName::Total) => response.duration,
+        Some(DurationPhaseName::Dns) => response.timings.name_lookup,
+        Some(DurationPhaseName::Connect) => response.timings.connect,
+        Some(DurationPhaseName::FirstByte) => response.timings.start_transfer,
+    };
     Ok(Some(Value::Number(Number::Integer(
-        response.duration.as_millis() as i64,
+        duration.as_millis() as i64,
     ))))
 }
 
@@ -344,6 +584,81 @@ fn eval_query_rawbytes(response: &Response) -> QueryResult {
     Ok(Some(Value::Bytes(response.body.clone())))
 }
 
+/// Evaluates an NDJSON `selector` on the HTTP `response` body.
+///
+/// The body is split on newlines, blank lines being skipped per the NDJSON spec. The `count`
+/// selector returns the number of lines; the `line <n> jsonpath <expr>` selector parses the nth
+/// (0-based) line as JSON and evaluates a JSONPath expression on it. A line number past the end
+/// of the body returns no result.
+fn eval_query_ndjson(
+    response: &Response,
+    selector: &NdjsonSelector,
+    variables: &VariableSet,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let text = match response.text() {
+        Ok(t) => t,
+        Err(inner) => {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::Http(inner),
+                false,
+            ));
+        }
+    };
+    let mut lines = text
+        .split('\n')
+        .map(|l| l.strip_suffix('\r').unwrap_or(l))
+        .filter(|l| !l.trim().is_empty());
+    match selector {
+        NdjsonSelector::Count => {
+            let count = lines.count() as i64;
+            Ok(Some(Value::Number(Number::Integer(count))))
+        }
+        NdjsonSelector::Line { line, expr, .. } => {
+            let Some(chunk) = lines.nth(line.as_u64() as usize) else {
+                return Ok(None);
+            };
+            let json = match serde_json::from_str(chunk) {
+                Ok(v) => v,
+                Err(_) => {
+                    return Err(RunnerError::new(
+                        query_source_info,
+                        RunnerErrorKind::QueryInvalidJson,
+                        false,
+                    ));
+                }
+            };
+            filter::eval_jsonpath_json(&json, expr, variables)
+        }
+    }
+}
+
+/// Evaluates a digest of the HTTP `response` body, hashed over the raw (undecoded) body bytes.
+///
+/// The digest is returned as raw bytes, or as a lowercase hexadecimal / Base64 string depending
+/// on the `algorithm` variant.
+fn eval_query_body_digest(response: &Response, algorithm: DigestAlgorithm) -> QueryResult {
+    let digest = match algorithm {
+        DigestAlgorithm::Sha256 | DigestAlgorithm::Sha256Hex | DigestAlgorithm::Sha256Base64 => {
+            let mut hasher = sha2::Sha256::new();
+            hasher.update(&response.body);
+            hasher.finalize()[..].to_vec()
+        }
+        DigestAlgorithm::Md5 | DigestAlgorithm::Md5Hex | DigestAlgorithm::Md5Base64 => {
+            md5::compute(&response.body).to_vec()
+        }
+    };
+    let value = match algorithm {
+        DigestAlgorithm::Sha256 | DigestAlgorithm::Md5 => Value::Bytes(digest),
+        DigestAlgorithm::Sha256Hex | DigestAlgorithm::Md5Hex => Value::String(hex::encode(&digest)),
+        DigestAlgorithm::Sha256Base64 | DigestAlgorithm::Md5Base64 => {
+            Value::String(BASE64_STANDARD.encode(&digest))
+        }
+    };
+    Ok(Some(value))
+}
+
 /// Evaluates the SHA-256 hash of the HTTP `response` body bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -404,6 +719,9 @@ fn eval_query_certificate(
             CertificateAttributeName::SubjectAltName => certificate
                 .subject_alt_name()
                 .map(|it| Value::String(it.clone())),
+            CertificateAttributeName::Version => {
+                certificate.version().map(|it| Value::String(it.clone()))
+            }
         };
         Ok(value)
     } else {
@@ -521,7 +839,7 @@ impl Value {
 pub mod tests {
     use std::num::ParseIntError;
 
-    use hurl_core::ast::{SourceInfo, TemplateElement, Whitespace};
+    use hurl_core::ast::{SourceInfo, TemplateElement, Whitespace, U64};
     use hurl_core::reader::Pos;
     use hurl_core::types::ToSource;
 
@@ -539,9 +857,142 @@ pub mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            timings: Default::default(),
         }
     }
 
+    #[test]
+    fn test_query_csv() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let response = Response {
+            body: b"name,age\nAlice,30\nBob,25\n".to_vec(),
+            ..default_response()
+        };
+
+        // csv col 0 row 1
+        let mut cache = BodyCache::new();
+        let column = CsvColumn::Index(U64::new(0, "0".to_source()));
+        assert_eq!(
+            eval_query_csv(&response, &mut cache, &column, 1, &variables, source_info)
+                .unwrap()
+                .unwrap(),
+            Value::String("Alice".to_string())
+        );
+
+        // csv col "age" row 1: the first record is a header row, `row 1` is Bob's record.
+        let column = CsvColumn::Name(Template::new(
+            Some('"'),
+            vec![TemplateElement::String {
+                value: "age".to_string(),
+                source: "age".to_source(),
+            }],
+            source_info,
+        ));
+        assert_eq!(
+            eval_query_csv(&response, &mut cache, &column, 1, &variables, source_info)
+                .unwrap()
+                .unwrap(),
+            Value::String("25".to_string())
+        );
+
+        // A column that doesn't exist returns no result.
+        let column = CsvColumn::Name(Template::new(
+            Some('"'),
+            vec![TemplateElement::String {
+                value: "city".to_string(),
+                source: "city".to_source(),
+            }],
+            source_info,
+        ));
+        assert!(
+            eval_query_csv(&response, &mut cache, &column, 0, &variables, source_info)
+                .unwrap()
+                .is_none()
+        );
+
+        // Tab-separated values, with the separator from the `csv-separator` option.
+        let response = Response {
+            body: b"Alice\t30\nBob\t25\n".to_vec(),
+            ..default_response()
+        };
+        let mut cache = BodyCache::new();
+        cache.set_csv_separator(Some(b'\t'));
+        let column = CsvColumn::Index(U64::new(1, "1".to_source()));
+        assert_eq!(
+            eval_query_csv(&response, &mut cache, &column, 1, &variables, source_info)
+                .unwrap()
+                .unwrap(),
+            Value::String("25".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_ndjson() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let response = Response {
+            body: b"{\"event\": \"created\"}\n\n{\"event\": \"deleted\"}\n".to_vec(),
+            ..default_response()
+        };
+
+        // body ndjson count: blank lines are skipped.
+        assert_eq!(
+            eval_query_ndjson(&response, &NdjsonSelector::Count, &variables, source_info)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(2))
+        );
+
+        // body ndjson line 1 jsonpath "$.event"
+        let line_selector = |line: u64| NdjsonSelector::Line {
+            space0: Whitespace {
+                value: String::from(" "),
+                source_info,
+            },
+            line: U64::new(line, line.to_string().to_source()),
+            space1: Whitespace {
+                value: String::from(" "),
+                source_info,
+            },
+            space2: Whitespace {
+                value: String::from(" "),
+                source_info,
+            },
+            expr: Template::new(
+                Some('"'),
+                vec![TemplateElement::String {
+                    value: "$.event".to_string(),
+                    source: "$.event".to_source(),
+                }],
+                source_info,
+            ),
+        };
+        assert_eq!(
+            eval_query_ndjson(&response, &line_selector(1), &variables, source_info)
+                .unwrap()
+                .unwrap(),
+            Value::String("deleted".to_string())
+        );
+
+        // A line number past the end of the body returns no result.
+        assert!(
+            eval_query_ndjson(&response, &line_selector(2), &variables, source_info)
+                .unwrap()
+                .is_none()
+        );
+
+        // A line that is not valid JSON is an error.
+        let response = Response {
+            body: b"not json\n".to_vec(),
+            ..default_response()
+        };
+        let error = eval_query_ndjson(&response, &line_selector(0), &variables, source_info)
+            .err()
+            .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
+    }
+
     pub fn xpath_invalid_query() -> Query {
         // xpath ???
         let whitespace = Whitespace {
@@ -760,6 +1211,48 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_duration() {
+        let response = Response {
+            duration: std::time::Duration::from_millis(200),
+            timings: http::Timings {
+                name_lookup: std::time::Duration::from_millis(5),
+                connect: std::time::Duration::from_millis(25),
+                start_transfer: std::time::Duration::from_millis(120),
+                ..Default::default()
+            },
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query_duration(&response, None).unwrap().unwrap(),
+            Value::Number(Number::Integer(200))
+        );
+        assert_eq!(
+            eval_query_duration(&response, Some(DurationPhaseName::Dns))
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(5))
+        );
+        assert_eq!(
+            eval_query_duration(&response, Some(DurationPhaseName::Connect))
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(25))
+        );
+        assert_eq!(
+            eval_query_duration(&response, Some(DurationPhaseName::FirstByte))
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(120))
+        );
+        assert_eq!(
+            eval_query_duration(&response, Some(DurationPhaseName::Total))
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(200))
+        );
+    }
+
     #[test]
     fn test_header_not_found() {
         let variables = VariableSet::new();
@@ -834,6 +1327,64 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_link_header() {
+        let variables = VariableSet::new();
+
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new(
+            "Link",
+            "<https://api.example.com/items?page=2>; rel=\"next\", <https://api.example.com/items?page=10>; rel=last",
+        ));
+        headers.push(http::Header::new(
+            "Link",
+            "<https://api.example.com/items?page=1>; rel=\"first prev\"; title=\"Page 1\"",
+        ));
+        let response = Response {
+            headers,
+            ..default_response()
+        };
+
+        let rel = |value: &str| {
+            Template::new(
+                Some('"'),
+                vec![TemplateElement::String {
+                    value: value.to_string(),
+                    source: value.to_source(),
+                }],
+                SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            )
+        };
+
+        // The rel value can be quoted or a bare token.
+        assert_eq!(
+            eval_query_link_header(&response, &rel("next"), &variables)
+                .unwrap()
+                .unwrap(),
+            Value::String("https://api.example.com/items?page=2".to_string())
+        );
+        assert_eq!(
+            eval_query_link_header(&response, &rel("last"), &variables)
+                .unwrap()
+                .unwrap(),
+            Value::String("https://api.example.com/items?page=10".to_string())
+        );
+
+        // A quoted rel value can hold several space-separated relation types,
+        // spread over multiple Link headers.
+        assert_eq!(
+            eval_query_link_header(&response, &rel("prev"), &variables)
+                .unwrap()
+                .unwrap(),
+            Value::String("https://api.example.com/items?page=1".to_string())
+        );
+
+        assert_eq!(
+            eval_query_link_header(&response, &rel("unknown"), &variables).unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn test_query_cookie() {
         let variables = VariableSet::new();
@@ -1424,6 +1975,50 @@ pub mod tests {
         assert_eq!(error.kind, RunnerErrorKind::InvalidRegex);
     }
 
+    #[test]
+    fn test_regex_named_groups() {
+        let variables = VariableSet::new();
+
+        // regex "(?<greeting>Hello) (?<name>[a-zA-Z]+)(?<punct>\?)?"
+        let regex = RegexValue::Template(Template::new(
+            Some('"'),
+            vec![TemplateElement::String {
+                value: "(?<greeting>Hello) (?<name>[a-zA-Z]+)(?<punct>\\?)?".to_string(),
+                source: "(?<greeting>Hello) (?<name>[a-zA-Z]+)(?<punct>\\\\?)?".to_source(),
+            }],
+            SourceInfo::new(Pos::new(1, 7), Pos::new(1, 58)),
+        ));
+        let groups = eval_regex_named_groups(
+            &http::hello_http_response(),
+            &regex,
+            &variables,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 58)),
+        )
+        .unwrap();
+        assert_eq!(
+            groups,
+            vec![
+                ("greeting".to_string(), "Hello".to_string()),
+                ("name".to_string(), "World".to_string()),
+                // The optional group doesn't participate in the match: empty string.
+                ("punct".to_string(), String::new()),
+            ]
+        );
+
+        // A pattern without named groups produces no variable.
+        let QueryValue::Regex { value: regex, .. } = regex_name().value else {
+            panic!("regex query expected");
+        };
+        let groups = eval_regex_named_groups(
+            &http::hello_http_response(),
+            &regex,
+            &variables,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 26)),
+        )
+        .unwrap();
+        assert!(groups.is_empty());
+    }
+
     #[test]
     fn test_query_bytes() {
         let variables = VariableSet::new();
@@ -1500,6 +2095,50 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_body_digest() {
+        let response = Response {
+            body: vec![0xff],
+            ..default_response()
+        };
+
+        assert_eq!(
+            eval_query_body_digest(&response, DigestAlgorithm::Sha256)
+                .unwrap()
+                .unwrap(),
+            Value::Bytes(
+                decode_hex("a8100ae6aa1940d0b663bb31cd466142ebbdbd5187131b92d93818987832eb89")
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            eval_query_body_digest(&response, DigestAlgorithm::Sha256Hex)
+                .unwrap()
+                .unwrap(),
+            Value::String(
+                "a8100ae6aa1940d0b663bb31cd466142ebbdbd5187131b92d93818987832eb89".to_string()
+            )
+        );
+        assert_eq!(
+            eval_query_body_digest(&response, DigestAlgorithm::Sha256Base64)
+                .unwrap()
+                .unwrap(),
+            Value::String("qBAK5qoZQNC2Y7sxzUZhQuu9vVGHExuS2TgYmHgy64k=".to_string())
+        );
+        assert_eq!(
+            eval_query_body_digest(&response, DigestAlgorithm::Md5Hex)
+                .unwrap()
+                .unwrap(),
+            Value::String("00594fd4f42ba43fc1ca0427a0576295".to_string())
+        );
+        assert_eq!(
+            eval_query_body_digest(&response, DigestAlgorithm::Md5Base64)
+                .unwrap()
+                .unwrap(),
+            Value::String("AFlP1PQrpD/BygQnoFdilQ==".to_string())
+        );
+    }
+
     #[test]
     fn test_query_certificate() {
         assert!(eval_query_certificate(
@@ -1517,6 +2156,7 @@ pub mod tests {
         let expire_date = Default::default();
         let serial_number = Some(String::new());
         let subject_alt_name = Some(String::new());
+        let version = Some(String::new());
         let certificate = http::Certificate::new(
             subject,
             issuer,
@@ -1524,6 +2164,7 @@ pub mod tests {
             expire_date,
             serial_number,
             subject_alt_name,
+            version,
         );
         assert_eq!(
             eval_query_certificate(
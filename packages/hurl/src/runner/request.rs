@@ -31,18 +31,23 @@ use crate::util::path::ContextDir;
 
 use super::body;
 use super::error::{RunnerError, RunnerErrorKind};
+use super::multiline;
 use super::multipart;
 use super::template;
 use super::variable::VariableSet;
 
 /// Transforms an AST `request` to a spec request given a set of `variables`.
+///
+/// `previous_url` is the URL of the last executed request, used to resolve the asterisk form
+/// `OPTIONS *`.
 pub fn eval_request(
     request: &Request,
     variables: &VariableSet,
     context_dir: &ContextDir,
+    previous_url: Option<&Url>,
 ) -> Result<RequestSpec, RunnerError> {
     let method = eval_method(&request.method);
-    let url = eval_url(&request.url, variables)?;
+    let (url, asterisk_form) = eval_url(&request.url, variables, previous_url, &method)?;
 
     // Headers
     let mut headers = HeaderVec::new();
@@ -92,9 +97,15 @@ pub fn eval_request(
         cookies.push(cookie);
     }
 
-    let body = match &request.body {
-        Some(body) => body::eval_body(body, variables, context_dir)?,
-        None => Body::Binary(vec![]),
+    // A [GraphQL] section composes the body of the request (the parser ensures that there is no
+    // explicit body in this case).
+    let body = match (request.graphql(), &request.body) {
+        (Some(graphql), _) => {
+            let s = multiline::eval_graphql_section(graphql, variables)?;
+            Body::Text(s)
+        }
+        (None, Some(body)) => body::eval_body(body, variables, context_dir)?,
+        (None, None) => Body::Binary(vec![]),
     };
 
     let mut multipart = vec![];
@@ -103,7 +114,9 @@ pub fn eval_request(
         multipart.push(param);
     }
 
-    let implicit_content_type = if !form.is_empty() {
+    let implicit_content_type = if request.graphql().is_some() {
+        Some("application/json".to_string())
+    } else if !form.is_empty() {
         Some("application/x-www-form-urlencoded".to_string())
     } else if !multipart.is_empty() {
         Some("multipart/form-data".to_string())
@@ -139,6 +152,7 @@ pub fn eval_request(
 
     Ok(RequestSpec {
         method,
+        asterisk_form,
         url,
         headers,
         querystring,
@@ -150,11 +164,40 @@ pub fn eval_request(
     })
 }
 
-fn eval_url(url_template: &Template, variables: &VariableSet) -> Result<Url, RunnerError> {
+/// Evaluates the URL of a request, given a set of `variables`. Returns the URL to connect to, and
+/// whether the request uses the asterisk form `OPTIONS *`: the literal `*` is resolved against the
+/// origin of `previous_url`, the URL of the last executed request.
+fn eval_url(
+    url_template: &Template,
+    variables: &VariableSet,
+    previous_url: Option<&Url>,
+    method: &Method,
+) -> Result<(Url, bool), RunnerError> {
     let url = template::eval_template(url_template, variables)?;
+    if url == "*" {
+        let source_info = url_template.source_info;
+        if method.0 != "OPTIONS" {
+            let runner_error_kind = RunnerErrorKind::InvalidUrl {
+                url,
+                message: "The asterisk form is only allowed for OPTIONS requests".to_string(),
+            };
+            return Err(RunnerError::new(source_info, runner_error_kind, false));
+        }
+        return match previous_url {
+            Some(previous_url) => Ok((previous_url.origin(), true)),
+            None => {
+                let runner_error_kind = RunnerErrorKind::InvalidUrl {
+                    url,
+                    message: "The asterisk form requires a previous request to provide the origin"
+                        .to_string(),
+                };
+                Err(RunnerError::new(source_info, runner_error_kind, false))
+            }
+        };
+    }
     let url = Url::from_str(&url);
     match url {
-        Ok(u) => Ok(u),
+        Ok(u) => Ok((u, false)),
         Err(UrlError { url, reason }) => {
             let source_info = url_template.source_info;
             let runner_error_kind = RunnerErrorKind::InvalidUrl {
@@ -367,7 +410,7 @@ mod tests {
     #[test]
     fn test_error_variable() {
         let variables = VariableSet::new();
-        let error = eval_request(&hello_request(), &variables, &ContextDir::default())
+        let error = eval_request(&hello_request(), &variables, &ContextDir::default(), None)
             .err()
             .unwrap();
         assert_eq!(
@@ -390,7 +433,7 @@ mod tests {
             Value::String(String::from("http://localhost:8000")),
         );
         let http_request =
-            eval_request(&hello_request(), &variables, &ContextDir::default()).unwrap();
+            eval_request(&hello_request(), &variables, &ContextDir::default(), None).unwrap();
         assert_eq!(http_request, http::hello_http_request());
     }
 
@@ -402,10 +445,55 @@ mod tests {
             Value::String(String::from("value1")),
         );
         let http_request =
-            eval_request(&query_request(), &variables, &ContextDir::default()).unwrap();
+            eval_request(&query_request(), &variables, &ContextDir::default(), None).unwrap();
         assert_eq!(http_request, http::query_http_request());
     }
 
+    #[test]
+    fn test_asterisk_form_request() {
+        let mut request = hello_request();
+        request.method = AstMethod::new("OPTIONS");
+        request.url = Template::new(
+            None,
+            vec![TemplateElement::String {
+                value: "*".to_string(),
+                source: "*".to_source(),
+            }],
+            SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        );
+        let variables = VariableSet::new();
+
+        // Without a previous request, there is no origin to connect to.
+        let error = eval_request(&request, &variables, &ContextDir::default(), None)
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, RunnerErrorKind::InvalidUrl { .. }));
+
+        // With a previous request, the URL is the origin of the previous URL.
+        let previous_url = http::Url::from_str("http://localhost:8000/hello").unwrap();
+        let http_request = eval_request(
+            &request,
+            &variables,
+            &ContextDir::default(),
+            Some(&previous_url),
+        )
+        .unwrap();
+        assert!(http_request.asterisk_form);
+        assert_eq!(http_request.url.raw(), "http://localhost:8000");
+
+        // The asterisk form is only valid for OPTIONS requests.
+        request.method = AstMethod::new("GET");
+        let error = eval_request(
+            &request,
+            &variables,
+            &ContextDir::default(),
+            Some(&previous_url),
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(error.kind, RunnerErrorKind::InvalidUrl { .. }));
+    }
+
     #[test]
     fn clear_cookie_store() {
         assert!(!get_cmd_cookie_storage_clear(&hello_request()));
@@ -15,7 +15,7 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Base64, Body, Bytes, Hex, Response, SourceInfo, StatusValue};
+use hurl_core::ast::{Base64, Body, Bytes, Hex, QueryValue, Response, SourceInfo, StatusValue};
 
 use crate::http;
 use crate::util::path::ContextDir;
@@ -27,6 +27,7 @@ use super::capture;
 use super::error::{RunnerError, RunnerErrorKind};
 use super::json;
 use super::multiline;
+use super::query;
 use super::result::{AssertResult, CaptureResult};
 use super::template;
 use super::value::Value;
@@ -386,6 +387,25 @@ pub fn eval_captures(
             // We insert a public capture.
             variables.insert(name, value);
         }
+
+        // The named groups of a regex capture create one additional variable per group, without
+        // needing a separate capture line for each.
+        if let QueryValue::Regex { value, .. } = &capture.query.value {
+            let last_response = http_responses.last().unwrap();
+            let groups = query::eval_regex_named_groups(
+                last_response,
+                value,
+                variables,
+                capture.query.source_info,
+            )?;
+            for (group_name, group_value) in groups {
+                if capture.redacted {
+                    variables.insert_secret(group_name, group_value);
+                } else {
+                    variables.insert(group_name, Value::String(group_value));
+                }
+            }
+        }
         captures.push(capture_result);
     }
     Ok(captures)
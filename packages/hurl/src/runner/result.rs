@@ -35,6 +35,9 @@ use super::variable::VariableSet;
 pub struct HurlResult {
     /// The entries result for this run.
     pub entries: Vec<EntryResult>,
+    /// The failed entries of this run. With `--continue-on-error`, a run goes on after a failed
+    /// entry and this list can hold more than one failure.
+    pub failures: Vec<EntryFailure>,
     /// Total duration of the run, including asserts and results computation.
     pub duration: Duration,
     /// `true` if the run is successful, `false` if there has been runtime or asserts errors.
@@ -52,8 +55,26 @@ impl HurlResult {
     /// of the entry where the error happens.
     ///
     /// The errors are only the "effective" ones: those that are due to retry are
-    /// ignored.
+    /// ignored, as well as warnings.
     pub fn errors(&self) -> Vec<(&RunnerError, SourceInfo)> {
+        self.effective_errors()
+            .into_iter()
+            .filter(|(error, _)| !error.is_warning())
+            .collect()
+    }
+
+    /// Returns all the effective warnings of this `HurlResult`, with the source information
+    /// of the entry where the warning happens.
+    pub fn warnings(&self) -> Vec<(&RunnerError, SourceInfo)> {
+        self.effective_errors()
+            .into_iter()
+            .filter(|(error, _)| error.is_warning())
+            .collect()
+    }
+
+    /// Returns all the effective errors (warnings included) of this `HurlResult`: those
+    /// that are due to retry are ignored.
+    fn effective_errors(&self) -> Vec<(&RunnerError, SourceInfo)> {
         let mut errors = vec![];
         let mut next_entries = self.entries.iter().skip(1);
         for entry in self.entries.iter() {
@@ -75,6 +96,23 @@ impl HurlResult {
     }
 }
 
+/// Represents a failed entry of a run, as reported in the run summary.
+///
+/// Contrary to [`EntryResult`], the failures don't hold the results of retried attempts: there is
+/// one failure per effectively failed entry execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryFailure {
+    /// Index of the failed entry on the file execution.
+    pub entry_index: Index,
+    /// HTTP method of the entry request.
+    pub method: String,
+    /// URL of the entry request, evaluated if a request has been executed, as written in the
+    /// source file otherwise.
+    pub url: String,
+    /// Short description of the first error of the entry.
+    pub message: String,
+}
+
 /// Represents the execution result of an entry.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EntryResult {
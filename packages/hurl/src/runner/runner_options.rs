@@ -19,7 +19,7 @@ use std::time::Duration;
 
 use hurl_core::types::{BytesPerSec, Count};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{ContentEncoding, IpResolve, RequestedHttpVersion};
 use crate::util::path::ContextDir;
 
 use super::output::Output;
@@ -28,17 +28,21 @@ use super::output::Output;
 pub struct RunnerOptionsBuilder {
     allow_reuse: bool,
     aws_sigv4: Option<String>,
+    cacert_append_file: Option<String>,
     cacert_file: Option<String>,
     client_cert_file: Option<String>,
     client_key_file: Option<String>,
+    compress: Option<ContentEncoding>,
     compressed: bool,
     connect_timeout: Duration,
     connects_to: Vec<String>,
     context_dir: ContextDir,
     continue_on_error: bool,
     cookie_input_file: Option<String>,
+    csv_separator: Option<u8>,
     delay: Duration,
     digest: bool,
+    exclude_tags: Vec<String>,
     follow_location: bool,
     follow_location_trusted: bool,
     from_entry: Option<usize>,
@@ -65,14 +69,19 @@ pub struct RunnerOptionsBuilder {
     resolves: Vec<String>,
     retry: Option<Count>,
     retry_interval: Duration,
+    retry_max_interval: Duration,
+    retry_on_status: Vec<u32>,
     skip: bool,
     ssl_no_revoke: bool,
+    tags: Vec<String>,
     timeout: Duration,
     to_entry: Option<usize>,
     unix_socket: Option<String>,
     use_cookie_store: bool,
     user: Option<String>,
     user_agent: Option<String>,
+    warn_insecure: bool,
+    xpath_namespaces: Vec<(String, String)>,
 }
 
 impl Default for RunnerOptionsBuilder {
@@ -80,17 +89,21 @@ impl Default for RunnerOptionsBuilder {
         RunnerOptionsBuilder {
             allow_reuse: true,
             aws_sigv4: None,
+            cacert_append_file: None,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
+            compress: None,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
             context_dir: ContextDir::default(),
             continue_on_error: false,
             cookie_input_file: None,
+            csv_separator: None,
             delay: Duration::from_millis(0),
             digest: false,
+            exclude_tags: vec![],
             follow_location: false,
             follow_location_trusted: false,
             from_entry: None,
@@ -117,14 +130,19 @@ impl Default for RunnerOptionsBuilder {
             resolves: vec![],
             retry: None,
             retry_interval: Duration::from_millis(1000),
+            retry_max_interval: Duration::from_millis(10000),
+            retry_on_status: vec![],
             skip: false,
             ssl_no_revoke: false,
+            tags: vec![],
             timeout: Duration::from_secs(300),
             to_entry: None,
             unix_socket: None,
             use_cookie_store: true,
             user: None,
             user_agent: None,
+            warn_insecure: true,
+            xpath_namespaces: vec![],
         }
     }
 }
@@ -152,6 +170,13 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Specifies a certificate file appended to the default CA certificates for peer
+    /// verification. The file may contain multiple CA certificates and must be in PEM format.
+    pub fn cacert_append_file(&mut self, cacert_append_file: Option<String>) -> &mut Self {
+        self.cacert_append_file = cacert_append_file;
+        self
+    }
+
     /// Specifies the certificate file for peer verification.
     /// The file may contain multiple CA certificates and must be in PEM format.
     pub fn cacert_file(&mut self, cacert_file: Option<String>) -> &mut Self {
@@ -171,6 +196,13 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Compresses the request body using one of the algorithms br, gzip, deflate before
+    /// sending it.
+    pub fn compress(&mut self, compress: Option<ContentEncoding>) -> &mut Self {
+        self.compress = compress;
+        self
+    }
+
     /// Requests a compressed response using one of the algorithms br, gzip, deflate and
     /// automatically decompress the content.
     pub fn compressed(&mut self, compressed: bool) -> &mut Self {
@@ -226,6 +258,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the field separator used by `csv` queries (a comma by default).
+    pub fn csv_separator(&mut self, csv_separator: Option<u8>) -> &mut Self {
+        self.csv_separator = csv_separator;
+        self
+    }
+
     /// Sets stopping or continuing executing requests to the end of the Hurl file even when an assert error occurs.
     ///
     /// By default, Hurl exits after an assert error in the HTTP response. Note that this option does
@@ -235,6 +273,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the list of tags that skip an entry when present in its `[Tags]` section.
+    pub fn exclude_tags(&mut self, exclude_tags: &[String]) -> &mut Self {
+        self.exclude_tags = exclude_tags.to_vec();
+        self
+    }
+
     /// Sets follow redirect.
     ///
     /// To limit the amount of redirects to follow use [`Self::max_redirect`].
@@ -407,6 +451,21 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets maximum duration between each retry, when backing off exponentially.
+    ///
+    /// Default is 10,000 ms.
+    pub fn retry_max_interval(&mut self, retry_max_interval: Duration) -> &mut Self {
+        self.retry_max_interval = retry_max_interval;
+        self
+    }
+
+    /// Sets the list of HTTP response status codes that trigger a retry, even when the entry
+    /// has no error.
+    pub fn retry_on_status(&mut self, retry_on_status: Vec<u32>) -> &mut Self {
+        self.retry_on_status = retry_on_status;
+        self
+    }
+
     /// Skip the run without executing any request.
     pub fn skip(&mut self, skip: bool) -> &mut Self {
         self.skip = skip;
@@ -419,6 +478,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the list of tags an entry must bear in its `[Tags]` section to be executed.
+    ///
+    /// When empty, every entry is executed.
+    pub fn tags(&mut self, tags: &[String]) -> &mut Self {
+        self.tags = tags.to_vec();
+        self
+    }
+
     /// Sets maximum time allowed for the transfer.
     ///
     /// Default 300 seconds.
@@ -455,14 +522,28 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Warns when an entry enables insecure SSL connections through its `[Options]` section.
+    pub fn warn_insecure(&mut self, warn_insecure: bool) -> &mut Self {
+        self.warn_insecure = warn_insecure;
+        self
+    }
+
+    /// Sets the XPath namespaces, as a list of (prefix, URI) pairs.
+    pub fn xpath_namespaces(&mut self, namespaces: &[(String, String)]) -> &mut Self {
+        self.xpath_namespaces = namespaces.to_vec();
+        self
+    }
+
     /// Create an instance of [`RunnerOptions`].
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
             allow_reuse: self.allow_reuse,
             aws_sigv4: self.aws_sigv4.clone(),
+            cacert_append_file: self.cacert_append_file.clone(),
             cacert_file: self.cacert_file.clone(),
             client_cert_file: self.client_cert_file.clone(),
             client_key_file: self.client_key_file.clone(),
+            compress: self.compress,
             compressed: self.compressed,
             connect_timeout: self.connect_timeout,
             connects_to: self.connects_to.clone(),
@@ -470,9 +551,11 @@ impl RunnerOptionsBuilder {
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
+            csv_separator: self.csv_separator,
             digest: self.digest,
             follow_location: self.follow_location,
             follow_location_trusted: self.follow_location_trusted,
+            exclude_tags: self.exclude_tags.clone(),
             from_entry: self.from_entry,
             headers: self.headers.clone(),
             http_version: self.http_version,
@@ -497,14 +580,19 @@ impl RunnerOptionsBuilder {
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
+            retry_max_interval: self.retry_max_interval,
+            retry_on_status: self.retry_on_status.clone(),
             skip: self.skip,
             ssl_no_revoke: self.ssl_no_revoke,
+            tags: self.tags.clone(),
             timeout: self.timeout,
             to_entry: self.to_entry,
             unix_socket: self.unix_socket.clone(),
             use_cookie_store: self.use_cookie_store,
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
+            warn_insecure: self.warn_insecure,
+            xpath_namespaces: self.xpath_namespaces.clone(),
         }
     }
 }
@@ -520,11 +608,15 @@ pub struct RunnerOptions {
     /// Specifies the AWS SigV4 option.
     pub(crate) aws_sigv4: Option<String>,
     /// Specifies the certificate file for peer verification.
+    pub(crate) cacert_append_file: Option<String>,
     pub(crate) cacert_file: Option<String>,
     /// Sets Client certificate file and password.
     pub(crate) client_cert_file: Option<String>,
     /// Sets private key file name.
     pub(crate) client_key_file: Option<String>,
+    /// Compresses the request body using one of the algorithms br, gzip, deflate before
+    /// sending it.
+    pub(crate) compress: Option<ContentEncoding>,
     /// Requests a compressed response using one of the algorithms br, gzip, deflate and
     /// automatically decompress the content.
     pub(crate) compressed: bool,
@@ -540,6 +632,7 @@ pub struct RunnerOptions {
     pub(crate) continue_on_error: bool,
     /// Reads cookies from this file (using the Netscape cookie file format).
     pub(crate) cookie_input_file: Option<String>,
+    pub(crate) csv_separator: Option<u8>,
     /// Enables HTTP Digest authentication.
     pub(crate) digest: bool,
     /// Sets follow redirect.
@@ -547,6 +640,9 @@ pub struct RunnerOptions {
     /// Sets follow redirect with trust.
     pub(crate) follow_location_trusted: bool,
     /// Executes Hurl file from from_entry (starting at 1), ignores the beginning of the file.
+    /// List of tags that skip an entry when present in its `[Tags]` section.
+    pub(crate) exclude_tags: Vec<String>,
+    /// Executes Hurl file from from_entry (starting at 1), ignores the beginning of the file.
     pub(crate) from_entry: Option<usize>,
     /// Sets additional headers (overrides if a header already exists).
     pub(crate) headers: Vec<String>,
@@ -593,10 +689,16 @@ pub struct RunnerOptions {
     pub(crate) retry: Option<Count>,
     /// Sets duration between each retry.
     pub(crate) retry_interval: Duration,
+    /// Sets maximum duration between each retry, when backing off exponentially.
+    pub(crate) retry_max_interval: Duration,
+    /// HTTP response status codes that trigger a retry, even when the entry has no error.
+    pub(crate) retry_on_status: Vec<u32>,
     /// Skip the run without executing any request.
     pub(crate) skip: bool,
     /// Disables certificate revocation checks for SSL backends where such behavior is present.
     pub(crate) ssl_no_revoke: bool,
+    /// List of tags an entry must bear in its `[Tags]` section to be executed.
+    pub(crate) tags: Vec<String>,
     /// Sets maximum time allowed for the transfer.
     pub(crate) timeout: Duration,
     /// Executes Hurl file to to_entry (starting at 1), ignores the remaining of the file.
@@ -609,6 +711,9 @@ pub struct RunnerOptions {
     pub(crate) user: Option<String>,
     /// Specifies the User-Agent string to send to the HTTP server.
     pub(crate) user_agent: Option<String>,
+    /// Warns when an entry enables insecure SSL connections through its `[Options]` section.
+    pub(crate) warn_insecure: bool,
+    pub(crate) xpath_namespaces: Vec<(String, String)>,
 }
 
 impl Default for RunnerOptions {
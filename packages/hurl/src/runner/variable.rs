@@ -15,8 +15,13 @@
  * limitations under the License.
  *
  */
-use std::collections::{HashMap, HashSet};
+use std::collections::{hash_map, HashMap, HashSet};
+use std::iter::Map;
 
+use hurl_core::ast::SourceInfo;
+use hurl_core::reader::Pos;
+
+use super::error::{RunnerError, RunnerErrorKind};
 use super::value::Value;
 
 /// Represents a variable named to hold `Value`.
@@ -30,6 +35,17 @@ pub struct Variable {
     visibility: Visibility,
 }
 
+/// Policy applied by [`VariableSet::merge`] when a variable is defined in both sets.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MergePolicy {
+    /// Variables from the merged set overwrite the existing ones.
+    Overwrite,
+    /// Existing variables are kept untouched.
+    KeepExisting,
+    /// The merge fails, reporting the conflicting variable names.
+    ErrorOnConflict,
+}
+
 /// Visibility of a variable value.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Visibility {
@@ -121,9 +137,48 @@ impl VariableSet {
         self.variables.get(name)
     }
 
-    /// Returns an iterator over all the variables values.
-    pub fn iter(&self) -> impl Iterator<Item = (&String, &Variable)> {
-        self.variables.iter()
+    /// Returns an iterator over all the variables names and values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.variables
+            .iter()
+            .map(|(name, variable)| (name.as_str(), variable.value()))
+    }
+
+    /// Returns an iterator over all the variables, with their visibility.
+    pub fn variables(&self) -> impl Iterator<Item = (&str, &Variable)> {
+        self.variables
+            .iter()
+            .map(|(name, variable)| (name.as_str(), variable))
+    }
+
+    /// Merges the variables from `other` into this set, following `policy` when a
+    /// variable is defined in both sets.
+    ///
+    /// Secrets from `other` are always merged: what's a secret remains a secret,
+    /// whatever the policy.
+    pub fn merge(&mut self, other: &VariableSet, policy: MergePolicy) -> Result<(), RunnerError> {
+        if policy == MergePolicy::ErrorOnConflict {
+            let mut conflicts = other
+                .variables
+                .keys()
+                .filter(|name| self.variables.contains_key(*name))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !conflicts.is_empty() {
+                conflicts.sort();
+                let kind = RunnerErrorKind::VariableConflict { names: conflicts };
+                let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+                return Err(RunnerError::new(source_info, kind, false));
+            }
+        }
+        for (name, variable) in &other.variables {
+            if policy == MergePolicy::KeepExisting && self.variables.contains_key(name) {
+                continue;
+            }
+            self.variables.insert(name.clone(), variable.clone());
+        }
+        self.secrets.extend(other.secrets.iter().cloned());
+        Ok(())
     }
 
     /// Returns the list of all secrets values.
@@ -132,6 +187,32 @@ impl VariableSet {
     }
 }
 
+impl<'a> IntoIterator for &'a VariableSet {
+    type Item = (&'a str, &'a Value);
+    type IntoIter = Map<
+        hash_map::Iter<'a, String, Variable>,
+        fn((&'a String, &'a Variable)) -> (&'a str, &'a Value),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.variables
+            .iter()
+            .map(|(name, variable)| (name.as_str(), variable.value()))
+    }
+}
+
+impl IntoIterator for VariableSet {
+    type Item = (String, Value);
+    type IntoIter =
+        Map<hash_map::IntoIter<String, Variable>, fn((String, Variable)) -> (String, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.variables
+            .into_iter()
+            .map(|(name, variable)| (name, variable.value))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::runner::Number::{Float, Integer};
@@ -211,10 +292,23 @@ mod test {
         });
 
         // Test iter()
-        for (name, variable) in variables.iter() {
+        for (name, value) in variables.iter() {
+            let expected = expected_value(name, &data);
+            assert_eq!(expected.unwrap(), value);
+        }
+
+        // Iterating by reference yields the same items as iter().
+        for (name, value) in &variables {
             let expected = expected_value(name, &data);
-            assert_eq!(expected.unwrap(), &variable.value);
+            assert_eq!(expected.unwrap(), value);
         }
+
+        // Iterating by value consumes the set.
+        let mut owned = variables.into_iter().collect::<Vec<_>>();
+        owned.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let mut expected = data.to_vec();
+        expected.sort_by(|(a, _), (b, _)| a.cmp(b));
+        assert_eq!(owned, expected);
     }
 
     #[test]
@@ -230,6 +324,48 @@ mod test {
         assert!(variables.secrets().contains(&"42".to_string()));
     }
 
+    #[test]
+    fn merge_variable_sets() {
+        use crate::runner::error::RunnerErrorKind;
+        use crate::runner::MergePolicy;
+
+        let mut a = VariableSet::new();
+        a.insert("foo".to_string(), Value::String("a".to_string()));
+        a.insert("bar".to_string(), Value::Bool(true));
+
+        let mut b = VariableSet::new();
+        b.insert("foo".to_string(), Value::String("b".to_string()));
+        b.insert_secret("baz".to_string(), "42".to_string());
+
+        // Conflicting names make the merge fail on `ErrorOnConflict`.
+        let error = a.clone().merge(&b, MergePolicy::ErrorOnConflict).err();
+        assert_eq!(
+            error.unwrap().kind,
+            RunnerErrorKind::VariableConflict {
+                names: vec!["foo".to_string()]
+            }
+        );
+
+        let mut keep = a.clone();
+        keep.merge(&b, MergePolicy::KeepExisting).unwrap();
+        assert_eq!(
+            keep.get("foo").unwrap().value,
+            Value::String("a".to_string())
+        );
+        assert!(keep.secrets().contains(&"42".to_string()));
+
+        let mut overwrite = a.clone();
+        overwrite.merge(&b, MergePolicy::Overwrite).unwrap();
+        assert_eq!(
+            overwrite.get("foo").unwrap().value,
+            Value::String("b".to_string())
+        );
+        assert_eq!(
+            overwrite.get("bar").unwrap().value,
+            Value::Bool(true)
+        );
+    }
+
     #[test]
     fn get_secrets() {
         let mut variables = VariableSet::new();
@@ -70,12 +70,15 @@ impl Document {
     }
 
     /// Evaluates a XPath 1.0 expression `expr` against a document.
-    pub fn eval_xpath(&self, expr: &str) -> Result<Value, XPathError> {
+    ///
+    /// `namespaces` is a list of user provided (prefix, URI) pairs, registered in addition to
+    /// the namespaces declared in the document.
+    pub fn eval_xpath(&self, expr: &str, namespaces: &[(String, String)]) -> Result<Value, XPathError> {
         let support_ns = match self.format {
             Format::Html => false,
             Format::Xml => true,
         };
-        libxml_eval_xpath(&self.inner, expr, support_ns)
+        libxml_eval_xpath(&self.inner, expr, support_ns, namespaces)
     }
 }
 
@@ -164,6 +167,7 @@ fn libxml_eval_xpath(
     doc: &libxml::tree::Document,
     expr: &str,
     support_ns: bool,
+    namespaces: &[(String, String)],
 ) -> Result<Value, XPathError> {
     let context = libxml::xpath::Context::new(doc).expect("error setting context in xpath module");
 
@@ -175,6 +179,11 @@ fn libxml_eval_xpath(
     if support_ns {
         register_namespaces(doc, &context);
     }
+    // User provided namespaces are registered last so they take precedence over the
+    // document's own declarations.
+    for (prefix, href) in namespaces {
+        let _ = context.register_namespace(prefix, href);
+    }
 
     let result = match context.evaluate(expr) {
         Ok(object) => object,
@@ -271,22 +280,22 @@ mod tests {
 
         let xpath = "count(//food/*)";
         assert_eq!(
-            doc.eval_xpath(xpath).unwrap(),
+            doc.eval_xpath(xpath, &[]).unwrap(),
             Value::Number(Number::from(3.0))
         );
 
         let xpath = "//food/*";
-        assert_eq!(doc.eval_xpath(xpath).unwrap(), Value::Nodeset(3));
+        assert_eq!(doc.eval_xpath(xpath, &[]).unwrap(), Value::Nodeset(3));
 
         let xpath = "count(//*[@type='fruit'])";
         assert_eq!(
-            doc.eval_xpath(xpath).unwrap(),
+            doc.eval_xpath(xpath, &[]).unwrap(),
             Value::Number(Number::from(2.0))
         );
 
         let xpath = "number(//food/banana/@price)";
         assert_eq!(
-            doc.eval_xpath(xpath).unwrap(),
+            doc.eval_xpath(xpath, &[]).unwrap(),
             Value::Number(Number::from(1.1))
         );
     }
@@ -296,8 +305,8 @@ mod tests {
         let xml = "<a/>";
         let doc = Document::parse(xml, Format::Xml).unwrap();
 
-        assert_eq!(doc.eval_xpath("^^^").unwrap_err(), XPathError::Eval);
-        assert_eq!(doc.eval_xpath("//").unwrap_err(), XPathError::Eval);
+        assert_eq!(doc.eval_xpath("^^^", &[]).unwrap_err(), XPathError::Eval);
+        assert_eq!(doc.eval_xpath("//", &[]).unwrap_err(), XPathError::Eval);
         // assert_eq!(1,2);
     }
 
@@ -316,7 +325,7 @@ mod tests {
         let doc = Document::parse(xml, Format::Xml).unwrap();
 
         assert_eq!(
-            doc.eval_xpath("normalize-space(//data)").unwrap(),
+            doc.eval_xpath("normalize-space(//data)", &[]).unwrap(),
             Value::String(String::from("café"))
         );
     }
@@ -327,7 +336,7 @@ mod tests {
         let doc = Document::parse(html, Format::Html).unwrap();
 
         assert_eq!(
-            doc.eval_xpath("normalize-space(//data)").unwrap(),
+            doc.eval_xpath("normalize-space(//data)", &[]).unwrap(),
             Value::String(String::from("café"))
         );
     }
@@ -345,7 +354,7 @@ mod tests {
         let doc = Document::parse(html, Format::Html).unwrap();
         let xpath = "normalize-space(/html/head/meta/@charset)";
         assert_eq!(
-            doc.eval_xpath(xpath).unwrap(),
+            doc.eval_xpath(xpath, &[]).unwrap(),
             Value::String(String::from("UTF-8"))
         );
     }
@@ -355,7 +364,7 @@ mod tests {
         let html = r#"<html></html>"#;
         let doc = Document::parse(html, Format::Html).unwrap();
         let xpath = "boolean(count(//a[contains(@href,'xxx')]))";
-        assert_eq!(doc.eval_xpath(xpath).unwrap(), Value::Bool(false));
+        assert_eq!(doc.eval_xpath(xpath, &[]).unwrap(), Value::Bool(false));
     }
 
     #[test]
@@ -363,7 +372,7 @@ mod tests {
         let html = r#"<html></html>"#;
         let doc = Document::parse(html, Format::Html).unwrap();
         let xpath = "strong(//head/title)";
-        assert_eq!(doc.eval_xpath(xpath).unwrap_err(), XPathError::Eval);
+        assert_eq!(doc.eval_xpath(xpath, &[]).unwrap_err(), XPathError::Eval);
     }
 
     #[test]
@@ -380,26 +389,26 @@ mod tests {
 
         let expr = "string(//a:books/b:book/b:title)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("Dune".to_string())
         );
 
         let expr = "string(//a:books/b:book/c:author)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("Franck Herbert".to_string())
         );
 
         let expr = "string(//*[name()='a:books']/*[name()='b:book']/*[name()='c:author'])";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("Franck Herbert".to_string())
         );
 
         let expr =
             "string(//*[local-name()='books']/*[local-name()='book']/*[local-name()='author'])";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("Franck Herbert".to_string())
         );
     }
@@ -415,19 +424,19 @@ mod tests {
 
         let expr = "string(//_:svg/_:text)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("SVG".to_string())
         );
 
         let expr = "string(//*[name()='svg']/*[name()='text'])";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("SVG".to_string())
         );
 
         let expr = "string(//*[local-name()='svg']/*[local-name()='text'])";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("SVG".to_string())
         );
     }
@@ -453,19 +462,19 @@ mod tests {
 
         let expr = "string(//soap:Envelope/soap:Body/ns1:OTA_AirAvailRS/@TransactionIdentifier)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("TID$16459590516432752971.demo2144".to_string())
         );
 
         let expr = "string(//*[name()='soap:Envelope']/*[name()='soap:Body']/*[name()='ns1:OTA_AirAvailRS']/@TransactionIdentifier)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("TID$16459590516432752971.demo2144".to_string())
         );
 
         let expr = "string(//*[local-name()='Envelope']/*[local-name()='Body']/*[local-name()='OTA_AirAvailRS']/@TransactionIdentifier)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("TID$16459590516432752971.demo2144".to_string())
         );
     }
@@ -491,20 +500,20 @@ mod tests {
 
         let expr = "string(//_:book/_:title)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("Cheaper by the Dozen".to_string())
         );
 
         let expr = "string(//_:book/isbn:number)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, &[]).unwrap(),
             Value::String("1568491379".to_string())
         );
 
         let expr = "//*[name()='book']/*[name()='notes']";
-        assert_eq!(doc.eval_xpath(expr).unwrap(), Value::Nodeset(1));
+        assert_eq!(doc.eval_xpath(expr, &[]).unwrap(), Value::Nodeset(1));
 
         let expr = "//_:book/_:notes/*[local-name()='p']";
-        assert_eq!(doc.eval_xpath(expr).unwrap(), Value::Nodeset(1));
+        assert_eq!(doc.eval_xpath(expr, &[]).unwrap(), Value::Nodeset(1));
     }
 }
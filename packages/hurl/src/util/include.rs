@@ -0,0 +1,208 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Expansion of `include` directives.
+//!
+//! A Hurl file can reuse the entries of another Hurl file with an `include "other.hurl"`
+//! directive:
+//!
+//! ```hurl
+//! include "auth.hurl"
+//!
+//! GET https://example.org/protected
+//! HTTP 200
+//! ```
+//!
+//! Directives are only recognised at the top of a file, before the first entry, and are expanded
+//! textually before the file is parsed. Included files can include other files; paths are resolved
+//! relative to the including file and circular includes are errors.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use hurl_core::input::{Input, InputKind};
+
+/// Replaces the `include` directives of `content` by the content of the included files.
+///
+/// `filename` is the input `content` has been read from, used to resolve relative paths (for the
+/// standard input, paths are resolved against `current_dir`). Returns an error message if an
+/// included file can't be read or if includes are circular.
+pub fn expand_includes(
+    content: &str,
+    filename: &Input,
+    current_dir: &Path,
+) -> Result<String, String> {
+    let mut visited = vec![];
+    let dir = match filename.kind() {
+        InputKind::File(path) => {
+            if let Ok(path) = fs::canonicalize(path) {
+                visited.push(path);
+            }
+            path.parent().unwrap().to_path_buf()
+        }
+        InputKind::Stdin(_) => current_dir.to_path_buf(),
+    };
+    expand(content, &dir, &mut visited)
+}
+
+fn expand(content: &str, dir: &Path, visited: &mut Vec<PathBuf>) -> Result<String, String> {
+    let mut expanded = String::new();
+    let mut rest = content;
+    while !rest.is_empty() {
+        let (line, remainder) = match rest.find('\n') {
+            Some(index) => (&rest[..index], &rest[index + 1..]),
+            None => (rest, ""),
+        };
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            expanded.push_str(line);
+            expanded.push('\n');
+        } else if trimmed.starts_with("include ") || trimmed.starts_with("include\t") {
+            let Some(path) = include_path(trimmed) else {
+                return Err(format!(
+                    "invalid include directive <{trimmed}>, expecting include \"file\""
+                ));
+            };
+            let path = dir.join(path);
+            let path = fs::canonicalize(&path)
+                .map_err(|error| format!("Issue reading from {}: {error}", path.display()))?;
+            if visited.contains(&path) {
+                return Err(format!("circular include of file {}", path.display()));
+            }
+            let included = fs::read_to_string(&path)
+                .map_err(|error| format!("Issue reading from {}: {error}", path.display()))?;
+            let parent = path.parent().unwrap().to_path_buf();
+            visited.push(path);
+            let included = expand(&included, &parent, visited)?;
+            visited.pop();
+            expanded.push_str(&included);
+            if !included.ends_with('\n') {
+                expanded.push('\n');
+            }
+        } else {
+            // The first entry is reached, the remaining of the file is kept as is.
+            expanded.push_str(rest);
+            break;
+        }
+        rest = remainder;
+    }
+    Ok(expanded)
+}
+
+/// Returns the files included by `content`, recursively.
+///
+/// `filename` and `current_dir` are used to resolve relative paths, like in
+/// [`expand_includes`]. Unreadable or circular includes are silently skipped: this function
+/// builds the watch list of `--watch` mode, it doesn't validate the file.
+pub fn included_files(content: &str, filename: &Input, current_dir: &Path) -> Vec<PathBuf> {
+    let dir = match filename.kind() {
+        InputKind::File(path) => match path.parent() {
+            Some(parent) => parent.to_path_buf(),
+            None => current_dir.to_path_buf(),
+        },
+        InputKind::Stdin(_) => current_dir.to_path_buf(),
+    };
+    let mut files = vec![];
+    collect_included_files(content, &dir, &mut files);
+    files
+}
+
+fn collect_included_files(content: &str, dir: &Path, files: &mut Vec<PathBuf>) {
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !(trimmed.starts_with("include ") || trimmed.starts_with("include\t")) {
+            // The first entry is reached, directives are not recognised anymore.
+            break;
+        }
+        let Some(path) = include_path(trimmed) else {
+            continue;
+        };
+        let path = dir.join(path);
+        let Ok(path) = fs::canonicalize(&path) else {
+            continue;
+        };
+        if files.contains(&path) {
+            continue;
+        }
+        let Ok(included) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let parent = path.parent().unwrap().to_path_buf();
+        files.push(path);
+        collect_included_files(&included, &parent, files);
+    }
+}
+
+/// Parses an `include "file"` directive, returning the included filename.
+fn include_path(line: &str) -> Option<&str> {
+    let value = line.strip_prefix("include")?;
+    let value = value.trim_start();
+    let value = value.strip_prefix('"')?;
+    let (path, rest) = value.split_once('"')?;
+    let rest = rest.trim();
+    if rest.is_empty() || rest.starts_with('#') {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_include_path() {
+        assert_eq!(include_path("include \"auth.hurl\""), Some("auth.hurl"));
+        assert_eq!(include_path("include\t\"a/b.hurl\""), Some("a/b.hurl"));
+        assert_eq!(
+            include_path("include \"auth.hurl\" # comment"),
+            Some("auth.hurl")
+        );
+
+        assert_eq!(include_path("include auth.hurl"), None);
+        assert_eq!(include_path("include \"auth.hurl\" trailing"), None);
+        assert_eq!(include_path("include \"auth.hurl"), None);
+    }
+
+    #[test]
+    fn expand_simple_include() {
+        let dir = std::env::temp_dir().join("hurl_include_test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("auth.hurl"), "POST http://example.org/login\nHTTP 200\n").unwrap();
+
+        let content = "# Setup\ninclude \"auth.hurl\"\n\nGET http://example.org/\nHTTP 200\n";
+        let expanded = expand(content, &dir, &mut vec![]).unwrap();
+        assert_eq!(
+            expanded,
+            "# Setup\nPOST http://example.org/login\nHTTP 200\n\nGET http://example.org/\nHTTP 200\n"
+        );
+    }
+
+    #[test]
+    fn expand_circular_include() {
+        let dir = std::env::temp_dir().join("hurl_include_cycle_test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.hurl"), "include \"b.hurl\"\n").unwrap();
+        fs::write(dir.join("b.hurl"), "include \"a.hurl\"\n").unwrap();
+
+        let error = expand("include \"a.hurl\"\n", &dir, &mut vec![]).unwrap_err();
+        assert!(error.starts_with("circular include of file"));
+    }
+}
@@ -29,6 +29,7 @@ use crate::util::term::{Stderr, WriteMode};
 pub enum ErrorFormat {
     Short,
     Long,
+    Json,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -41,6 +42,7 @@ pub enum Verbosity {
 /// A dedicated logger for an Hurl file. This logger can display rich parsing and runtime errors.
 #[derive(Clone)]
 pub struct Logger {
+    pub(crate) assert_fail_context: usize,
     pub(crate) color: bool,
     pub(crate) error_format: ErrorFormat,
     pub(crate) verbosity: Option<Verbosity>,
@@ -50,12 +52,14 @@ pub struct Logger {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LoggerOptions {
+    assert_fail_context: usize,
     color: bool,
     error_format: ErrorFormat,
     verbosity: Option<Verbosity>,
 }
 
 pub struct LoggerOptionsBuilder {
+    assert_fail_context: usize,
     color: bool,
     error_format: ErrorFormat,
     verbosity: Option<Verbosity>,
@@ -67,6 +71,12 @@ impl LoggerOptionsBuilder {
         LoggerOptionsBuilder::default()
     }
 
+    /// Sets the number of response body lines shown around assert failures (0 to disable).
+    pub fn assert_fail_context(&mut self, lines: usize) -> &mut Self {
+        self.assert_fail_context = lines;
+        self
+    }
+
     /// Sets color usage.
     pub fn color(&mut self, color: bool) -> &mut Self {
         self.color = color;
@@ -75,7 +85,8 @@ impl LoggerOptionsBuilder {
 
     /// Control the format of error messages.
     /// If `error_format` is [`ErrorFormat::Long`], the HTTP request and response that has
-    /// errors is displayed (headers, body, etc..)
+    /// errors is displayed (headers, body, etc..). If it is [`ErrorFormat::Json`], each error
+    /// is emitted as a one-line JSON object with its stable error code.
     pub fn error_format(&mut self, error_format: ErrorFormat) -> &mut Self {
         self.error_format = error_format;
         self
@@ -90,6 +101,7 @@ impl LoggerOptionsBuilder {
     /// Creates a new logger.
     pub fn build(&self) -> LoggerOptions {
         LoggerOptions {
+            assert_fail_context: self.assert_fail_context,
             color: self.color,
             error_format: self.error_format,
             verbosity: self.verbosity,
@@ -100,6 +112,7 @@ impl LoggerOptionsBuilder {
 impl Default for LoggerOptionsBuilder {
     fn default() -> Self {
         LoggerOptionsBuilder {
+            assert_fail_context: 0,
             color: false,
             error_format: ErrorFormat::Short,
             verbosity: None,
@@ -111,6 +124,7 @@ impl Logger {
     /// Creates a new instance.
     pub fn new(options: &LoggerOptions, term: Stderr, secrets: &[String]) -> Self {
         Logger {
+            assert_fail_context: options.assert_fail_context,
             color: options.color,
             error_format: options.error_format,
             verbosity: options.verbosity,
@@ -282,6 +296,17 @@ impl Logger {
         self.eprintln(&s.to_string(fmt));
     }
 
+    /// Prints a rich warning message to this logger [`Stderr`] instance, no matter what is the verbosity.
+    pub fn warning_rich(&mut self, message: &str) {
+        let fmt = self.format();
+        let mut s = StyledString::new();
+        s.push_with("warning", Style::new().yellow().bold());
+        s.push(": ");
+        s.push(message);
+        s.push("\n");
+        self.eprintln(&s.to_string(fmt));
+    }
+
     /// Prints an error message to this logger [`Stderr`] instance, no matter what is the verbosity.
     pub fn error_rich(&mut self, message: &str) {
         let fmt = self.format();
@@ -29,6 +29,8 @@ pub struct ContextDir {
     /// As a consequence, it is always defined (and can't be replaced by a `Option<PathBuf>`).
     /// It can be relative (to the current directory) or absolute.
     file_root: PathBuf,
+    /// Allows access to files outside of the file root (see `--allow-path-escape` option).
+    allow_path_escape: bool,
 }
 
 impl Default for ContextDir {
@@ -36,6 +38,7 @@ impl Default for ContextDir {
         ContextDir {
             current_dir: PathBuf::new(),
             file_root: PathBuf::new(),
+            allow_path_escape: false,
         }
     }
 }
@@ -46,9 +49,15 @@ impl ContextDir {
         ContextDir {
             current_dir: PathBuf::from(current_dir),
             file_root: PathBuf::from(file_root),
+            allow_path_escape: false,
         }
     }
 
+    /// Allows or denies access to files outside of the file root.
+    pub fn allow_path_escape(&mut self, allowed: bool) {
+        self.allow_path_escape = allowed;
+    }
+
     /// Returns a path (absolute or relative), given a filename.
     pub fn resolved_path(&self, filename: &Path) -> PathBuf {
         self.file_root.join(filename)
@@ -58,10 +67,20 @@ impl ContextDir {
     /// This method is used to check if a local file can be included in POST request or if a
     /// response can be outputted to a given file when using `output` option in \[Options\] sections.
     pub fn is_access_allowed(&self, filename: &Path) -> bool {
+        if self.allow_path_escape {
+            return true;
+        }
         let file = self.resolved_path(filename);
         let absolute_file = self.current_dir.join(file);
         let absolute_file_root = self.current_dir.join(&self.file_root);
-        is_descendant(absolute_file.as_path(), absolute_file_root.as_path())
+        if !is_descendant(absolute_file.as_path(), absolute_file_root.as_path()) {
+            return false;
+        }
+        // The lexical check above can be bypassed by a symlink inside the file root pointing
+        // outside of it: compare the symlink-resolved paths too.
+        let canonical_file = canonicalize_existing_prefix(absolute_file.as_path());
+        let canonical_file_root = canonicalize_existing_prefix(absolute_file_root.as_path());
+        is_descendant(canonical_file.as_path(), canonical_file_root.as_path())
     }
 }
 
@@ -109,6 +128,29 @@ fn normalize_path(path: &Path) -> PathBuf {
     ret
 }
 
+/// Returns `path` with symlinks resolved, without requiring the final path to exist.
+///
+/// [`std::fs::canonicalize`] fails on a path that doesn't exist yet (e.g. an output file to be
+/// created): in that case, the deepest existing ancestor is canonicalised and the remaining
+/// components are appended back.
+fn canonicalize_existing_prefix(path: &Path) -> PathBuf {
+    let path = normalize_path(path);
+    for ancestor in path.ancestors() {
+        // The empty last ancestor of a relative path is the current directory: resolving it
+        // makes a relative file and an empty file root compare on the same absolute form.
+        let existing = if ancestor.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            ancestor
+        };
+        if let Ok(resolved) = std::fs::canonicalize(existing) {
+            let rest = path.strip_prefix(ancestor).unwrap();
+            return resolved.join(rest);
+        }
+    }
+    path
+}
+
 // Create parent directories, if missing, given a filepath ending with a file name
 pub fn create_dir_all(filename: &Path) -> Result<(), std::io::Error> {
     if let Some(parent) = filename.parent() {
@@ -220,6 +262,22 @@ mod tests {
         assert!(!ctx.is_access_allowed(Path::new("../../file/foo.bin")));
     }
 
+    #[test]
+    fn check_filename_allowed_access_with_path_escape_allowed() {
+        // ```
+        // $ cd /tmp
+        // $ hurl --allow-path-escape test.hurl
+        // ```
+        let current_dir = Path::new("/tmp");
+        let file_root = Path::new("");
+        let mut ctx = ContextDir::new(current_dir, file_root);
+        ctx.allow_path_escape(true);
+        assert!(ctx.is_access_allowed(Path::new("foo.bin")));
+        assert!(ctx.is_access_allowed(Path::new("/file/foo.bin")));
+        assert!(ctx.is_access_allowed(Path::new("../foo.bin")));
+        assert!(ctx.is_access_allowed(Path::new("../../file/foo.bin")));
+    }
+
     #[test]
     fn is_descendant_true() {
         let child = Path::new("/tmp/foo/bar.txt");
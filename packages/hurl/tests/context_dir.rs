@@ -0,0 +1,54 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2026 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fs;
+use std::path::Path;
+
+use hurl::util::path::ContextDir;
+
+/// A file accessed through a symlink that points outside of the file root must be rejected,
+/// even though the lexical path stays inside the file root.
+#[test]
+#[cfg(unix)]
+fn symlink_escape_is_rejected() {
+    let tmp = std::env::temp_dir().join("hurl_context_dir_symlink_escape");
+    let _ = fs::remove_dir_all(&tmp);
+    let file_root = tmp.join("root");
+    let secret_dir = tmp.join("secret");
+    fs::create_dir_all(&file_root).unwrap();
+    fs::create_dir_all(&secret_dir).unwrap();
+    fs::write(secret_dir.join("password.txt"), "sesame").unwrap();
+    std::os::unix::fs::symlink(&secret_dir, file_root.join("link")).unwrap();
+
+    let ctx = ContextDir::new(Path::new("/"), &file_root);
+
+    // A lexical escape is rejected as before.
+    assert!(!ctx.is_access_allowed(Path::new("../secret/password.txt")));
+    // A symlink escape is rejected too, whether the target exists or not.
+    assert!(!ctx.is_access_allowed(Path::new("link/password.txt")));
+    assert!(!ctx.is_access_allowed(Path::new("link/not_yet_created.txt")));
+    // Regular files inside the file root are still allowed, even not yet created ones.
+    assert!(ctx.is_access_allowed(Path::new("data.txt")));
+    assert!(ctx.is_access_allowed(Path::new("out/report.json")));
+
+    // With `--allow-path-escape`, the symlink is allowed.
+    let mut ctx = ctx;
+    ctx.allow_path_escape(true);
+    assert!(ctx.is_access_allowed(Path::new("link/password.txt")));
+
+    let _ = fs::remove_dir_all(&tmp);
+}
@@ -20,7 +20,7 @@ use std::time::Duration;
 
 use hurl::http::{Call, HttpVersion, Request, Response, Url};
 use hurl::runner;
-use hurl::runner::{EntryResult, HurlResult, RunnerOptionsBuilder, VariableSet};
+use hurl::runner::{EntryResult, HurlResult, RunnerOptionsBuilder, Value, VariableSet};
 use hurl::util::logger::LoggerOptionsBuilder;
 use hurl::util::path::ContextDir;
 use hurl_core::input::Input;
@@ -154,4 +154,27 @@ fn simple_sample() {
 
     let response = &call.response;
     check_response(response);
+
+    // Captured variables can be enumerated after the run.
+    let captured = result
+        .variables
+        .iter()
+        .find(|(name, _)| *name == "data")
+        .map(|(_, value)| value.clone());
+    assert_eq!(captured, Some(Value::String("Hello World!".to_string())));
+
+    let names = (&result.variables)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["data"]);
+
+    let owned = result.variables.into_iter().collect::<Vec<_>>();
+    assert_eq!(
+        owned,
+        vec![(
+            "data".to_string(),
+            Value::String("Hello World!".to_string())
+        )]
+    );
 }
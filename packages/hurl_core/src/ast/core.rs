@@ -23,7 +23,9 @@ use super::option::EntryOption;
 use super::primitive::{
     Bytes, KeyValue, LineTerminator, Placeholder, SourceInfo, Template, Whitespace, I64,
 };
-use super::section::{Assert, Capture, Cookie, MultipartParam, RegexValue, Section, SectionValue};
+use super::section::{
+    Assert, Capture, Cookie, GraphQlSection, MultipartParam, RegexValue, Section, SectionValue, Tag,
+};
 
 /// Represents Hurl AST root node.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -132,6 +134,26 @@ impl Request {
         }
         &[]
     }
+
+    /// Returns the tags of this request.
+    pub fn tags(&self) -> &[Tag] {
+        for section in &self.sections {
+            if let SectionValue::Tags(tags) = &section.value {
+                return tags;
+            }
+        }
+        &[]
+    }
+
+    /// Returns the GraphQL query of this request, if any.
+    pub fn graphql(&self) -> Option<&GraphQlSection> {
+        for section in &self.sections {
+            if let SectionValue::GraphQl(graphql) = &section.value {
+                return Some(graphql);
+            }
+        }
+        None
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -258,6 +280,8 @@ pub struct Body {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
     pub value: Bytes,
+    /// `true` when the body was written with the `<<<DELIM` heredoc syntax.
+    pub is_heredoc: bool,
     pub line_terminator0: LineTerminator,
 }
 
@@ -305,6 +329,10 @@ pub enum FilterValue {
         space0: Whitespace,
         expr: Template,
     },
+    JwtClaim {
+        space0: Whitespace,
+        name: Template,
+    },
     Last,
     Location,
     Nth {
@@ -372,6 +400,7 @@ impl FilterValue {
             FilterValue::HtmlEscape => "htmlEscape",
             FilterValue::HtmlUnescape => "htmlUnescape",
             FilterValue::JsonPath { .. } => "jsonpath",
+            FilterValue::JwtClaim { .. } => "jwt-claim",
             FilterValue::Last => "last",
             FilterValue::Location => "location",
             FilterValue::Nth { .. } => "nth",
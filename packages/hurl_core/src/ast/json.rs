@@ -48,6 +48,9 @@ pub enum JsonValue {
         elements: Vec<JsonObjectElement>,
     },
     Null,
+    /// An `exact(...)` wrapper, only meaningful in a `matches_json` predicate: the wrapped value
+    /// must match exactly instead of partially.
+    Exact(Box<JsonValue>),
 }
 
 impl ToSource for JsonValue {
@@ -78,6 +81,7 @@ impl ToSource for JsonValue {
                 format!("{{{}{}}}", space0, elements.join(",")).to_source()
             }
             JsonValue::Null => "null".to_source(),
+            JsonValue::Exact(value) => format!("exact({})", value.to_source()).to_source(),
         }
     }
 }
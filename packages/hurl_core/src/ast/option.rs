@@ -37,11 +37,14 @@ pub struct EntryOption {
 pub enum OptionKind {
     AwsSigV4(Template),
     CaCertificate(Template),
+    CaCertificateAppend(Template),
     ClientCert(Template),
     ClientKey(Template),
+    Compress(CompressOption),
     Compressed(BooleanOption),
     ConnectTo(Template),
     ConnectTimeout(DurationOption),
+    CsvSeparator(Template),
     Delay(DurationOption),
     Digest(BooleanOption),
     Header(Template),
@@ -52,9 +55,11 @@ pub enum OptionKind {
     Insecure(BooleanOption),
     IpV4(BooleanOption),
     IpV6(BooleanOption),
+    IpVersion(IpVersionOption),
     FollowLocation(BooleanOption),
     FollowLocationTrusted(BooleanOption),
     LimitRate(NaturalOption),
+    MaxFilesize(NaturalOption),
     MaxRedirect(CountOption),
     MaxTime(DurationOption),
     Negotiate(BooleanOption),
@@ -70,6 +75,8 @@ pub enum OptionKind {
     Resolve(Template),
     Retry(CountOption),
     RetryInterval(DurationOption),
+    RetryMaxInterval(DurationOption),
+    RetryOnStatus(Vec<U64>),
     Skip(BooleanOption),
     UnixSocket(Template),
     User(Template),
@@ -77,6 +84,7 @@ pub enum OptionKind {
     Verbose(BooleanOption),
     Verbosity(VerbosityOption),
     VeryVerbose(BooleanOption),
+    XpathNamespace(Template),
 }
 
 impl OptionKind {
@@ -85,11 +93,14 @@ impl OptionKind {
         match self {
             OptionKind::AwsSigV4(_) => "aws-sigv4",
             OptionKind::CaCertificate(_) => "cacert",
+            OptionKind::CaCertificateAppend(_) => "cacert-append",
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
+            OptionKind::Compress(_) => "compress",
             OptionKind::Compressed(_) => "compressed",
             OptionKind::ConnectTo(_) => "connect-to",
             OptionKind::ConnectTimeout(_) => "connect-timeout",
+            OptionKind::CsvSeparator(_) => "csv-separator",
             OptionKind::Delay(_) => "delay",
             OptionKind::Digest(_) => "digest",
             OptionKind::FollowLocation(_) => "location",
@@ -102,7 +113,9 @@ impl OptionKind {
             OptionKind::Insecure(_) => "insecure",
             OptionKind::IpV4(_) => "ipv4",
             OptionKind::IpV6(_) => "ipv6",
+            OptionKind::IpVersion(_) => "ip-version",
             OptionKind::LimitRate(_) => "limit-rate",
+            OptionKind::MaxFilesize(_) => "max-filesize",
             OptionKind::MaxRedirect(_) => "max-redirs",
             OptionKind::MaxTime(_) => "max-time",
             OptionKind::Negotiate(_) => "negotiate",
@@ -118,6 +131,8 @@ impl OptionKind {
             OptionKind::Resolve(_) => "resolve",
             OptionKind::Retry(_) => "retry",
             OptionKind::RetryInterval(_) => "retry-interval",
+            OptionKind::RetryMaxInterval(_) => "retry-max-interval",
+            OptionKind::RetryOnStatus(_) => "retry-on-status",
             OptionKind::Skip(_) => "skip",
             OptionKind::UnixSocket(_) => "unix-socket",
             OptionKind::User(_) => "user",
@@ -125,6 +140,7 @@ impl OptionKind {
             OptionKind::Verbose(_) => "verbose",
             OptionKind::Verbosity(_) => "verbosity",
             OptionKind::VeryVerbose(_) => "very-verbose",
+            OptionKind::XpathNamespace(_) => "xpath-namespace",
         }
     }
 }
@@ -134,11 +150,14 @@ impl fmt::Display for OptionKind {
         let value = match self {
             OptionKind::AwsSigV4(value) => value.to_string(),
             OptionKind::CaCertificate(filename) => filename.to_string(),
+            OptionKind::CaCertificateAppend(filename) => filename.to_string(),
             OptionKind::ClientCert(filename) => filename.to_string(),
             OptionKind::ClientKey(filename) => filename.to_string(),
+            OptionKind::Compress(value) => value.to_string(),
             OptionKind::Compressed(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.to_string(),
             OptionKind::ConnectTimeout(value) => value.to_string(),
+            OptionKind::CsvSeparator(value) => value.to_string(),
             OptionKind::Delay(value) => value.to_string(),
             OptionKind::Digest(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
@@ -151,7 +170,9 @@ impl fmt::Display for OptionKind {
             OptionKind::Insecure(value) => value.to_string(),
             OptionKind::IpV4(value) => value.to_string(),
             OptionKind::IpV6(value) => value.to_string(),
+            OptionKind::IpVersion(value) => value.to_string(),
             OptionKind::LimitRate(value) => value.to_string(),
+            OptionKind::MaxFilesize(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
             OptionKind::MaxTime(value) => value.to_string(),
             OptionKind::Negotiate(value) => value.to_string(),
@@ -167,6 +188,12 @@ impl fmt::Display for OptionKind {
             OptionKind::Resolve(value) => value.to_string(),
             OptionKind::Retry(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
+            OptionKind::RetryMaxInterval(value) => value.to_string(),
+            OptionKind::RetryOnStatus(value) => value
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
             OptionKind::Skip(value) => value.to_string(),
             OptionKind::UnixSocket(value) => value.to_string(),
             OptionKind::User(value) => value.to_string(),
@@ -174,6 +201,7 @@ impl fmt::Display for OptionKind {
             OptionKind::Verbose(value) => value.to_string(),
             OptionKind::Verbosity(value) => value.to_string(),
             OptionKind::VeryVerbose(value) => value.to_string(),
+            OptionKind::XpathNamespace(value) => value.to_string(),
         };
         write!(f, "{}: {}", self.identifier(), value)
     }
@@ -285,6 +313,51 @@ impl ToSource for VariableValue {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CompressOption {
+    Brotli,
+    Deflate,
+    Gzip,
+}
+
+impl fmt::Display for CompressOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier())
+    }
+}
+
+impl CompressOption {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            CompressOption::Brotli => "br",
+            CompressOption::Deflate => "deflate",
+            CompressOption::Gzip => "gzip",
+        }
+    }
+}
+
+/// Value of an `ip-version` option: the IP version used to resolve hostnames.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IpVersionOption {
+    V4,
+    V6,
+}
+
+impl fmt::Display for IpVersionOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier())
+    }
+}
+
+impl IpVersionOption {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            IpVersionOption::V4 => "4",
+            IpVersionOption::V6 => "6",
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum VerbosityOption {
     Brief,
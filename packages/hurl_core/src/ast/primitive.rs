@@ -546,6 +546,48 @@ impl fmt::Display for Variable {
 pub enum Function {
     NewDate,
     NewUuid,
+    Base64Encode(Box<Expr>),
+    Base64Decode(Box<Expr>),
+    Now {
+        format: String,
+        offset: Option<TimeOffset>,
+    },
+    HmacSha256 {
+        key: Box<Expr>,
+        message: Box<Expr>,
+    },
+    HmacSha256Base64 {
+        key: Box<Expr>,
+        message: Box<Expr>,
+    },
+    UrlEncode {
+        arg: Box<Expr>,
+        /// With `form=true`, spaces are encoded as `+` (HTML form encoding) instead of `%20`.
+        form: bool,
+    },
+    UrlDecode(Box<Expr>),
+    Jsonpath {
+        arg: Box<Expr>,
+        expr: String,
+    },
+    Xpath {
+        arg: Box<Expr>,
+        expr: String,
+    },
+    // Bounds are boxed to keep the size of [`Function`] (and so of any expression) small.
+    RandomInt {
+        min: Box<I64>,
+        max: Box<I64>,
+    },
+    RandomFloat {
+        min: Box<Number>,
+        max: Box<Number>,
+    },
+    Counter {
+        name: String,
+        start: Option<Box<I64>>,
+        step: Option<Box<I64>>,
+    },
 }
 
 impl fmt::Display for Function {
@@ -553,6 +595,73 @@ impl fmt::Display for Function {
         match self {
             Function::NewDate => write!(f, "newDate"),
             Function::NewUuid => write!(f, "newUuid"),
+            Function::Base64Encode(arg) => write!(f, "base64Encode({arg})"),
+            Function::Base64Decode(arg) => write!(f, "base64Decode({arg})"),
+            Function::Now { format, offset } => match offset {
+                Some(offset) => write!(f, "now(\"{format}\", {offset})"),
+                None => write!(f, "now(\"{format}\")"),
+            },
+            Function::HmacSha256 { key, message } => {
+                write!(f, "hmac_sha256({key}, {message})")
+            }
+            Function::HmacSha256Base64 { key, message } => {
+                write!(f, "hmac_sha256_base64({key}, {message})")
+            }
+            Function::UrlEncode { arg, form } => {
+                if *form {
+                    write!(f, "url_encode({arg}, form=true)")
+                } else {
+                    write!(f, "url_encode({arg})")
+                }
+            }
+            Function::UrlDecode(arg) => write!(f, "url_decode({arg})"),
+            Function::Jsonpath { arg, expr } => write!(f, "jsonpath({arg}, \"{expr}\")"),
+            Function::Xpath { arg, expr } => write!(f, "xpath({arg}, \"{expr}\")"),
+            Function::RandomInt { min, max } => write!(f, "random_int({min}, {max})"),
+            Function::RandomFloat { min, max } => write!(f, "random_float({min}, {max})"),
+            Function::Counter { name, start, step } => {
+                write!(f, "counter(\"{name}\"")?;
+                if let Some(start) = start {
+                    write!(f, ", start={start}")?;
+                }
+                if let Some(step) = step {
+                    write!(f, ", step={step}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// A signed time offset like `+1d`, `-2h` or `+30m`, used by the `now` function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeOffset {
+    pub value: i64,
+    pub unit: TimeOffsetUnit,
+}
+
+impl fmt::Display for TimeOffset {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let sign = if self.value < 0 { "-" } else { "+" };
+        write!(f, "{sign}{}{}", self.value.abs(), self.unit)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeOffsetUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+}
+
+impl fmt::Display for TimeOffsetUnit {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TimeOffsetUnit::Second => write!(f, "s"),
+            TimeOffsetUnit::Minute => write!(f, "m"),
+            TimeOffsetUnit::Hour => write!(f, "h"),
+            TimeOffsetUnit::Day => write!(f, "d"),
         }
     }
 }
@@ -20,10 +20,11 @@ use std::fmt;
 use crate::types::{SourceString, ToSource};
 
 use super::core::Filter;
+use super::json::JsonValue;
 use super::option::EntryOption;
 use super::primitive::{
-    Base64, File, Hex, KeyValue, LineTerminator, MultilineString, Number, Placeholder, Regex,
-    SourceInfo, Template, Whitespace,
+    Base64, File, GraphQlVariables, Hex, KeyValue, LineTerminator, MultilineString, Number,
+    Placeholder, Regex, SourceInfo, Template, U64, Whitespace,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -51,6 +52,8 @@ impl Section {
             SectionValue::MultipartFormData(_, false) => "MultipartFormData",
             SectionValue::Options(_) => "Options",
             SectionValue::Bindings(_) => "Bindings",
+            SectionValue::Tags(_) => "Tags",
+            SectionValue::GraphQl(_) => "GraphQL",
         }
     }
 }
@@ -67,6 +70,18 @@ pub enum SectionValue {
     Asserts(Vec<Assert>),
     Options(Vec<EntryOption>),
     Bindings(Vec<BindingParam>),
+    Tags(Vec<Tag>),
+    GraphQl(GraphQlSection),
+}
+
+/// The content of a `[GraphQL]` section: a query written verbatim, optionally followed by a
+/// `variables` JSON object and an `operationName` line. The runner composes the JSON request
+/// body from these parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphQlSection {
+    pub query: Template,
+    pub variables: Option<GraphQlVariables>,
+    pub operation_name: Option<Template>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -80,6 +95,16 @@ pub struct Cookie {
     pub line_terminator0: LineTerminator,
 }
 
+/// A tag of a `[Tags]` section, used to filter the entries to run (see `--tags` option).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tag {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub name: String,
+    pub source_info: SourceInfo,
+    pub line_terminator0: LineTerminator,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct BindingParam {
     pub line_terminators: Vec<LineTerminator>,
@@ -97,6 +122,24 @@ pub enum BindingExpr {
         space0: Whitespace,
         filename: Template,
     },
+    /// Binds a variable to a key of a shared JSON object file: several
+    /// variables can be synced to the same file, one key per variable.
+    JsonFile {
+        space0: Whitespace,
+        filename: Template,
+    },
+    /// Binds a variable to a key of a shared YAML mapping file, the
+    /// human-friendly flavour of [`BindingExpr::JsonFile`].
+    YamlFile {
+        space0: Whitespace,
+        filename: Template,
+    },
+    /// Binds a variable to an OS environment variable: the environment provides
+    /// the initial value and updates are written back to it.
+    Env {
+        space0: Whitespace,
+        var_name: Template,
+    },
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -124,6 +167,9 @@ pub struct FilenameValue {
     pub space1: Whitespace,
     pub space2: Whitespace,
     pub content_type: Option<Template>,
+    /// Filename announced in the part's `Content-Disposition` header, set with a `filename:`
+    /// annotation (the source file name is used otherwise).
+    pub filename_override: Option<Template>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -167,11 +213,24 @@ pub enum QueryValue {
         space0: Whitespace,
         name: Template,
     },
+    LinkHeader {
+        space0: Whitespace,
+        rel: Template,
+    },
     Cookie {
         space0: Whitespace,
         expr: CookiePath,
     },
     Body,
+    BodyDigest {
+        space0: Whitespace,
+        algorithm: DigestAlgorithm,
+    },
+    Ndjson {
+        space0: Whitespace,
+        space1: Whitespace,
+        selector: NdjsonSelector,
+    },
     Xpath {
         space0: Whitespace,
         expr: Template,
@@ -180,6 +239,14 @@ pub enum QueryValue {
         space0: Whitespace,
         expr: Template,
     },
+    Csv {
+        space0: Whitespace,
+        space1: Whitespace,
+        column: CsvColumn,
+        space2: Whitespace,
+        space3: Whitespace,
+        row: U64,
+    },
     Regex {
         space0: Whitespace,
         value: RegexValue,
@@ -188,7 +255,9 @@ pub enum QueryValue {
         space0: Whitespace,
         name: Template,
     },
-    Duration,
+    Duration {
+        phase: Option<DurationPhase>,
+    },
     Bytes,
     RawBytes,
     Sha256,
@@ -209,13 +278,17 @@ impl QueryValue {
             QueryValue::Version => "version",
             QueryValue::Url => "url",
             QueryValue::Header { .. } => "header",
+            QueryValue::LinkHeader { .. } => "link-header",
             QueryValue::Cookie { .. } => "cookie",
             QueryValue::Body => "body",
+            QueryValue::BodyDigest { .. } => "body",
+            QueryValue::Ndjson { .. } => "body",
             QueryValue::Xpath { .. } => "xpath",
             QueryValue::Jsonpath { .. } => "jsonpath",
+            QueryValue::Csv { .. } => "csv",
             QueryValue::Regex { .. } => "regex",
             QueryValue::Variable { .. } => "variable",
-            QueryValue::Duration => "duration",
+            QueryValue::Duration { .. } => "duration",
             QueryValue::Bytes => "bytes",
             QueryValue::RawBytes => "rawbytes",
             QueryValue::Sha256 => "sha256",
@@ -227,6 +300,59 @@ impl QueryValue {
     }
 }
 
+/// Column selector of a `csv` query: either a 0-based column index, or a column name looked up
+/// in the first row of the body (which is then treated as a header row).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CsvColumn {
+    Index(U64),
+    Name(Template),
+}
+
+/// Selector of a `body ndjson` query: either the number of lines of the NDJSON body, or a
+/// JSONPath expression evaluated on one of its lines. Blank lines are not counted, per the
+/// NDJSON spec.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum NdjsonSelector {
+    /// `count`: the number of non-blank lines.
+    Count,
+    /// `line <n> jsonpath <expr>`: parses the nth (0-based) non-blank line as JSON and
+    /// evaluates a JSONPath expression on it.
+    Line {
+        space0: Whitespace,
+        line: U64,
+        space1: Whitespace,
+        space2: Whitespace,
+        expr: Template,
+    },
+}
+
+/// Digest algorithm of a `body <algorithm>` query, with the encoding of the computed hash:
+/// raw bytes, lowercase hexadecimal string or Base64 string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    Sha256,
+    Sha256Hex,
+    Sha256Base64,
+    Md5,
+    Md5Hex,
+    Md5Base64,
+}
+
+impl DigestAlgorithm {
+    /// Returns the Hurl string identifier of this digest algorithm.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha256 => "sha256",
+            DigestAlgorithm::Sha256Hex => "sha256-hex",
+            DigestAlgorithm::Sha256Base64 => "sha256-base64",
+            DigestAlgorithm::Md5 => "md5",
+            DigestAlgorithm::Md5Hex => "md5-hex",
+            DigestAlgorithm::Md5Base64 => "md5-base64",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RegexValue {
     Template(Template),
@@ -313,6 +439,34 @@ impl CookieAttributeName {
     }
 }
 
+/// The transfer phase measured by a `duration` query: without an explicit phase, the query
+/// returns the total time of the transfer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DurationPhase {
+    pub space0: Whitespace,
+    pub name: DurationPhaseName,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DurationPhaseName {
+    Dns,
+    Connect,
+    FirstByte,
+    Total,
+}
+
+impl DurationPhaseName {
+    /// Returns the Hurl string identifier of this duration phase name.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            DurationPhaseName::Dns => "dns",
+            DurationPhaseName::Connect => "connect",
+            DurationPhaseName::FirstByte => "first-byte",
+            DurationPhaseName::Total => "total",
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CertificateAttributeName {
     Subject,
@@ -321,6 +475,7 @@ pub enum CertificateAttributeName {
     ExpireDate,
     SerialNumber,
     SubjectAltName,
+    Version,
 }
 
 impl CertificateAttributeName {
@@ -333,6 +488,7 @@ impl CertificateAttributeName {
             CertificateAttributeName::ExpireDate => "Expire-Date",
             CertificateAttributeName::SerialNumber => "Serial-Number",
             CertificateAttributeName::SubjectAltName => "Subject-Alt-Name",
+            CertificateAttributeName::Version => "Version",
         }
     }
 }
@@ -388,6 +544,10 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    EqualIgnoreCase {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     NotEqual {
         space0: Whitespace,
         value: PredicateValue,
@@ -408,18 +568,44 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    Approximately {
+        space0: Whitespace,
+        value: PredicateValue,
+        space1: Whitespace,
+        space2: Whitespace,
+        delta: PredicateValue,
+    },
+    /// Inclusive range check on both ends: `between <min> <max>`.
+    Between {
+        space0: Whitespace,
+        min: PredicateValue,
+        space1: Whitespace,
+        max: PredicateValue,
+    },
     StartWith {
         space0: Whitespace,
         value: PredicateValue,
     },
+    StartWithIgnoreCase {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     EndWith {
         space0: Whitespace,
         value: PredicateValue,
     },
+    EndWithIgnoreCase {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     Contain {
         space0: Whitespace,
         value: PredicateValue,
     },
+    ContainIgnoreCase {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     Include {
         space0: Whitespace,
         value: PredicateValue,
@@ -428,8 +614,31 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    JsonSchema {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+    MatchesSchema {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+    MatchesJson {
+        space0: Whitespace,
+        value: JsonValue,
+    },
+    Semver {
+        space0: Whitespace,
+        operator: SemverOperator,
+        space1: Whitespace,
+        value: PredicateValue,
+    },
+    IsSorted {
+        space0: Whitespace,
+        order: SortOrder,
+    },
     Exist,
     IsBoolean,
+    IsClientError,
     IsCollection,
     IsDate,
     IsEmpty,
@@ -438,11 +647,59 @@ pub enum PredicateFuncValue {
     IsIpv4,
     IsIpv6,
     IsIsoDate,
+    IsJson,
     IsList,
     IsNumber,
     IsObject,
+    IsRedirect,
+    IsServerError,
     IsString,
+    IsSuccess,
+    IsUnique,
     IsUuid,
+    IsXml,
+}
+
+/// Sort order of an `is_sorted` predicate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    /// Returns the Hurl string identifier of this sort order.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/// Comparison operator of a `semver` predicate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SemverOperator {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+impl SemverOperator {
+    /// Returns the Hurl string identifier of this operator.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            SemverOperator::Equal => "==",
+            SemverOperator::NotEqual => "!=",
+            SemverOperator::GreaterThan => ">",
+            SemverOperator::GreaterThanOrEqual => ">=",
+            SemverOperator::LessThan => "<",
+            SemverOperator::LessThanOrEqual => "<=",
+        }
+    }
 }
 
 impl PredicateFuncValue {
@@ -450,18 +707,30 @@ impl PredicateFuncValue {
     pub fn identifier(&self) -> &'static str {
         match self {
             PredicateFuncValue::Equal { .. } => "==",
+            PredicateFuncValue::EqualIgnoreCase { .. } => "equals-ignorecase",
             PredicateFuncValue::NotEqual { .. } => "!=",
             PredicateFuncValue::GreaterThan { .. } => ">",
             PredicateFuncValue::GreaterThanOrEqual { .. } => ">=",
             PredicateFuncValue::LessThan { .. } => "<",
             PredicateFuncValue::LessThanOrEqual { .. } => "<=",
+            PredicateFuncValue::Approximately { .. } => "approximately",
+            PredicateFuncValue::Between { .. } => "between",
             PredicateFuncValue::StartWith { .. } => "startsWith",
+            PredicateFuncValue::StartWithIgnoreCase { .. } => "starts-with-ignorecase",
             PredicateFuncValue::EndWith { .. } => "endsWith",
+            PredicateFuncValue::EndWithIgnoreCase { .. } => "ends-with-ignorecase",
             PredicateFuncValue::Contain { .. } => "contains",
+            PredicateFuncValue::ContainIgnoreCase { .. } => "contains-ignorecase",
             PredicateFuncValue::Include { .. } => "includes",
             PredicateFuncValue::Match { .. } => "matches",
+            PredicateFuncValue::JsonSchema { .. } => "jsonschema",
+            PredicateFuncValue::MatchesSchema { .. } => "matches-schema",
+            PredicateFuncValue::MatchesJson { .. } => "matches_json",
+            PredicateFuncValue::Semver { .. } => "semver",
+            PredicateFuncValue::IsSorted { .. } => "is_sorted",
             PredicateFuncValue::Exist => "exists",
             PredicateFuncValue::IsBoolean => "isBoolean",
+            PredicateFuncValue::IsClientError => "is-client-error",
             PredicateFuncValue::IsCollection => "isCollection",
             PredicateFuncValue::IsDate => "isDate",
             PredicateFuncValue::IsEmpty => "isEmpty",
@@ -470,11 +739,17 @@ impl PredicateFuncValue {
             PredicateFuncValue::IsIpv4 => "isIpv4",
             PredicateFuncValue::IsIpv6 => "isIpv6",
             PredicateFuncValue::IsIsoDate => "isIsoDate",
+            PredicateFuncValue::IsJson => "is-json",
             PredicateFuncValue::IsList => "isList",
             PredicateFuncValue::IsNumber => "isNumber",
             PredicateFuncValue::IsObject => "isObject",
+            PredicateFuncValue::IsRedirect => "is-redirect",
+            PredicateFuncValue::IsServerError => "is-server-error",
             PredicateFuncValue::IsString => "isString",
+            PredicateFuncValue::IsSuccess => "is-success",
+            PredicateFuncValue::IsUnique => "is_unique",
             PredicateFuncValue::IsUuid => "isUuid",
+            PredicateFuncValue::IsXml => "is-xml",
         }
     }
 }
@@ -521,6 +796,21 @@ impl ToSource for BindingExpr {
                 s.push_str(&space0.value);
                 s.push_str(filename.to_source().as_str());
             }
+            BindingExpr::JsonFile { space0, filename } => {
+                s.push_str("jsonfile");
+                s.push_str(&space0.value);
+                s.push_str(filename.to_source().as_str());
+            }
+            BindingExpr::YamlFile { space0, filename } => {
+                s.push_str("yamlfile");
+                s.push_str(&space0.value);
+                s.push_str(filename.to_source().as_str());
+            }
+            BindingExpr::Env { space0, var_name } => {
+                s.push_str("env");
+                s.push_str(&space0.value);
+                s.push_str(var_name.to_source().as_str());
+            }
         }
         s
     }
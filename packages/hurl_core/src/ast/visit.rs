@@ -22,9 +22,10 @@
 //! Code heavily inspired from <https://github.com/rust-lang/rust/blob/master/compiler/rustc_ast/src/visit.rs>
 use crate::ast::{
     Assert, Base64, Body, BooleanOption, Bytes, Capture, Comment, Cookie, CookiePath, CountOption,
-    DurationOption, Entry, EntryOption, File, FilenameParam, FilenameValue, Filter, FilterValue,
-    Hex, HurlFile, IntegerValue, JsonValue, KeyValue, LineTerminator, Method, MultilineString,
-    MultipartParam, NaturalOption, Number, OptionKind, Placeholder, Predicate, PredicateFuncValue,
+    CompressOption, CsvColumn, DurationOption, Entry, EntryOption, File, FilenameParam,
+    FilenameValue, Filter, FilterValue,
+    Hex, HurlFile, IntegerValue, IpVersionOption, JsonValue, KeyValue, LineTerminator, Method, MultilineString,
+    MultipartParam, NaturalOption, NdjsonSelector, Number, OptionKind, Placeholder, Predicate, PredicateFuncValue,
     PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response, Section, SectionValue,
     StatusValue, Template, VariableDefinition, VariableValue, VerbosityOption, VersionValue,
     Whitespace, U64,
@@ -224,6 +225,14 @@ pub trait Visitor: Sized {
         walk_variable_value(self, value);
     }
 
+    fn visit_compress_option(&mut self, value: &CompressOption) {
+        walk_compress_option(self, value);
+    }
+
+    fn visit_ip_version_option(&mut self, value: &IpVersionOption) {
+        walk_ip_version_option(self, value);
+    }
+
     fn visit_verbosity_option(&mut self, value: &VerbosityOption) {
         walk_verbosity_option(self, value);
     }
@@ -366,11 +375,14 @@ pub fn walk_entry_option<V: Visitor>(visitor: &mut V, option: &EntryOption) {
     match &option.kind {
         OptionKind::AwsSigV4(value) => visitor.visit_template(value),
         OptionKind::CaCertificate(filename) => visitor.visit_filename(filename),
+        OptionKind::CaCertificateAppend(filename) => visitor.visit_filename(filename),
         OptionKind::ClientCert(filename) => visitor.visit_filename(filename),
         OptionKind::ClientKey(filename) => visitor.visit_filename(filename),
+        OptionKind::Compress(value) => visitor.visit_compress_option(value),
         OptionKind::Compressed(value) => visitor.visit_bool_option(value),
         OptionKind::ConnectTo(value) => visitor.visit_template(value),
         OptionKind::ConnectTimeout(value) => visitor.visit_duration_option(value),
+        OptionKind::CsvSeparator(value) => visitor.visit_template(value),
         OptionKind::Delay(value) => visitor.visit_duration_option(value),
         OptionKind::Digest(value) => visitor.visit_bool_option(value),
         OptionKind::FollowLocation(value) => visitor.visit_bool_option(value),
@@ -383,7 +395,9 @@ pub fn walk_entry_option<V: Visitor>(visitor: &mut V, option: &EntryOption) {
         OptionKind::Insecure(value) => visitor.visit_bool_option(value),
         OptionKind::IpV4(value) => visitor.visit_bool_option(value),
         OptionKind::IpV6(value) => visitor.visit_bool_option(value),
+        OptionKind::IpVersion(value) => visitor.visit_ip_version_option(value),
         OptionKind::LimitRate(value) => visitor.visit_natural_option(value),
+        OptionKind::MaxFilesize(value) => visitor.visit_natural_option(value),
         OptionKind::MaxRedirect(value) => visitor.visit_count_option(value),
         OptionKind::MaxTime(value) => visitor.visit_duration_option(value),
         OptionKind::Negotiate(value) => visitor.visit_bool_option(value),
@@ -399,6 +413,8 @@ pub fn walk_entry_option<V: Visitor>(visitor: &mut V, option: &EntryOption) {
         OptionKind::Resolve(value) => visitor.visit_template(value),
         OptionKind::Retry(value) => visitor.visit_count_option(value),
         OptionKind::RetryInterval(value) => visitor.visit_duration_option(value),
+        OptionKind::RetryMaxInterval(value) => visitor.visit_duration_option(value),
+        OptionKind::RetryOnStatus(value) => value.iter().for_each(|v| visitor.visit_u64(v)),
         OptionKind::Skip(value) => visitor.visit_bool_option(value),
         OptionKind::UnixSocket(value) => visitor.visit_filename(value),
         OptionKind::User(value) => visitor.visit_template(value),
@@ -406,6 +422,7 @@ pub fn walk_entry_option<V: Visitor>(visitor: &mut V, option: &EntryOption) {
         OptionKind::Verbose(value) => visitor.visit_bool_option(value),
         OptionKind::Verbosity(value) => visitor.visit_verbosity_option(value),
         OptionKind::VeryVerbose(value) => visitor.visit_bool_option(value),
+        OptionKind::XpathNamespace(value) => visitor.visit_template(value),
     };
     visitor.visit_lt(&option.line_terminator0);
 }
@@ -451,6 +468,10 @@ pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
             visitor.visit_whitespace(space0);
             visitor.visit_template(expr);
         }
+        FilterValue::JwtClaim { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
         FilterValue::Last => {}
         FilterValue::Location => {}
         FilterValue::Nth { space0, n } => {
@@ -539,6 +560,13 @@ pub fn walk_filename_value<V: Visitor>(visitor: &mut V, value: &FilenameValue) {
     if let Some(content_type) = &value.content_type {
         visitor.visit_template(content_type);
     }
+    if let Some(filename) = &value.filename_override {
+        if value.content_type.is_some() {
+            visitor.visit_literal(";");
+        }
+        visitor.visit_literal("filename:");
+        visitor.visit_template(filename);
+    }
 }
 
 pub fn walk_header<V: Visitor>(visitor: &mut V, header: &KeyValue) {
@@ -606,6 +634,10 @@ pub fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) {
             visitor.visit_whitespace(space0);
             visitor.visit_template(name);
         }
+        QueryValue::LinkHeader { space0, rel } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(rel);
+        }
         QueryValue::Cookie { space0, expr } => {
             visitor.visit_whitespace(space0);
             visitor.visit_cookie_path(expr);
@@ -618,6 +650,26 @@ pub fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) {
             visitor.visit_whitespace(space0);
             visitor.visit_template(expr);
         }
+        QueryValue::Csv {
+            space0,
+            space1,
+            column,
+            space2,
+            space3,
+            row,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_literal("col");
+            visitor.visit_whitespace(space1);
+            match column {
+                CsvColumn::Index(index) => visitor.visit_u64(index),
+                CsvColumn::Name(name) => visitor.visit_template(name),
+            }
+            visitor.visit_whitespace(space2);
+            visitor.visit_literal("row");
+            visitor.visit_whitespace(space3);
+            visitor.visit_u64(row);
+        }
         QueryValue::Regex { space0, value } => {
             visitor.visit_whitespace(space0);
             match value {
@@ -636,10 +688,41 @@ pub fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) {
             visitor.visit_whitespace(space0);
             visitor.visit_string(attribute_name.to_source().as_str());
         }
+        QueryValue::BodyDigest { space0, algorithm } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_string(algorithm.identifier());
+        }
+        QueryValue::Ndjson {
+            space0,
+            space1,
+            selector,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_literal("ndjson");
+            visitor.visit_whitespace(space1);
+            match selector {
+                NdjsonSelector::Count => visitor.visit_literal("count"),
+                NdjsonSelector::Line {
+                    space0,
+                    line,
+                    space1,
+                    space2,
+                    expr,
+                } => {
+                    visitor.visit_literal("line");
+                    visitor.visit_whitespace(space0);
+                    visitor.visit_u64(line);
+                    visitor.visit_whitespace(space1);
+                    visitor.visit_literal("jsonpath");
+                    visitor.visit_whitespace(space2);
+                    visitor.visit_template(expr);
+                }
+            }
+        }
         QueryValue::Body
         | QueryValue::Status
         | QueryValue::Url
-        | QueryValue::Duration
+        | QueryValue::Duration { .. }
         | QueryValue::Bytes
         | QueryValue::RawBytes
         | QueryValue::Sha256
@@ -662,6 +745,21 @@ pub fn walk_predicate<V: Visitor>(visitor: &mut V, pred: &Predicate) {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
         }
+        PredicateFuncValue::EqualIgnoreCase { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
+        PredicateFuncValue::Semver {
+            space0,
+            operator,
+            space1,
+            value,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_literal(operator.identifier());
+            visitor.visit_whitespace(space1);
+            visitor.visit_predicate_value(value);
+        }
         PredicateFuncValue::NotEqual { space0, value } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
@@ -682,18 +780,55 @@ pub fn walk_predicate<V: Visitor>(visitor: &mut V, pred: &Predicate) {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
         }
+        PredicateFuncValue::Approximately {
+            space0,
+            value,
+            space1,
+            space2,
+            delta,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+            visitor.visit_whitespace(space1);
+            visitor.visit_literal("delta");
+            visitor.visit_whitespace(space2);
+            visitor.visit_predicate_value(delta);
+        }
+        PredicateFuncValue::Between {
+            space0,
+            min,
+            space1,
+            max,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(min);
+            visitor.visit_whitespace(space1);
+            visitor.visit_predicate_value(max);
+        }
         PredicateFuncValue::StartWith { space0, value } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
         }
+        PredicateFuncValue::StartWithIgnoreCase { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
         PredicateFuncValue::EndWith { space0, value } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
         }
+        PredicateFuncValue::EndWithIgnoreCase { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
         PredicateFuncValue::Contain { space0, value } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
         }
+        PredicateFuncValue::ContainIgnoreCase { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
         PredicateFuncValue::Include { space0, value } => {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
@@ -702,8 +837,22 @@ pub fn walk_predicate<V: Visitor>(visitor: &mut V, pred: &Predicate) {
             visitor.visit_whitespace(space0);
             visitor.visit_predicate_value(value);
         }
+        PredicateFuncValue::JsonSchema { space0, value }
+        | PredicateFuncValue::MatchesSchema { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
+        PredicateFuncValue::MatchesJson { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_json_body(value);
+        }
+        PredicateFuncValue::IsSorted { space0, order } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_literal(order.identifier());
+        }
         PredicateFuncValue::Exist
         | PredicateFuncValue::IsBoolean
+        | PredicateFuncValue::IsClientError
         | PredicateFuncValue::IsCollection
         | PredicateFuncValue::IsDate
         | PredicateFuncValue::IsEmpty
@@ -712,11 +861,17 @@ pub fn walk_predicate<V: Visitor>(visitor: &mut V, pred: &Predicate) {
         | PredicateFuncValue::IsIpv4
         | PredicateFuncValue::IsIpv6
         | PredicateFuncValue::IsIsoDate
+        | PredicateFuncValue::IsJson
         | PredicateFuncValue::IsList
         | PredicateFuncValue::IsNumber
         | PredicateFuncValue::IsObject
+        | PredicateFuncValue::IsRedirect
+        | PredicateFuncValue::IsServerError
         | PredicateFuncValue::IsString
-        | PredicateFuncValue::IsUuid => {}
+        | PredicateFuncValue::IsSuccess
+        | PredicateFuncValue::IsUnique
+        | PredicateFuncValue::IsUuid
+        | PredicateFuncValue::IsXml => {}
     }
 }
 
@@ -800,8 +955,17 @@ pub fn walk_section_value<V: Visitor>(visitor: &mut V, section_value: &SectionVa
             options.iter().for_each(|o| visitor.visit_entry_option(o));
         }
         SectionValue::QueryParams(params, _) => params.iter().for_each(|p| visitor.visit_kv(p)),
+        SectionValue::Tags(_) => {}
         SectionValue::Bindings(binding_params) => {
-            binding_params.iter().for_each(|p| visitor.visit_binding_param(p))
+            binding_params
+                .iter()
+                .for_each(|p| visitor.visit_binding_param(p));
+        }
+        SectionValue::GraphQl(graphql) => {
+            visitor.visit_template(&graphql.query);
+            if let Some(name) = &graphql.operation_name {
+                visitor.visit_template(name);
+            }
         }
     }
 }
@@ -821,6 +985,21 @@ pub fn walk_binding_param<V: Visitor>(visitor: &mut V, param: &crate::ast::Bindi
             visitor.visit_whitespace(space0);
             visitor.visit_template(filename);
         }
+        crate::ast::BindingExpr::JsonFile { space0, filename } => {
+            visitor.visit_literal("jsonfile");
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(filename);
+        }
+        crate::ast::BindingExpr::YamlFile { space0, filename } => {
+            visitor.visit_literal("yamlfile");
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(filename);
+        }
+        crate::ast::BindingExpr::Env { space0, var_name } => {
+            visitor.visit_literal("env");
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(var_name);
+        }
     }
     visitor.visit_lt(&param.line_terminator0);
 }
@@ -842,6 +1021,14 @@ pub fn walk_variable_value<V: Visitor>(visitor: &mut V, value: &VariableValue) {
     }
 }
 
+pub fn walk_compress_option<V: Visitor>(visitor: &mut V, value: &CompressOption) {
+    visitor.visit_string(value.identifier());
+}
+
+pub fn walk_ip_version_option<V: Visitor>(visitor: &mut V, value: &IpVersionOption) {
+    visitor.visit_string(value.identifier());
+}
+
 pub fn walk_verbosity_option<V: Visitor>(visitor: &mut V, value: &VerbosityOption) {
     visitor.visit_string(value.identifier());
 }
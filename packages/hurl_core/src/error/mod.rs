@@ -15,7 +15,7 @@
  * limitations under the License.
  *
  */
-use std::cmp::max;
+use std::cmp::{max, min};
 
 use crate::ast::SourceInfo;
 use crate::text::{Format, Style, StyledString};
@@ -160,6 +160,52 @@ pub fn add_carets(message: &str, source_info: SourceInfo, content: &[&str]) -> S
     s
 }
 
+/// Returns the valid value the closest to `actual`, if any.
+///
+/// This is used to build "Did you mean ...?" error messages: a value is suggested
+/// when its edit distance to `actual` is small enough.
+pub fn suggestion(valid_values: &[&str], actual: &str) -> Option<String> {
+    for value in valid_values {
+        if levenshtein_distance(
+            value.to_lowercase().as_str(),
+            actual.to_lowercase().as_str(),
+        ) < 2
+        {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+// From https://en.wikibooks.org/wiki/Algorithm_Implementation/Strings/Levenshtein_distance#Rust
+fn levenshtein_distance(s1: &str, s2: &str) -> usize {
+    let v1: Vec<char> = s1.chars().collect();
+    let v2: Vec<char> = s2.chars().collect();
+
+    fn min3<T: Ord>(v1: T, v2: T, v3: T) -> T {
+        min(v1, min(v2, v3))
+    }
+    fn delta(x: char, y: char) -> usize {
+        usize::from(x != y)
+    }
+
+    let mut column: Vec<usize> = (0..=v1.len()).collect();
+    for x in 1..=v2.len() {
+        column[0] = x;
+        let mut lastdiag = x - 1;
+        for y in 1..=v1.len() {
+            let olddiag = column[y];
+            column[y] = min3(
+                column[y] + 1,
+                column[y - 1] + 1,
+                lastdiag + delta(v1[y - 1], v2[x - 1]),
+            );
+            lastdiag = olddiag;
+        }
+    }
+    column[v1.len()]
+}
+
 /// Format used by to_string
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -298,6 +344,30 @@ mod tests {
     use crate::reader::Pos;
     use crate::text::{Format, Style};
 
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("Saturday", "Sunday"), 3);
+    }
+
+    #[test]
+    fn test_suggestion() {
+        let valid_values = ["Captures", "Asserts"];
+        assert_eq!(
+            suggestion(&valid_values, "Asserts"),
+            Some("Asserts".to_string())
+        );
+        assert_eq!(
+            suggestion(&valid_values, "Assert"),
+            Some("Asserts".to_string())
+        );
+        assert_eq!(
+            suggestion(&valid_values, "assert"),
+            Some("Asserts".to_string())
+        );
+        assert_eq!(suggestion(&valid_values, "asser"), None);
+    }
+
     #[test]
     fn test_add_carets() {
         // `Hello World`
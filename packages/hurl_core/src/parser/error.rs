@@ -15,8 +15,6 @@
  * limitations under the License.
  *
  */
-use std::cmp;
-
 use crate::ast::SourceInfo;
 use crate::error;
 use crate::error::DisplaySourceError;
@@ -38,6 +36,7 @@ pub enum ParseErrorKind {
     Expecting { value: String },
     FileContentType,
     Filename,
+    GraphQlSectionBody,
     GraphQlVariables,
     HexDigit,
     InvalidCookieAttribute,
@@ -101,6 +100,7 @@ impl DisplaySourceError for ParseError {
             ParseErrorKind::Expecting { .. } => "Parsing literal".to_string(),
             ParseErrorKind::FileContentType => "Parsing file content type".to_string(),
             ParseErrorKind::Filename => "Parsing filename".to_string(),
+            ParseErrorKind::GraphQlSectionBody => "Parsing section".to_string(),
             ParseErrorKind::GraphQlVariables => "Parsing GraphQL variables".to_string(),
             ParseErrorKind::HexDigit => "Parsing hexadecimal number".to_string(),
             ParseErrorKind::InvalidCookieAttribute => "Parsing cookie attribute".to_string(),
@@ -141,6 +141,9 @@ impl DisplaySourceError for ParseError {
             ParseErrorKind::Expecting { value } => format!("expecting '{value}'"),
             ParseErrorKind::FileContentType => "expecting a content type".to_string(),
             ParseErrorKind::Filename => "expecting a filename".to_string(),
+            ParseErrorKind::GraphQlSectionBody => {
+                "the [GraphQL] section is not compatible with a body".to_string()
+            }
             ParseErrorKind::GraphQlVariables => {
                 "GraphQL variables is not a valid JSON object".to_string()
             }
@@ -290,84 +293,18 @@ impl crate::combinator::ParseError for ParseError {
 }
 
 fn did_you_mean(valid_values: &[&str], actual: &str, default: &str) -> String {
-    if let Some(suggest) = suggestion(valid_values, actual) {
+    if let Some(suggest) = error::suggestion(valid_values, actual) {
         format!("Did you mean {suggest}?")
     } else {
         default.to_string()
     }
 }
 
-fn suggestion(valid_values: &[&str], actual: &str) -> Option<String> {
-    for value in valid_values {
-        if levenshtein_distance(
-            value.to_lowercase().as_str(),
-            actual.to_lowercase().as_str(),
-        ) < 2
-        {
-            return Some(value.to_string());
-        }
-    }
-    None
-}
-
-// From https://en.wikibooks.org/wiki/Algorithm_Implementation/Strings/Levenshtein_distance#Rust
-fn levenshtein_distance(s1: &str, s2: &str) -> usize {
-    let v1: Vec<char> = s1.chars().collect();
-    let v2: Vec<char> = s2.chars().collect();
-
-    fn min3<T: Ord>(v1: T, v2: T, v3: T) -> T {
-        cmp::min(v1, cmp::min(v2, v3))
-    }
-    fn delta(x: char, y: char) -> usize {
-        usize::from(x != y)
-    }
-
-    let mut column: Vec<usize> = (0..=v1.len()).collect();
-    for x in 1..=v2.len() {
-        column[0] = x;
-        let mut lastdiag = x - 1;
-        for y in 1..=v1.len() {
-            let olddiag = column[y];
-            column[y] = min3(
-                column[y] + 1,
-                column[y - 1] + 1,
-                lastdiag + delta(v1[y - 1], v2[x - 1]),
-            );
-            lastdiag = olddiag;
-        }
-    }
-    column[v1.len()]
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::error::OutputFormat;
 
-    #[test]
-    fn test_levenshtein() {
-        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
-        assert_eq!(levenshtein_distance("Saturday", "Sunday"), 3);
-    }
-
-    #[test]
-    fn test_suggestion() {
-        let valid_values = ["Captures", "Asserts"];
-        assert_eq!(
-            suggestion(&valid_values, "Asserts"),
-            Some("Asserts".to_string())
-        );
-        assert_eq!(
-            suggestion(&valid_values, "Assert"),
-            Some("Asserts".to_string())
-        );
-        assert_eq!(
-            suggestion(&valid_values, "assert"),
-            Some("Asserts".to_string())
-        );
-        assert_eq!(suggestion(&valid_values, "asser"), None);
-    }
-
     #[test]
     fn test_parsing_error() {
         let content = "GET abc";
@@ -68,6 +68,7 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
             html_decode_filter,
             html_encode_filter,
             jsonpath_filter,
+            jwt_claim_filter,
             last_filter,
             location_filter,
             nth_filter,
@@ -192,6 +193,13 @@ fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::JsonPath { space0, expr })
 }
 
+fn jwt_claim_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jwt-claim", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::JwtClaim { space0, name })
+}
+
 fn last_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("last", reader)?;
     Ok(FilterValue::Last)
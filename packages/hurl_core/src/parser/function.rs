@@ -15,8 +15,11 @@
  * limitations under the License.
  *
  */
-use crate::ast::Function;
-use crate::parser::{ParseError, ParseErrorKind, ParseResult};
+use crate::ast::{Function, TimeOffset, TimeOffsetUnit};
+use crate::combinator::ParseError as ParseErrorTrait;
+use crate::parser::number::{integer, number};
+use crate::parser::primitives::{boolean, literal, try_literal, zero_or_more_spaces};
+use crate::parser::{expr, ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
 
 /// Parse a function
@@ -27,6 +30,162 @@ pub fn parse(reader: &mut Reader) -> ParseResult<Function> {
     match function_name.as_str() {
         "newDate" => Ok(Function::NewDate),
         "newUuid" => Ok(Function::NewUuid),
+        // `uuid()` is an alias of `newUuid`: the parentheses are required so that
+        // a variable named `uuid` keeps working.
+        "uuid" => {
+            try_literal("(", reader)?;
+            zero_or_more_spaces(reader)?;
+            literal(")", reader)?;
+            Ok(Function::NewUuid)
+        }
+        "base64Encode" => {
+            let arg = argument(reader)?;
+            Ok(Function::Base64Encode(Box::new(arg)))
+        }
+        "base64Decode" => {
+            let arg = argument(reader)?;
+            Ok(Function::Base64Decode(Box::new(arg)))
+        }
+        // `hmac_sha256(key, message)` signs a message, as a lowercase hex digest or in
+        // a base64 variant.
+        "hmac_sha256" => {
+            let (key, message) = arguments2(reader)?;
+            Ok(Function::HmacSha256 {
+                key: Box::new(key),
+                message: Box::new(message),
+            })
+        }
+        "hmac_sha256_base64" => {
+            let (key, message) = arguments2(reader)?;
+            Ok(Function::HmacSha256Base64 {
+                key: Box::new(key),
+                message: Box::new(message),
+            })
+        }
+        // `url_encode(q)` percent-encodes per RFC 3986 (spaces as `%20`), with an
+        // optional form-encoding mode like `url_encode(q, form=true)` (spaces as `+`).
+        "url_encode" => {
+            try_literal("(", reader)?;
+            zero_or_more_spaces(reader)?;
+            let arg = expr::parse(reader).map_err(|e| e.to_non_recoverable())?;
+            zero_or_more_spaces(reader)?;
+            let form = if try_literal(",", reader).is_ok() {
+                zero_or_more_spaces(reader)?;
+                literal("form=", reader)?;
+                let form = boolean(reader).map_err(|e| e.to_non_recoverable())?;
+                zero_or_more_spaces(reader)?;
+                form
+            } else {
+                false
+            };
+            literal(")", reader)?;
+            Ok(Function::UrlEncode {
+                arg: Box::new(arg),
+                form,
+            })
+        }
+        "url_decode" => {
+            let arg = argument(reader)?;
+            Ok(Function::UrlDecode(Box::new(arg)))
+        }
+        // `jsonpath(value, "$.id")` and `xpath(value, "//id")` extract a value from a
+        // string holding a JSON/XML document.
+        "jsonpath" => {
+            let (arg, expr) = argument_and_string(reader)?;
+            Ok(Function::Jsonpath {
+                arg: Box::new(arg),
+                expr,
+            })
+        }
+        "xpath" => {
+            let (arg, expr) = argument_and_string(reader)?;
+            Ok(Function::Xpath {
+                arg: Box::new(arg),
+                expr,
+            })
+        }
+        // `random_int(min, max)` and `random_float(min, max)` produce a random value in
+        // [min, max] inclusive; bounds are literal numbers.
+        "random_int" => {
+            try_literal("(", reader)?;
+            zero_or_more_spaces(reader)?;
+            let min = integer(reader).map_err(|e| e.to_non_recoverable())?;
+            zero_or_more_spaces(reader)?;
+            literal(",", reader)?;
+            zero_or_more_spaces(reader)?;
+            let max = integer(reader).map_err(|e| e.to_non_recoverable())?;
+            zero_or_more_spaces(reader)?;
+            literal(")", reader)?;
+            Ok(Function::RandomInt {
+                min: Box::new(min),
+                max: Box::new(max),
+            })
+        }
+        "random_float" => {
+            try_literal("(", reader)?;
+            zero_or_more_spaces(reader)?;
+            let min = number(reader).map_err(|e| e.to_non_recoverable())?;
+            zero_or_more_spaces(reader)?;
+            literal(",", reader)?;
+            zero_or_more_spaces(reader)?;
+            let max = number(reader).map_err(|e| e.to_non_recoverable())?;
+            zero_or_more_spaces(reader)?;
+            literal(")", reader)?;
+            Ok(Function::RandomFloat {
+                min: Box::new(min),
+                max: Box::new(max),
+            })
+        }
+        // `now("%Y-%m-%d")` formats the current UTC time, with an optional
+        // offset like `now("%Y-%m-%d", +1d)`.
+        "now" => {
+            try_literal("(", reader)?;
+            zero_or_more_spaces(reader)?;
+            let format = quoted_string(reader).map_err(|e| e.to_non_recoverable())?;
+            zero_or_more_spaces(reader)?;
+            let offset = if try_literal(",", reader).is_ok() {
+                zero_or_more_spaces(reader)?;
+                let offset = time_offset(reader)?;
+                zero_or_more_spaces(reader)?;
+                Some(offset)
+            } else {
+                None
+            };
+            literal(")", reader)?;
+            Ok(Function::Now { format, offset })
+        }
+        // `counter("name")` returns 0, 1, 2… on successive calls; `counter("name", start=10,
+        // step=2)` sets the initial value and the increment.
+        "counter" => {
+            try_literal("(", reader)?;
+            zero_or_more_spaces(reader)?;
+            let name = quoted_string(reader).map_err(|e| e.to_non_recoverable())?;
+            zero_or_more_spaces(reader)?;
+            let mut start = None;
+            let mut step = None;
+            if try_literal(",", reader).is_ok() {
+                zero_or_more_spaces(reader)?;
+                if try_literal("start=", reader).is_ok() {
+                    let value = integer(reader).map_err(|e| e.to_non_recoverable())?;
+                    start = Some(Box::new(value));
+                    zero_or_more_spaces(reader)?;
+                    if try_literal(",", reader).is_ok() {
+                        zero_or_more_spaces(reader)?;
+                        literal("step=", reader)?;
+                        let value = integer(reader).map_err(|e| e.to_non_recoverable())?;
+                        step = Some(Box::new(value));
+                        zero_or_more_spaces(reader)?;
+                    }
+                } else {
+                    literal("step=", reader)?;
+                    let value = integer(reader).map_err(|e| e.to_non_recoverable())?;
+                    step = Some(Box::new(value));
+                    zero_or_more_spaces(reader)?;
+                }
+            }
+            literal(")", reader)?;
+            Ok(Function::Counter { name, start, step })
+        }
         _ => Err(ParseError::new(
             start.pos,
             true,
@@ -37,6 +196,94 @@ pub fn parse(reader: &mut Reader) -> ParseResult<Function> {
     }
 }
 
+/// Parse a single function argument `(expr)`.
+///
+/// Errors past the opening parenthesis are not recoverable: the function name
+/// has already been recognised so there is no variable fallback.
+fn argument(reader: &mut Reader) -> ParseResult<crate::ast::Expr> {
+    try_literal("(", reader)?;
+    zero_or_more_spaces(reader)?;
+    let arg = expr::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    zero_or_more_spaces(reader)?;
+    literal(")", reader)?;
+    Ok(arg)
+}
+
+/// Parse a two-argument list `(expr, expr)`.
+fn arguments2(reader: &mut Reader) -> ParseResult<(crate::ast::Expr, crate::ast::Expr)> {
+    try_literal("(", reader)?;
+    zero_or_more_spaces(reader)?;
+    let first = expr::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    zero_or_more_spaces(reader)?;
+    literal(",", reader)?;
+    zero_or_more_spaces(reader)?;
+    let second = expr::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    zero_or_more_spaces(reader)?;
+    literal(")", reader)?;
+    Ok((first, second))
+}
+
+/// Parse an argument list `(expr, "string")`.
+fn argument_and_string(reader: &mut Reader) -> ParseResult<(crate::ast::Expr, String)> {
+    try_literal("(", reader)?;
+    zero_or_more_spaces(reader)?;
+    let arg = expr::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    zero_or_more_spaces(reader)?;
+    literal(",", reader)?;
+    zero_or_more_spaces(reader)?;
+    let value = quoted_string(reader).map_err(|e| e.to_non_recoverable())?;
+    zero_or_more_spaces(reader)?;
+    literal(")", reader)?;
+    Ok((arg, value))
+}
+
+/// Parse a double-quoted string literal, without template support.
+fn quoted_string(reader: &mut Reader) -> ParseResult<String> {
+    literal("\"", reader)?;
+    let value = reader.read_while(|c| c != '"' && c != '\n');
+    literal("\"", reader)?;
+    Ok(value)
+}
+
+/// Parse a time offset like `+1d`, `-2h` or `+30m`.
+fn time_offset(reader: &mut Reader) -> ParseResult<TimeOffset> {
+    let start = reader.cursor();
+    let sign = match reader.read() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => {
+            let kind = ParseErrorKind::Expecting {
+                value: "+ or -".to_string(),
+            };
+            return Err(ParseError::new(start.pos, false, kind));
+        }
+    };
+    let digits = reader.read_while(|c| c.is_ascii_digit());
+    let Ok(value) = digits.parse::<i64>() else {
+        let kind = ParseErrorKind::Expecting {
+            value: "a number".to_string(),
+        };
+        return Err(ParseError::new(reader.cursor().pos, false, kind));
+    };
+    let unit_pos = reader.cursor().pos;
+    let unit = match reader.read() {
+        Some('s') => TimeOffsetUnit::Second,
+        Some('m') => TimeOffsetUnit::Minute,
+        Some('h') => TimeOffsetUnit::Hour,
+        Some('d') => TimeOffsetUnit::Day,
+        _ => {
+            let kind = ParseErrorKind::Expecting {
+                value: "s, m, h or d".to_string(),
+            };
+            return Err(ParseError::new(unit_pos, false, kind));
+        }
+    };
+    Ok(TimeOffset {
+        value: sign * value,
+        unit,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,6 +295,166 @@ mod tests {
         assert_eq!(parse(&mut reader).unwrap(), Function::NewUuid);
     }
 
+    #[test]
+    fn test_uuid_alias() {
+        let mut reader = Reader::new("uuid()");
+        assert_eq!(parse(&mut reader).unwrap(), Function::NewUuid);
+
+        // Without parentheses, `uuid` is a plain variable.
+        let mut reader = Reader::new("uuid");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(err.recoverable);
+    }
+
+    #[test]
+    fn test_argument() {
+        let mut reader = Reader::new("base64Encode(token)");
+        let function = parse(&mut reader).unwrap();
+        let Function::Base64Encode(arg) = function else {
+            panic!("expecting a base64Encode function");
+        };
+        assert_eq!(arg.to_string(), "token");
+
+        // A missing parenthesis falls back to a variable (recoverable).
+        let mut reader = Reader::new("base64Encode");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(err.recoverable);
+
+        // An invalid argument is not recoverable.
+        let mut reader = Reader::new("base64Decode(");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_now() {
+        let mut reader = Reader::new("now(\"%Y-%m-%dT%H:%M:%SZ\")");
+        assert_eq!(
+            parse(&mut reader).unwrap(),
+            Function::Now {
+                format: "%Y-%m-%dT%H:%M:%SZ".to_string(),
+                offset: None,
+            }
+        );
+
+        let mut reader = Reader::new("now(\"%Y-%m-%d\", +1d)");
+        assert_eq!(
+            parse(&mut reader).unwrap(),
+            Function::Now {
+                format: "%Y-%m-%d".to_string(),
+                offset: Some(TimeOffset {
+                    value: 1,
+                    unit: TimeOffsetUnit::Day,
+                }),
+            }
+        );
+
+        // Without parentheses, `now` is a plain variable.
+        let mut reader = Reader::new("now");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(err.recoverable);
+
+        // An invalid offset is not recoverable.
+        let mut reader = Reader::new("now(\"%Y\", 1d)");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_url_encode() {
+        let mut reader = Reader::new("url_encode(query)");
+        let function = parse(&mut reader).unwrap();
+        let Function::UrlEncode { arg, form } = function else {
+            panic!("expecting an url_encode function");
+        };
+        assert_eq!(arg.to_string(), "query");
+        assert!(!form);
+
+        let mut reader = Reader::new("url_encode(query, form=true)");
+        let function = parse(&mut reader).unwrap();
+        let Function::UrlEncode { form, .. } = function else {
+            panic!("expecting an url_encode function");
+        };
+        assert!(form);
+
+        // An invalid flag is not recoverable.
+        let mut reader = Reader::new("url_encode(query, plus=true)");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_random() {
+        let mut reader = Reader::new("random_int(1, 1000)");
+        let function = parse(&mut reader).unwrap();
+        let Function::RandomInt { min, max } = function else {
+            panic!("expecting a random_int function");
+        };
+        assert_eq!(min.as_i64(), 1);
+        assert_eq!(max.as_i64(), 1000);
+
+        let mut reader = Reader::new("random_float(0.0, 1.0)");
+        let function = parse(&mut reader).unwrap();
+        assert_eq!(function.to_string(), "random_float(0, 1)");
+
+        // Bounds must be literal numbers.
+        let mut reader = Reader::new("random_int(min, max)");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_jsonpath_xpath() {
+        let mut reader = Reader::new("jsonpath(prev_body, \"$.id\")");
+        let function = parse(&mut reader).unwrap();
+        let Function::Jsonpath { arg, expr } = function else {
+            panic!("expecting a jsonpath function");
+        };
+        assert_eq!(arg.to_string(), "prev_body");
+        assert_eq!(expr, "$.id");
+
+        let mut reader = Reader::new("xpath(doc, \"//id\")");
+        let function = parse(&mut reader).unwrap();
+        assert_eq!(function.to_string(), "xpath(doc, \"//id\")");
+
+        // A missing expression is not recoverable.
+        let mut reader = Reader::new("jsonpath(prev_body)");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_counter() {
+        let mut reader = Reader::new("counter(\"page\")");
+        let function = parse(&mut reader).unwrap();
+        assert_eq!(
+            function,
+            Function::Counter {
+                name: "page".to_string(),
+                start: None,
+                step: None,
+            }
+        );
+
+        let mut reader = Reader::new("counter(\"n\", start=10, step=2)");
+        let function = parse(&mut reader).unwrap();
+        assert_eq!(function.to_string(), "counter(\"n\", start=10, step=2)");
+
+        let mut reader = Reader::new("counter(\"n\", step=2)");
+        let function = parse(&mut reader).unwrap();
+        assert_eq!(function.to_string(), "counter(\"n\", step=2)");
+
+        // Without parentheses, `counter` is a plain variable.
+        let mut reader = Reader::new("counter");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(err.recoverable);
+
+        // An invalid argument is not recoverable.
+        let mut reader = Reader::new("counter(\"n\", offset=1)");
+        let err = parse(&mut reader).unwrap_err();
+        assert!(!err.recoverable);
+    }
+
     #[test]
     fn test_not_exist() {
         let mut reader = Reader::new("name");
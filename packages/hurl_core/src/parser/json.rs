@@ -33,11 +33,21 @@ pub fn parse(reader: &mut Reader) -> ParseResult<JsonValue> {
             expression_value,
             list_value,
             object_value,
+            exact_value,
         ],
         reader,
     )
 }
 
+/// Parses an `exact(...)` wrapper, used in `matches_json` predicates to switch a value from
+/// partial to exact matching.
+fn exact_value(reader: &mut Reader) -> ParseResult<JsonValue> {
+    try_literal("exact(", reader)?;
+    let value = non_recover(parse, reader)?;
+    literal(")", reader)?;
+    Ok(JsonValue::Exact(Box::new(value)))
+}
+
 /// Helper for parse, but already knowing that we are inside a JSON body.
 fn parse_in_json(reader: &mut Reader) -> ParseResult<JsonValue> {
     if let Some(c) = reader.peek() {
@@ -22,6 +22,15 @@ pub fn parse_hurl_file(s: &str) -> ParseResult<HurlFile> {
     parsers::hurl_file(&mut reader)
 }
 
+/// Parses a Hurl file, recovering from syntax errors.
+///
+/// Returns the file made of the entries that have been parsed successfully, along with every
+/// [`ParseError`] encountered. The error list is empty if and only if `s` is a valid Hurl file.
+pub fn parse_hurl_file_with_recovery(s: &str) -> (HurlFile, Vec<ParseError>) {
+    let mut reader = Reader::new(s);
+    parsers::hurl_file_with_recovery(&mut reader)
+}
+
 pub use self::error::{JsonErrorVariant, ParseError, ParseErrorKind};
 pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
@@ -261,6 +261,99 @@ fn graphql(reader: &mut Reader) -> ParseResult<MultilineString> {
     })
 }
 
+/// Parses a `<<<DELIM` heredoc bloc.
+///
+/// The bloc starts with `<<<` followed by a delimiter, and ends on the first line holding only
+/// the delimiter. The newline after the opening delimiter is not part of the value. Contrary to
+/// text blocs, template expressions are kept as simple text; the tilde form `<<<~DELIM` evaluates
+/// them.
+///
+/// ## Example
+///
+/// ~~~hurl
+/// POST https://foo.com
+/// <<<EOF
+/// {
+///   "name": "bob"
+/// }
+/// EOF
+/// ~~~
+pub fn heredoc_string(reader: &mut Reader) -> ParseResult<MultilineString> {
+    try_literal("<<<", reader)?;
+    let templatized = try_literal("~", reader).is_ok();
+    let start = reader.cursor();
+    let delimiter = reader.read_while(|c| c.is_alphanumeric() || c == '_');
+    if delimiter.is_empty() {
+        let kind = ParseErrorKind::Expecting {
+            value: "heredoc delimiter".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    let space = zero_or_more_spaces(reader)?;
+    let newline = newline(reader)?;
+    let value = heredoc_string_value(&delimiter, templatized, reader)?;
+    let kind = if templatized {
+        MultilineStringKind::Text(value)
+    } else {
+        MultilineStringKind::Raw(value)
+    };
+    Ok(MultilineString {
+        space,
+        newline,
+        kind,
+    })
+}
+
+fn heredoc_string_value(
+    delimiter: &str,
+    templatize: bool,
+    reader: &mut Reader,
+) -> ParseResult<Template> {
+    let mut chars = vec![];
+
+    let start = reader.cursor();
+    let end = loop {
+        let save = reader.cursor();
+        if reader.is_eof() {
+            let kind = ParseErrorKind::Expecting {
+                value: delimiter.to_string(),
+            };
+            return Err(ParseError::new(save.pos, false, kind));
+        }
+        let line = reader.read_while(|c| c != '\n' && c != '\r');
+        if line == delimiter {
+            break save;
+        }
+        reader.seek(save);
+        loop {
+            let pos = reader.cursor().pos;
+            let Some(c) = reader.read() else {
+                break;
+            };
+            chars.push((c, c.to_string(), pos));
+            if c == '\n' {
+                break;
+            }
+        }
+    };
+
+    let source_info = SourceInfo::new(start.pos, end.pos);
+
+    let elements = if templatize {
+        let encoded_string = template::EncodedString { source_info, chars };
+        template::templatize(encoded_string)?
+    } else {
+        let source = chars.iter().map(|(c, _, _)| c).collect::<String>();
+        let template = TemplateElement::String {
+            value: source.to_string(),
+            source: source.to_source(),
+        };
+        vec![template]
+    };
+    let template = Template::new(None, elements, source_info);
+    Ok(template)
+}
+
 fn whitespace(reader: &mut Reader) -> ParseResult<Whitespace> {
     let start = reader.cursor();
     match reader.read() {
@@ -297,7 +390,7 @@ fn language_hint(reader: &mut Reader) -> ParseResult<String> {
     Ok(hint)
 }
 
-fn graphql_variables(reader: &mut Reader) -> ParseResult<GraphQlVariables> {
+pub(crate) fn graphql_variables(reader: &mut Reader) -> ParseResult<GraphQlVariables> {
     try_literal("variables", reader)?;
     let space = zero_or_more_spaces(reader)?;
     let start = reader.cursor();
@@ -603,6 +696,123 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_heredoc_string() {
+        let mut reader = Reader::new("<<<EOF\nline1\nline2\nEOF");
+        assert_eq!(
+            heredoc_string(&mut reader).unwrap(),
+            MultilineString {
+                space: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 7)),
+                },
+                newline: Whitespace {
+                    value: "\n".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(2, 1)),
+                },
+                kind: MultilineStringKind::Raw(Template::new(
+                    None,
+                    vec![TemplateElement::String {
+                        value: "line1\nline2\n".to_string(),
+                        source: "line1\nline2\n".to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(2, 1), Pos::new(4, 1))
+                )),
+            }
+        );
+        assert_eq!(reader.cursor().index, CharPos(22));
+    }
+
+    #[test]
+    fn test_heredoc_string_not_templatized() {
+        // Without the tilde form, a template expression is kept as simple text.
+        let mut reader = Reader::new("<<<EOF\n{{name}}\nEOF");
+        let multiline = heredoc_string(&mut reader).unwrap();
+        assert_eq!(
+            multiline.kind,
+            MultilineStringKind::Raw(Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: "{{name}}\n".to_string(),
+                    source: "{{name}}\n".to_source(),
+                }],
+                SourceInfo::new(Pos::new(2, 1), Pos::new(3, 1))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_heredoc_string_templatized() {
+        let mut reader = Reader::new("<<<~EOF\n{{name}}\nEOF");
+        assert_eq!(
+            heredoc_string(&mut reader).unwrap(),
+            MultilineString {
+                space: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 8)),
+                },
+                newline: Whitespace {
+                    value: "\n".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(2, 1)),
+                },
+                kind: MultilineStringKind::Text(Template::new(
+                    None,
+                    vec![
+                        TemplateElement::Placeholder(Placeholder {
+                            space0: Whitespace {
+                                value: String::new(),
+                                source_info: SourceInfo::new(Pos::new(2, 3), Pos::new(2, 3)),
+                            },
+                            expr: Expr {
+                                source_info: SourceInfo::new(Pos::new(2, 3), Pos::new(2, 7)),
+                                kind: ExprKind::Variable(Variable {
+                                    name: "name".to_string(),
+                                    source_info: SourceInfo::new(Pos::new(2, 3), Pos::new(2, 7)),
+                                }),
+                            },
+                            space1: Whitespace {
+                                value: String::new(),
+                                source_info: SourceInfo::new(Pos::new(2, 7), Pos::new(2, 7)),
+                            },
+                        }),
+                        TemplateElement::String {
+                            value: "\n".to_string(),
+                            source: "\n".to_source(),
+                        },
+                    ],
+                    SourceInfo::new(Pos::new(2, 1), Pos::new(3, 1))
+                )),
+            }
+        );
+    }
+
+    #[test]
+    fn test_heredoc_string_error() {
+        // No delimiter
+        let mut reader = Reader::new("<<<\nline1\n");
+        let error = heredoc_string(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 4));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "heredoc delimiter".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+
+        // Unterminated bloc
+        let mut reader = Reader::new("<<<EOF\nline1\nline2\n");
+        let error = heredoc_string(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(4, 1));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "EOF".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_multiline_string_failed() {
         let data = [
@@ -17,8 +17,9 @@
  */
 use super::placeholder;
 use crate::ast::{
-    is_variable_reserved, BooleanOption, CountOption, DurationOption, EntryOption, NaturalOption,
-    OptionKind, SourceInfo, VariableDefinition, VariableValue, VerbosityOption,
+    is_variable_reserved, BooleanOption, CompressOption, CountOption, DurationOption, EntryOption,
+    IpVersionOption, NaturalOption, OptionKind, SourceInfo, VariableDefinition, VariableValue,
+    VerbosityOption, U64,
 };
 use crate::combinator::{choice, non_recover};
 use crate::parser::duration::duration;
@@ -30,7 +31,7 @@ use crate::parser::primitives::{
 use crate::parser::string::{quoted_template, unquoted_template};
 use crate::parser::{filename, filename_password, ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
-use crate::types::Count;
+use crate::types::{Count, ToSource};
 
 /// Parse an option in an `[Options]` section.
 pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
@@ -48,10 +49,13 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
     let kind = match option.as_str() {
         "aws-sigv4" => option_aws_sigv4(reader)?,
         "cacert" => option_cacert(reader)?,
+        "cacert-append" => option_cacert_append(reader)?,
         "cert" => option_cert(reader)?,
+        "compress" => option_compress(reader)?,
         "compressed" => option_compressed(reader)?,
         "connect-to" => option_connect_to(reader)?,
         "connect-timeout" => option_connect_timeout(reader)?,
+        "csv-separator" => option_csv_separator(reader)?,
         "delay" => option_delay(reader)?,
         "digest" => option_digest(reader)?,
         "insecure" => option_insecure(reader)?,
@@ -60,12 +64,14 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "http1.1" => option_http_11(reader)?,
         "http2" => option_http_2(reader)?,
         "http3" => option_http_3(reader)?,
+        "ip-version" => option_ip_version(reader)?,
         "ipv4" => option_ipv4(reader)?,
         "ipv6" => option_ipv6(reader)?,
         "key" => option_key(reader)?,
         "limit-rate" => option_limit_rate(reader)?,
         "location" => option_follow_location(reader)?,
         "location-trusted" => option_follow_location_trusted(reader)?,
+        "max-filesize" => option_max_filesize(reader)?,
         "max-redirs" => option_max_redirect(reader)?,
         "max-time" => option_max_time(reader)?,
         "negotiate" => option_negotiate(reader)?,
@@ -81,6 +87,8 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "resolve" => option_resolve(reader)?,
         "retry" => option_retry(reader)?,
         "retry-interval" => option_retry_interval(reader)?,
+        "retry-max-interval" => option_retry_max_interval(reader)?,
+        "retry-on-status" => option_retry_on_status(reader)?,
         "skip" => option_skip(reader)?,
         "unix-socket" => option_unix_socket(reader)?,
         "user" => option_user(reader)?,
@@ -88,6 +96,7 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "verbose" => option_verbose(reader)?,
         "verbosity" => option_verbosity(reader)?,
         "very-verbose" => option_very_verbose(reader)?,
+        "xpath-namespace" => option_xpath_namespace(reader)?,
         _ => {
             return Err(ParseError::new(
                 start.pos,
@@ -118,11 +127,33 @@ fn option_cacert(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::CaCertificate(value))
 }
 
+fn option_cacert_append(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::CaCertificateAppend(value))
+}
+
 fn option_cert(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename_password::parse(reader)?;
     Ok(OptionKind::ClientCert(value))
 }
 
+fn option_compress(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let start = reader.cursor();
+    let name = reader.read_while(|c| c.is_ascii_alphabetic());
+    match name.as_str() {
+        "br" => Ok(OptionKind::Compress(CompressOption::Brotli)),
+        "deflate" => Ok(OptionKind::Compress(CompressOption::Deflate)),
+        "gzip" => Ok(OptionKind::Compress(CompressOption::Gzip)),
+        _ => {
+            reader.seek(start);
+            let kind = ParseErrorKind::Expecting {
+                value: "br|deflate|gzip".to_string(),
+            };
+            Err(ParseError::new(start.pos, false, kind))
+        }
+    }
+}
+
 fn option_compressed(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Compressed(value))
@@ -138,6 +169,11 @@ fn option_connect_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::ConnectTimeout(value))
 }
 
+fn option_csv_separator(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::CsvSeparator(value))
+}
+
 fn option_delay(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = duration_option(reader)?;
     Ok(OptionKind::Delay(value))
@@ -188,6 +224,22 @@ fn option_insecure(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Insecure(value))
 }
 
+fn option_ip_version(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let start = reader.cursor();
+    let name = reader.read_while(|c| c.is_ascii_digit());
+    match name.as_str() {
+        "4" => Ok(OptionKind::IpVersion(IpVersionOption::V4)),
+        "6" => Ok(OptionKind::IpVersion(IpVersionOption::V6)),
+        _ => {
+            reader.seek(start);
+            let kind = ParseErrorKind::Expecting {
+                value: "4|6".to_string(),
+            };
+            Err(ParseError::new(start.pos, false, kind))
+        }
+    }
+}
+
 fn option_ipv4(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::IpV4(value))
@@ -204,10 +256,47 @@ fn option_key(reader: &mut Reader) -> ParseResult<OptionKind> {
 }
 
 fn option_limit_rate(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = non_recover(natural_option, reader)?;
+    let value = non_recover(bytes_option, reader)?;
     Ok(OptionKind::LimitRate(value))
 }
 
+/// Parses a number of bytes, with optional binary `k`/`m`/`g` suffixes (like curl's `--limit-rate`
+/// and `--max-filesize`): `56k` is 56 * 1024 bytes.
+fn bytes_option(reader: &mut Reader) -> ParseResult<NaturalOption> {
+    let start = reader.cursor();
+    match natural(reader) {
+        Ok(v) => {
+            let multiplier = if try_literal("k", reader).is_ok() {
+                1024
+            } else if try_literal("m", reader).is_ok() {
+                1024 * 1024
+            } else if try_literal("g", reader).is_ok() {
+                1024 * 1024 * 1024
+            } else {
+                1
+            };
+            let source = reader.read_from(start.index).to_source();
+            let value = U64::new(v.as_u64().saturating_mul(multiplier), source);
+            Ok(NaturalOption::Literal(value))
+        }
+        Err(_) => {
+            reader.seek(start);
+            let placeholder = placeholder::parse(reader).map_err(|e| {
+                let kind = ParseErrorKind::Expecting {
+                    value: "integer >= 0".to_string(),
+                };
+                ParseError::new(e.pos, false, kind)
+            })?;
+            Ok(NaturalOption::Placeholder(placeholder))
+        }
+    }
+}
+
+fn option_max_filesize(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(bytes_option, reader)?;
+    Ok(OptionKind::MaxFilesize(value))
+}
+
 fn option_max_redirect(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(count_option, reader)?;
     Ok(OptionKind::MaxRedirect(value))
@@ -283,6 +372,31 @@ fn option_retry_interval(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::RetryInterval(value))
 }
 
+fn option_retry_max_interval(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(duration_option, reader)?;
+    Ok(OptionKind::RetryMaxInterval(value))
+}
+
+/// Parses the space-separated list of status codes of a `retry-on-status` option.
+fn option_retry_on_status(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let mut statuses = vec![non_recover(natural, reader)?];
+    loop {
+        let save = reader.cursor();
+        let spaces = zero_or_more_spaces(reader)?;
+        if spaces.value.is_empty() {
+            break;
+        }
+        match natural(reader) {
+            Ok(status) => statuses.push(status),
+            Err(_) => {
+                reader.seek(save);
+                break;
+            }
+        }
+    }
+    Ok(OptionKind::RetryOnStatus(statuses))
+}
+
 fn option_skip(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Skip(value))
@@ -330,6 +444,11 @@ fn option_very_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::VeryVerbose(value))
 }
 
+fn option_xpath_namespace(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::XpathNamespace(value))
+}
+
 fn count(reader: &mut Reader) -> ParseResult<Count> {
     let start = reader.cursor();
     let value = non_recover(integer, reader)?;
@@ -362,23 +481,6 @@ fn boolean_option(reader: &mut Reader) -> ParseResult<BooleanOption> {
     }
 }
 
-fn natural_option(reader: &mut Reader) -> ParseResult<NaturalOption> {
-    let start = reader.cursor();
-    match natural(reader) {
-        Ok(v) => Ok(NaturalOption::Literal(v)),
-        Err(_) => {
-            reader.seek(start);
-            let placeholder = placeholder::parse(reader).map_err(|e| {
-                let kind = ParseErrorKind::Expecting {
-                    value: "integer >= 0".to_string(),
-                };
-                ParseError::new(e.pos, false, kind)
-            })?;
-            Ok(NaturalOption::Placeholder(placeholder))
-        }
-    }
-}
-
 fn count_option(reader: &mut Reader) -> ParseResult<CountOption> {
     let start = reader.cursor();
     match count(reader) {
@@ -655,6 +757,29 @@ mod tests {
         assert!(!error.recoverable);
     }
 
+    #[test]
+    fn test_option_cacert_append() {
+        let mut reader = Reader::new("/etc/internal-ca.pem #foo");
+
+        assert_eq!(
+            option_cacert_append(&mut reader).unwrap(),
+            OptionKind::CaCertificateAppend(Template::new(
+                None,
+                vec![TemplateElement::String {
+                    value: "/etc/internal-ca.pem".to_string(),
+                    source: "/etc/internal-ca.pem".to_source()
+                }],
+                SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos {
+                        line: 1,
+                        column: 21,
+                    },
+                },
+            )),
+        );
+    }
+
     #[test]
     fn test_option_cert() {
         let mut reader = Reader::new("/etc/client-cert.pem #foo");
@@ -678,6 +803,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_limit_rate() {
+        let mut reader = Reader::new("limit-rate: 56k\n");
+        let option = parse(&mut reader).unwrap();
+        let OptionKind::LimitRate(NaturalOption::Literal(value)) = option.kind else {
+            panic!("expecting a limit-rate option");
+        };
+        assert_eq!(value.as_u64(), 56 * 1024);
+        assert_eq!(value.to_source().as_str(), "56k");
+
+        let mut reader = Reader::new("limit-rate: 1m\n");
+        let option = parse(&mut reader).unwrap();
+        let OptionKind::LimitRate(NaturalOption::Literal(value)) = option.kind else {
+            panic!("expecting a limit-rate option");
+        };
+        assert_eq!(value.as_u64(), 1024 * 1024);
+
+        // Without suffix, the value is in bytes per second.
+        let mut reader = Reader::new("limit-rate: 8000\n");
+        let option = parse(&mut reader).unwrap();
+        let OptionKind::LimitRate(NaturalOption::Literal(value)) = option.kind else {
+            panic!("expecting a limit-rate option");
+        };
+        assert_eq!(value.as_u64(), 8000);
+    }
+
+    #[test]
+    fn test_option_max_filesize() {
+        let mut reader = Reader::new("max-filesize: 512k\n");
+        let option = parse(&mut reader).unwrap();
+        let OptionKind::MaxFilesize(NaturalOption::Literal(value)) = option.kind else {
+            panic!("expecting a max-filesize option");
+        };
+        assert_eq!(value.as_u64(), 512 * 1024);
+        assert_eq!(value.to_source().as_str(), "512k");
+
+        // Without suffix, the value is in bytes.
+        let mut reader = Reader::new("max-filesize: 1048576\n");
+        let option = parse(&mut reader).unwrap();
+        let OptionKind::MaxFilesize(NaturalOption::Literal(value)) = option.kind else {
+            panic!("expecting a max-filesize option");
+        };
+        assert_eq!(value.as_u64(), 1_048_576);
+    }
+
+    #[test]
+    fn test_option_ip_version() {
+        let mut reader = Reader::new("ip-version: 4\n");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(option.kind, OptionKind::IpVersion(IpVersionOption::V4));
+
+        let mut reader = Reader::new("ip-version: 6\n");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(option.kind, OptionKind::IpVersion(IpVersionOption::V6));
+
+        let mut reader = Reader::new("ip-version: 5\n");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "4|6".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_option_retry_error() {
         let mut reader = Reader::new("retry: ###");
@@ -17,11 +17,12 @@
  */
 use crate::ast::VersionValue::VersionAny;
 use crate::ast::{
-    Body, Entry, HurlFile, Method, Request, Response, Section, SourceInfo, Status, StatusValue,
-    Version, VersionValue,
+    Body, Bytes, Entry, HurlFile, Method, Request, Response, Section, SectionValue, SourceInfo,
+    Status, StatusValue, Version, VersionValue,
 };
 use crate::combinator::{optional, zero_or_more};
 use crate::parser::bytes::bytes;
+use crate::parser::multiline::heredoc_string;
 use crate::parser::primitives::{
     eof, key_value, line_terminator, one_or_more_spaces, optional_line_terminators, try_literal,
     zero_or_more_spaces,
@@ -47,6 +48,66 @@ pub fn hurl_file(reader: &mut Reader) -> ParseResult<HurlFile> {
     })
 }
 
+/// Parses a Hurl file, continuing after syntax errors.
+///
+/// Contrary to [`hurl_file`], this parser doesn't stop at the first syntax error: when an entry
+/// fails to parse, the error is recorded and the parser skips to the next blank line, where a new
+/// entry may start. Entries that failed to parse are absent from the returned file. The returned
+/// error list is empty if and only if the whole file has been parsed successfully.
+pub fn hurl_file_with_recovery(reader: &mut Reader) -> (HurlFile, Vec<ParseError>) {
+    let mut errors = vec![];
+    let bindings = match optional(global_bindings_section, reader) {
+        Ok(bindings) => bindings,
+        Err(error) => {
+            errors.push(error);
+            skip_to_next_entry(reader);
+            None
+        }
+    };
+    let mut entries = vec![];
+    loop {
+        let save = reader.cursor();
+        match entry(reader) {
+            Ok(entry) => entries.push(entry),
+            Err(error) if error.recoverable => {
+                reader.seek(save);
+                break;
+            }
+            Err(error) => {
+                errors.push(error);
+                skip_to_next_entry(reader);
+                if reader.is_eof() {
+                    break;
+                }
+            }
+        }
+    }
+    let line_terminators = optional_line_terminators(reader).unwrap_or_default();
+    if let Err(error) = eof(reader) {
+        errors.push(error);
+    }
+    let file = HurlFile {
+        entries,
+        line_terminators,
+        bindings,
+    };
+    (file, errors)
+}
+
+/// Advances the reader past the next blank line, a position where a new entry may start.
+fn skip_to_next_entry(reader: &mut Reader) {
+    // The remaining of the line where the error occurred is always skipped, whether blank or not.
+    let _ = reader.read_while(|c| c != '\n');
+    let _ = reader.read();
+    while !reader.is_eof() {
+        let line = reader.read_while(|c| c != '\n');
+        let _ = reader.read();
+        if line.trim().is_empty() {
+            break;
+        }
+    }
+}
+
 fn global_bindings_section(reader: &mut Reader) -> ParseResult<Section> {
     let save = reader.cursor();
 
@@ -61,7 +122,7 @@ fn global_bindings_section(reader: &mut Reader) -> ParseResult<Section> {
             reader.seek(save);
             return Err(ParseError::new(
                 start.pos,
-                false,
+                true,
                 ParseErrorKind::Expecting {
                     value: "[Bindings]".to_string(),
                 },
@@ -74,7 +135,7 @@ fn global_bindings_section(reader: &mut Reader) -> ParseResult<Section> {
         reader.seek(save);
         return Err(ParseError::new(
             start.pos,
-            false,
+            true,
             ParseErrorKind::Expecting {
                 value: "Bindings".to_string(),
             },
@@ -118,6 +179,17 @@ fn request(reader: &mut Reader) -> ParseResult<Request> {
 
     check_duplicated_sections(&sections)?;
 
+    // A [GraphQL] section composes the request body itself, an explicit body is not allowed.
+    if body.is_some() {
+        if let Some(section) = sections
+            .iter()
+            .find(|s| matches!(s.value, SectionValue::GraphQl(_)))
+        {
+            let kind = ParseErrorKind::GraphQlSectionBody;
+            return Err(ParseError::new(section.source_info.start, false, kind));
+        }
+    }
+
     Ok(Request {
         line_terminators,
         space0,
@@ -259,12 +331,23 @@ fn body(reader: &mut Reader) -> ParseResult<Body> {
     //  let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
-    let value = bytes(reader)?;
+    let start = reader.cursor();
+    let (value, is_heredoc) = match heredoc_string(reader) {
+        Ok(value) => (Bytes::MultilineString(value), true),
+        Err(error) => {
+            if !error.recoverable {
+                return Err(error);
+            }
+            reader.seek(start);
+            (bytes(reader)?, false)
+        }
+    };
     let line_terminator0 = line_terminator(reader)?;
     Ok(Body {
         line_terminators,
         space0,
         value,
+        is_heredoc,
         line_terminator0,
     })
 }
@@ -286,6 +369,22 @@ mod tests {
         assert_eq!(hurl_file.entries.len(), 1);
     }
 
+    #[test]
+    fn test_hurl_file_with_recovery() {
+        let mut reader = Reader::new("GET http://google.fr");
+        let (file, errors) = hurl_file_with_recovery(&mut reader);
+        assert_eq!(file.entries.len(), 1);
+        assert!(errors.is_empty());
+
+        // Both invalid entries are reported, the valid one is kept.
+        let mut reader = Reader::new("GET\nx-foo: bar\n\nget http://google.fr\n\nGET http://google.fr\n");
+        let (file, errors) = hurl_file_with_recovery(&mut reader);
+        assert_eq!(file.entries.len(), 1);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].pos, Pos::new(1, 4));
+        assert_eq!(errors[1].pos, Pos::new(4, 1));
+    }
+
     #[test]
     fn test_entry() {
         let mut reader = Reader::new("GET http://google.fr");
@@ -455,6 +554,7 @@ mod tests {
                         SourceInfo::new(Pos::new(3, 1), Pos::new(4, 1)),
                     )),
                 }),
+                is_heredoc: false,
                 line_terminator0: LineTerminator {
                     space0: Whitespace {
                         value: String::new(),
@@ -530,6 +630,17 @@ mod tests {
         assert_eq!(error.pos, Pos { line: 1, column: 1 });
     }
 
+    #[test]
+    fn test_request_graphql_section_with_body_error() {
+        let mut reader = Reader::new(
+            "POST http://example.org/graphql\n[GraphQL]\n{\n  me { name }\n}\n\n{\"query\": \"\"}\n",
+        );
+        let error = request(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::GraphQlSectionBody);
+        assert_eq!(error.pos, Pos { line: 2, column: 1 });
+    }
+
     #[test]
     fn test_response() {
         let mut reader = Reader::new("HTTP/1.1 200");
@@ -16,11 +16,13 @@
  *
  */
 use crate::ast::{
-    Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo, Whitespace,
+    Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SemverOperator, SortOrder,
+    SourceInfo, Whitespace,
 };
-use crate::combinator::choice;
+use crate::combinator::{choice, non_recover};
+use crate::parser::json;
 use crate::parser::predicate_value::predicate_value;
-use crate::parser::primitives::{one_or_more_spaces, try_literal, zero_or_more_spaces};
+use crate::parser::primitives::{literal, one_or_more_spaces, try_literal, zero_or_more_spaces};
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
 
@@ -71,17 +73,27 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
     let start = reader.cursor();
     match choice(
         &[
+            equal_ignorecase_predicate,
             equal_predicate,
             not_equal_predicate,
             greater_or_equal_predicate,
             greater_predicate,
             less_or_equal_predicate,
             less_predicate,
+            approximately_predicate,
+            between_predicate,
+            start_with_ignorecase_predicate,
             start_with_predicate,
+            end_with_ignorecase_predicate,
             end_with_predicate,
+            contain_ignorecase_predicate,
             contain_predicate,
             include_predicate,
+            matches_json_predicate,
+            matches_schema_predicate,
             match_predicate,
+            json_schema_predicate,
+            semver_predicate,
             integer_predicate,
             float_predicate,
             boolean_predicate,
@@ -97,6 +109,14 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             is_ipv4_predicate,
             is_ipv6_predicate,
             is_uuid_predicate,
+            is_json_predicate,
+            is_xml_predicate,
+            is_success_predicate,
+            is_redirect_predicate,
+            is_client_error_predicate,
+            is_server_error_predicate,
+            is_sorted_predicate,
+            is_unique_predicate,
         ],
         reader,
     ) {
@@ -124,6 +144,21 @@ impl PredicateValue {
     }
 }
 
+fn equal_ignorecase_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("equals-ignorecase", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::EqualIgnoreCase { space0, value })
+}
+
 fn equal_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("==", reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -202,8 +237,71 @@ fn less_or_equal_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValu
     }
 }
 
+fn approximately_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("approximately", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_number() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    let space1 = one_or_more_spaces(reader)?;
+    literal("delta", reader)?;
+    let space2 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let delta = predicate_value(reader)?;
+    if !delta.is_number() && !delta.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::Approximately {
+        space0,
+        value,
+        space1,
+        space2,
+        delta,
+    })
+}
+
+fn between_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("between", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let min = predicate_value(reader)?;
+    if !min.is_number() && !min.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    let space1 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let max = predicate_value(reader)?;
+    if !max.is_number() && !max.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::Between {
+        space0,
+        min,
+        space1,
+        max,
+    })
+}
+
 fn start_with_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
-    try_literal("startsWith", reader)?;
+    try_literal("starts-with", reader).or_else(|_| try_literal("startsWith", reader))?;
     let space0 = one_or_more_spaces(reader)?;
     let save = reader.cursor();
     let value = predicate_value(reader)?;
@@ -217,8 +315,23 @@ fn start_with_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
     Ok(PredicateFuncValue::StartWith { space0, value })
 }
 
+fn start_with_ignorecase_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("starts-with-ignorecase", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::StartWithIgnoreCase { space0, value })
+}
+
 fn end_with_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
-    try_literal("endsWith", reader)?;
+    try_literal("ends-with", reader).or_else(|_| try_literal("endsWith", reader))?;
     let space0 = one_or_more_spaces(reader)?;
     let save = reader.cursor();
     let value = predicate_value(reader)?;
@@ -232,6 +345,36 @@ fn end_with_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::EndWith { space0, value })
 }
 
+fn end_with_ignorecase_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("ends-with-ignorecase", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::EndWithIgnoreCase { space0, value })
+}
+
+fn contain_ignorecase_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("contains-ignorecase", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::ContainIgnoreCase { space0, value })
+}
+
 fn contain_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("contains", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -261,6 +404,87 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Match { space0, value })
 }
 
+fn matches_json_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("matches_json", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = non_recover(json::parse, reader)?;
+    Ok(PredicateFuncValue::MatchesJson { space0, value })
+}
+
+fn json_schema_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("jsonschema", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::JsonSchema { space0, value })
+}
+
+fn matches_schema_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("matches-schema", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::MatchesSchema { space0, value })
+}
+
+fn semver_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("semver", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let operator = semver_operator(reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::Semver {
+        space0,
+        operator,
+        space1,
+        value,
+    })
+}
+
+fn semver_operator(reader: &mut Reader) -> ParseResult<SemverOperator> {
+    // Two chars operators are tried first so that `>=` is not parsed as `>`.
+    if try_literal(">=", reader).is_ok() {
+        Ok(SemverOperator::GreaterThanOrEqual)
+    } else if try_literal("<=", reader).is_ok() {
+        Ok(SemverOperator::LessThanOrEqual)
+    } else if try_literal("==", reader).is_ok() {
+        Ok(SemverOperator::Equal)
+    } else if try_literal("!=", reader).is_ok() {
+        Ok(SemverOperator::NotEqual)
+    } else if try_literal(">", reader).is_ok() {
+        Ok(SemverOperator::GreaterThan)
+    } else if try_literal("<", reader).is_ok() {
+        Ok(SemverOperator::LessThan)
+    } else {
+        let kind = ParseErrorKind::Expecting {
+            value: "comparison operator".to_string(),
+        };
+        Err(ParseError::new(reader.cursor().pos, false, kind))
+    }
+}
+
 fn integer_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("isInteger", reader)?;
     Ok(PredicateFuncValue::IsInteger)
@@ -326,6 +550,61 @@ fn is_uuid_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::IsUuid)
 }
 
+fn is_json_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("is-json", reader)?;
+    Ok(PredicateFuncValue::IsJson)
+}
+
+fn is_xml_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("is-xml", reader)?;
+    Ok(PredicateFuncValue::IsXml)
+}
+
+fn is_success_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("is-success", reader)?;
+    Ok(PredicateFuncValue::IsSuccess)
+}
+
+fn is_redirect_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("is-redirect", reader)?;
+    Ok(PredicateFuncValue::IsRedirect)
+}
+
+fn is_client_error_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("is-client-error", reader)?;
+    Ok(PredicateFuncValue::IsClientError)
+}
+
+fn is_server_error_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("is-server-error", reader)?;
+    Ok(PredicateFuncValue::IsServerError)
+}
+
+fn is_sorted_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("is_sorted", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let order = sort_order(reader)?;
+    Ok(PredicateFuncValue::IsSorted { space0, order })
+}
+
+fn sort_order(reader: &mut Reader) -> ParseResult<SortOrder> {
+    if try_literal("asc", reader).is_ok() {
+        Ok(SortOrder::Asc)
+    } else if try_literal("desc", reader).is_ok() {
+        Ok(SortOrder::Desc)
+    } else {
+        let kind = ParseErrorKind::Expecting {
+            value: "Order <asc> or <desc>".to_string(),
+        };
+        Err(ParseError::new(reader.cursor().pos, false, kind))
+    }
+}
+
+fn is_unique_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("is_unique", reader)?;
+    Ok(PredicateFuncValue::IsUnique)
+}
+
 fn is_list_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("isList", reader)?;
     Ok(PredicateFuncValue::IsList)
@@ -408,6 +687,64 @@ mod tests {
         assert_eq!(error.kind, ParseErrorKind::Predicate);
     }
 
+    #[test]
+    fn test_equal_ignorecase_predicate() {
+        let mut reader = Reader::new("equals-ignorecase \"Text/HTML\"");
+        let PredicateFuncValue::EqualIgnoreCase { value, .. } =
+            equal_ignorecase_predicate(&mut reader).unwrap()
+        else {
+            panic!("expected an equals-ignorecase predicate");
+        };
+        assert!(value.is_string());
+
+        // Only strings can be compared ignoring case.
+        let mut reader = Reader::new("equals-ignorecase true");
+        let error = equal_ignorecase_predicate(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+
+        let mut reader = Reader::new("contains-ignorecase \"hello\"");
+        let PredicateFuncValue::ContainIgnoreCase { value, .. } =
+            contain_ignorecase_predicate(&mut reader).unwrap()
+        else {
+            panic!("expected a contains-ignorecase predicate");
+        };
+        assert!(value.is_string());
+    }
+
+    #[test]
+    fn test_start_with_ignorecase_predicate() {
+        // Both the camelCase and the kebab-case spellings are accepted.
+        let mut reader = Reader::new("starts-with \"application/\"");
+        let PredicateFuncValue::StartWith { value, .. } = start_with_predicate(&mut reader).unwrap()
+        else {
+            panic!("expected a starts-with predicate");
+        };
+        assert!(value.is_string());
+
+        let mut reader = Reader::new("starts-with-ignorecase \"Application/\"");
+        let PredicateFuncValue::StartWithIgnoreCase { value, .. } =
+            start_with_ignorecase_predicate(&mut reader).unwrap()
+        else {
+            panic!("expected a starts-with-ignorecase predicate");
+        };
+        assert!(value.is_string());
+
+        let mut reader = Reader::new("ends-with-ignorecase \"World!\"");
+        let PredicateFuncValue::EndWithIgnoreCase { value, .. } =
+            end_with_ignorecase_predicate(&mut reader).unwrap()
+        else {
+            panic!("expected an ends-with-ignorecase predicate");
+        };
+        assert!(value.is_string());
+
+        // Only strings can be compared ignoring case.
+        let mut reader = Reader::new("starts-with-ignorecase hex,12;");
+        let error = start_with_ignorecase_predicate(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
     #[test]
     fn test_equal_predicate() {
         let mut reader = Reader::new("==  true");
@@ -512,10 +849,153 @@ mod tests {
         assert_eq!(error.kind, ParseErrorKind::PredicateValue);
     }
 
+    #[test]
+    fn test_matches_schema_predicate() {
+        let mut reader = Reader::new("matches-schema \"schema.json\" xxx");
+        let result = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(result, PredicateFuncValue::MatchesSchema { .. }));
+        assert_eq!(reader.cursor().pos, Pos::new(1, 29));
+
+        // `matches-schema` must not be swallowed by the `matches` predicate.
+        let mut reader = Reader::new("matches \"a.*b\"");
+        let result = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(result, PredicateFuncValue::Match { .. }));
+    }
+
     #[test]
     fn test_date_predicate() {
         let mut reader = Reader::new("isDate");
         let result = date_predicate(&mut reader);
         assert_eq!(result.unwrap(), PredicateFuncValue::IsDate);
     }
+
+    #[test]
+    fn test_is_json_predicate() {
+        let mut reader = Reader::new("is-json");
+        let result = is_json_predicate(&mut reader);
+        assert_eq!(result.unwrap(), PredicateFuncValue::IsJson);
+    }
+
+    #[test]
+    fn test_is_xml_predicate() {
+        let mut reader = Reader::new("is-xml");
+        let result = is_xml_predicate(&mut reader);
+        assert_eq!(result.unwrap(), PredicateFuncValue::IsXml);
+    }
+
+    #[test]
+    fn test_status_class_predicates() {
+        let mut reader = Reader::new("is-success");
+        let result = is_success_predicate(&mut reader);
+        assert_eq!(result.unwrap(), PredicateFuncValue::IsSuccess);
+
+        let mut reader = Reader::new("is-redirect");
+        let result = is_redirect_predicate(&mut reader);
+        assert_eq!(result.unwrap(), PredicateFuncValue::IsRedirect);
+
+        let mut reader = Reader::new("is-client-error");
+        let result = is_client_error_predicate(&mut reader);
+        assert_eq!(result.unwrap(), PredicateFuncValue::IsClientError);
+
+        let mut reader = Reader::new("is-server-error");
+        let result = is_server_error_predicate(&mut reader);
+        assert_eq!(result.unwrap(), PredicateFuncValue::IsServerError);
+    }
+
+    #[test]
+    fn test_semver_predicate() {
+        let mut reader = Reader::new("semver >= \"2.0.0\"");
+        assert_eq!(
+            semver_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::Semver {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                operator: SemverOperator::GreaterThanOrEqual,
+                space1: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                value: PredicateValue::String(Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: "2.0.0".to_string(),
+                        source: "2.0.0".to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(1, 11), Pos::new(1, 18)),
+                )),
+            }
+        );
+
+        let mut reader = Reader::new("semver 2");
+        let error = semver_predicate(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "comparison operator".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_approximately_predicate() {
+        let mut reader = Reader::new("approximately 19.99 delta 0.01");
+        assert_eq!(
+            approximately_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::Approximately {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 15)),
+                },
+                value: PredicateValue::Number(Number::Float(Float::new(
+                    19.99,
+                    "19.99".to_source()
+                ))),
+                space1: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 20), Pos::new(1, 21)),
+                },
+                space2: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 26), Pos::new(1, 27)),
+                },
+                delta: PredicateValue::Number(Number::Float(Float::new(
+                    0.01,
+                    "0.01".to_source()
+                ))),
+            }
+        );
+
+        let mut reader = Reader::new("approximately \"a\" delta 0.01");
+        let error = approximately_predicate(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
+    #[test]
+    fn test_between_predicate() {
+        let mut reader = Reader::new("between 18 65");
+        assert_eq!(
+            between_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::Between {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                min: PredicateValue::Number(Number::Integer(I64::new(18, "18".to_source()))),
+                space1: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 12)),
+                },
+                max: PredicateValue::Number(Number::Integer(I64::new(65, "65".to_source()))),
+            }
+        );
+
+        let mut reader = Reader::new("between \"a\" 65");
+        let error = between_predicate(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
 }
@@ -289,7 +289,38 @@ pub fn regex(reader: &mut Reader) -> ParseResult<Regex> {
             c => unescaped.push(c),
         }
     }
-    match regex::Regex::new(unescaped.as_str()) {
+
+    // Perl-style inline flags after the closing `/`: `/pattern/ms` enables
+    // multiline and dot-all matching.
+    let mut case_insensitive = false;
+    let mut multi_line = false;
+    let mut dot_matches_new_line = false;
+    while let Some(c) = reader.peek() {
+        if !c.is_ascii_alphanumeric() {
+            break;
+        }
+        let flag_pos = reader.cursor().pos;
+        reader.read();
+        source.push(c);
+        match c {
+            'i' => case_insensitive = true,
+            'm' => multi_line = true,
+            's' => dot_matches_new_line = true,
+            _ => {
+                let kind = ParseErrorKind::RegexExpr {
+                    message: format!("invalid regex flag <{c}>, valid flags are i, m and s"),
+                };
+                return Err(ParseError::new(flag_pos, false, kind));
+            }
+        }
+    }
+
+    let result = regex::RegexBuilder::new(unescaped.as_str())
+        .case_insensitive(case_insensitive)
+        .multi_line(multi_line)
+        .dot_matches_new_line(dot_matches_new_line)
+        .build();
+    match result {
         Ok(inner) => Ok(Regex { inner, source }),
         Err(e) => {
             let message = match e {
@@ -861,6 +892,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_regex_flags() {
+        // `m` enables multiline matching, `s` dot-all matching.
+        let mut reader = Reader::new("/^Error:.*Internal/ms");
+        let value = regex(&mut reader).unwrap();
+        assert_eq!(value.source, "/^Error:.*Internal/ms".to_source());
+        assert!(value
+            .inner
+            .is_match("Error: failure\nInternal Server Error"));
+
+        // `i` enables case-insensitive matching.
+        let mut reader = Reader::new("/hello/i");
+        let value = regex(&mut reader).unwrap();
+        assert_eq!(value.source, "/hello/i".to_source());
+        assert!(value.inner.is_match("HELLO"));
+
+        // Without flags, `.` doesn't match a newline and `^` only the start of the text.
+        let mut reader = Reader::new("/^Error:.*Internal/");
+        let value = regex(&mut reader).unwrap();
+        assert!(!value
+            .inner
+            .is_match("Error: failure\nInternal Server Error"));
+    }
+
+    #[test]
+    fn test_regex_invalid_flag() {
+        let mut reader = Reader::new("/hello/x");
+        let error = regex(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::RegexExpr {
+                message: "invalid regex flag <x>, valid flags are i, m and s".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_regex_error() {
         let mut reader = Reader::new("xxx");
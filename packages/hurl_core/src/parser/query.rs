@@ -15,9 +15,13 @@
  * limitations under the License.
  *
  */
-use crate::ast::{CertificateAttributeName, Query, QueryValue, RegexValue, SourceInfo};
+use crate::ast::{
+    CertificateAttributeName, CsvColumn, DigestAlgorithm, DurationPhase, DurationPhaseName,
+    NdjsonSelector, Query, QueryValue, RegexValue, SourceInfo,
+};
 use crate::combinator::{choice, ParseError as ParseErrorTrait};
 use crate::parser::cookiepath::cookiepath;
+use crate::parser::number::natural;
 use crate::parser::primitives::{literal, one_or_more_spaces, regex, try_literal};
 use crate::parser::string::{quoted_oneline_string, quoted_template};
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
@@ -40,10 +44,14 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
             version_query,
             url_query,
             header_query,
+            link_header_query,
             cookie_query,
+            body_ndjson_query,
+            body_digest_query,
             body_query,
             xpath_query,
             jsonpath_query,
+            csv_query,
             regex_query,
             variable_query,
             duration_query,
@@ -81,6 +89,13 @@ fn header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Header { space0, name })
 }
 
+fn link_header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("link-header", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let rel = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::LinkHeader { space0, rel })
+}
+
 fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("cookie", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -104,6 +119,74 @@ fn body_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Body)
 }
 
+fn body_ndjson_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("body", reader)?;
+    // A missing space must stay recoverable: `body` alone is a valid plain body query.
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_recoverable())?;
+    try_literal("ndjson", reader)?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let selector = ndjson_selector(reader)?;
+    Ok(QueryValue::Ndjson {
+        space0,
+        space1,
+        selector,
+    })
+}
+
+fn ndjson_selector(reader: &mut Reader) -> ParseResult<NdjsonSelector> {
+    if try_literal("count", reader).is_ok() {
+        return Ok(NdjsonSelector::Count);
+    }
+    literal("line", reader)?;
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let line = natural(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    literal("jsonpath", reader)?;
+    let space2 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(NdjsonSelector::Line {
+        space0,
+        line,
+        space1,
+        space2,
+        expr,
+    })
+}
+
+fn body_digest_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("body", reader)?;
+    // A missing space must stay recoverable: `body` alone is a valid plain body query.
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_recoverable())?;
+    let algorithm = digest_algorithm(reader)?;
+    Ok(QueryValue::BodyDigest { space0, algorithm })
+}
+
+fn digest_algorithm(reader: &mut Reader) -> ParseResult<DigestAlgorithm> {
+    // Encoded variants are tried first so that `sha256` doesn't shadow `sha256-hex`.
+    if try_literal("sha256-hex", reader).is_ok() {
+        Ok(DigestAlgorithm::Sha256Hex)
+    } else if try_literal("sha256-base64", reader).is_ok() {
+        Ok(DigestAlgorithm::Sha256Base64)
+    } else if try_literal("sha256", reader).is_ok() {
+        Ok(DigestAlgorithm::Sha256)
+    } else if try_literal("md5-hex", reader).is_ok() {
+        Ok(DigestAlgorithm::Md5Hex)
+    } else if try_literal("md5-base64", reader).is_ok() {
+        Ok(DigestAlgorithm::Md5Base64)
+    } else if try_literal("md5", reader).is_ok() {
+        Ok(DigestAlgorithm::Md5)
+    } else {
+        // A recoverable error: `body` followed by something else than a digest
+        // algorithm is parsed as a plain `body` query.
+        let value =
+            "Algorithm <sha256>, <sha256-hex>, <sha256-base64>, <md5>, <md5-hex>, or <md5-base64>"
+                .to_string();
+        let kind = ParseErrorKind::Expecting { value };
+        let cur = reader.cursor();
+        Err(ParseError::new(cur.pos, true, kind))
+    }
+}
+
 fn xpath_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("xpath", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -132,6 +215,41 @@ fn jsonpath_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Jsonpath { space0, expr })
 }
 
+fn csv_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("csv", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    literal("col", reader)?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let column = csv_column(reader)?;
+    let space2 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    literal("row", reader)?;
+    let space3 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let row = natural(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::Csv {
+        space0,
+        space1,
+        column,
+        space2,
+        space3,
+        row,
+    })
+}
+
+/// Parses the column selector of a `csv` query: either a 0-based index (`col 0`) or a quoted
+/// column name (`col "name"`).
+fn csv_column(reader: &mut Reader) -> ParseResult<CsvColumn> {
+    let save = reader.cursor();
+    match natural(reader) {
+        Ok(index) => Ok(CsvColumn::Index(index)),
+        Err(e) if e.recoverable => {
+            reader.seek(save);
+            let name = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+            Ok(CsvColumn::Name(name))
+        }
+        Err(e) => Err(e.to_non_recoverable()),
+    }
+}
+
 fn regex_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("regex", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -170,7 +288,39 @@ fn variable_query(reader: &mut Reader) -> ParseResult<QueryValue> {
 
 fn duration_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("duration", reader)?;
-    Ok(QueryValue::Duration)
+    // The phase is optional: without it, the query returns the total time of the transfer.
+    let save = reader.cursor();
+    let phase = match one_or_more_spaces(reader) {
+        Ok(space0) => match duration_phase_name(reader) {
+            Ok(name) => Some(DurationPhase { space0, name }),
+            Err(_) => {
+                reader.seek(save);
+                None
+            }
+        },
+        Err(_) => {
+            reader.seek(save);
+            None
+        }
+    };
+    Ok(QueryValue::Duration { phase })
+}
+
+fn duration_phase_name(reader: &mut Reader) -> ParseResult<DurationPhaseName> {
+    if try_literal("dns", reader).is_ok() {
+        Ok(DurationPhaseName::Dns)
+    } else if try_literal("connect", reader).is_ok() {
+        Ok(DurationPhaseName::Connect)
+    } else if try_literal("first-byte", reader).is_ok() {
+        Ok(DurationPhaseName::FirstByte)
+    } else if try_literal("total", reader).is_ok() {
+        Ok(DurationPhaseName::Total)
+    } else {
+        let value = "Phase <dns>, <connect>, <first-byte>, or <total>".to_string();
+        let kind = ParseErrorKind::Expecting { value };
+        let cur = reader.cursor();
+        Err(ParseError::new(cur.pos, true, kind))
+    }
 }
 
 fn bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
@@ -227,9 +377,11 @@ fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeNam
         Ok(CertificateAttributeName::SerialNumber)
     } else if try_literal(r#"Subject-Alt-Name""#, reader).is_ok() {
         Ok(CertificateAttributeName::SubjectAltName)
+    } else if try_literal(r#"Version""#, reader).is_ok() {
+        Ok(CertificateAttributeName::Version)
     } else {
         let value =
-            "Field <Subject>, <Issuer>, <Start-Date>, <Expire-Date>, <Serial-Number>, or <Subject-Alt-Name>".to_string();
+            "Field <Subject>, <Issuer>, <Start-Date>, <Expire-Date>, <Serial-Number>, <Subject-Alt-Name>, or <Version>".to_string();
         let kind = ParseErrorKind::Expecting { value };
         let cur = reader.cursor();
         Err(ParseError::new(cur.pos, false, kind))
@@ -241,7 +393,7 @@ mod tests {
     use super::*;
     use crate::ast::{
         CookieAttribute, CookieAttributeName, CookiePath, Filter, FilterValue, Template,
-        TemplateElement, Whitespace,
+        TemplateElement, U64, Whitespace,
     };
     use crate::parser::filter::filters;
     use crate::reader::{CharPos, Pos};
@@ -293,6 +445,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_link_header_query() {
+        let mut reader = Reader::new("link-header \"next\"");
+        assert_eq!(
+            link_header_query(&mut reader).unwrap(),
+            QueryValue::LinkHeader {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                },
+                rel: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: "next".to_string(),
+                        source: "next".to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(1, 13), Pos::new(1, 19))
+                )
+            }
+        );
+    }
+
+    #[test]
+    fn test_body_digest_query() {
+        let mut reader = Reader::new("body sha256");
+        assert_eq!(
+            query(&mut reader).unwrap().value,
+            QueryValue::BodyDigest {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+                algorithm: DigestAlgorithm::Sha256,
+            }
+        );
+
+        let mut reader = Reader::new("body sha256-hex");
+        assert_eq!(
+            query(&mut reader).unwrap().value,
+            QueryValue::BodyDigest {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+                algorithm: DigestAlgorithm::Sha256Hex,
+            }
+        );
+
+        let mut reader = Reader::new("body md5-base64");
+        assert_eq!(
+            query(&mut reader).unwrap().value,
+            QueryValue::BodyDigest {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+                algorithm: DigestAlgorithm::Md5Base64,
+            }
+        );
+
+        // `body` followed by something else than a digest algorithm is a plain body query.
+        let mut reader = Reader::new("body == \"xx\"");
+        assert_eq!(query(&mut reader).unwrap().value, QueryValue::Body);
+    }
+
+    #[test]
+    fn test_body_ndjson_query() {
+        let mut reader = Reader::new("body ndjson count");
+        assert_eq!(
+            query(&mut reader).unwrap().value,
+            QueryValue::Ndjson {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+                space1: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                },
+                selector: NdjsonSelector::Count,
+            }
+        );
+
+        let mut reader = Reader::new("body ndjson line 0 jsonpath \"$.event\"");
+        assert_eq!(
+            query(&mut reader).unwrap().value,
+            QueryValue::Ndjson {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+                space1: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                },
+                selector: NdjsonSelector::Line {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 17), Pos::new(1, 18)),
+                    },
+                    line: U64::new(0, "0".to_source()),
+                    space1: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 19), Pos::new(1, 20)),
+                    },
+                    space2: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 28), Pos::new(1, 29)),
+                    },
+                    expr: Template::new(
+                        Some('"'),
+                        vec![TemplateElement::String {
+                            value: "$.event".to_string(),
+                            source: "$.event".to_source(),
+                        }],
+                        SourceInfo::new(Pos::new(1, 29), Pos::new(1, 38))
+                    ),
+                },
+            }
+        );
+    }
+
     #[test]
     fn test_cookie_query() {
         let mut reader = Reader::new("cookie \"Foo[Domain]\"");
@@ -409,6 +683,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_csv_query() {
+        let mut reader = Reader::new("csv col 0 row 1");
+        assert_eq!(
+            csv_query(&mut reader).unwrap(),
+            QueryValue::Csv {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 5)),
+                },
+                space1: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                column: CsvColumn::Index(U64::new(0, "0".to_source())),
+                space2: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                space3: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 15)),
+                },
+                row: U64::new(1, "1".to_source()),
+            }
+        );
+
+        let mut reader = Reader::new("csv col \"name\" row 0");
+        let QueryValue::Csv { column, row, .. } = csv_query(&mut reader).unwrap() else {
+            panic!("expected a csv query");
+        };
+        assert_eq!(
+            column,
+            CsvColumn::Name(Template::new(
+                Some('"'),
+                vec![TemplateElement::String {
+                    value: "name".to_string(),
+                    source: "name".to_source(),
+                }],
+                SourceInfo::new(Pos::new(1, 9), Pos::new(1, 15))
+            ))
+        );
+        assert_eq!(row, U64::new(0, "0".to_source()));
+
+        let mut reader = Reader::new("csv col name row 0");
+        assert!(csv_query(&mut reader).is_err());
+    }
+
     #[test]
     fn test_query_with_filters() {
         let mut reader = Reader::new("body urlDecode ");
@@ -17,18 +17,19 @@
  */
 use crate::ast::{
     Assert, BindingExpr, BindingParam, Capture, Cookie, FilenameParam, FilenameValue,
-    MultipartParam, Section, SectionValue, SourceInfo, Whitespace,
+    GraphQlSection, MultipartParam, Section, SectionValue, SourceInfo, Tag, Template, Whitespace,
 };
 use crate::combinator::{optional, recover, zero_or_more};
 use crate::parser::filter::filters;
+use crate::parser::multiline::graphql_variables;
 use crate::parser::predicate::predicate;
 use crate::parser::primitives::{
     key_value, line_terminator, literal, one_or_more_spaces, optional_line_terminators,
     try_literal, zero_or_more_spaces,
 };
 use crate::parser::query::query;
-use crate::parser::string::{quoted_template, unquoted_template};
-use crate::parser::{filename, key_string, option, ParseError, ParseErrorKind, ParseResult};
+use crate::parser::string::{quoted_template, unquoted_template, unquoted_template_until};
+use crate::parser::{filename, key_string, option, template, ParseError, ParseErrorKind, ParseResult};
 use crate::reader::{Pos, Reader};
 
 pub fn request_sections(reader: &mut Reader) -> ParseResult<Vec<Section>> {
@@ -59,6 +60,8 @@ pub fn request_section(reader: &mut Reader) -> ParseResult<Section> {
         "MultipartFormData" => section_value_multipart_form_data(reader, false)?,
         "Cookies" => section_value_cookies(reader)?,
         "Options" => section_value_options(reader)?,
+        "Tags" => section_value_tags(reader)?,
+        "GraphQL" => section_value_graphql(reader)?,
         "Bindings" => {
             let kind = ParseErrorKind::RequestSectionName { name: "Bindings".to_string() };
             let pos = Pos::new(start.pos.line, start.pos.column + 1);
@@ -166,11 +169,141 @@ fn section_value_options(reader: &mut Reader) -> ParseResult<SectionValue> {
     Ok(SectionValue::Options(options))
 }
 
+fn section_value_tags(reader: &mut Reader) -> ParseResult<SectionValue> {
+    let tags = zero_or_more(tag, reader)?;
+    Ok(SectionValue::Tags(tags))
+}
+
 pub fn section_value_sync(reader: &mut Reader) -> ParseResult<SectionValue> {
     let items = zero_or_more(binding_param, reader)?;
     Ok(SectionValue::Bindings(items))
 }
 
+/// Parses a `[GraphQL]` section: a query written verbatim, optionally followed by a `variables`
+/// JSON object and an `operationName` line.
+///
+/// The query ends on the first empty line, so the section can not swallow the response spec or
+/// the next entry of the file.
+fn section_value_graphql(reader: &mut Reader) -> ParseResult<SectionValue> {
+    let start = reader.cursor();
+    let mut chars = vec![];
+    let mut end = reader.cursor();
+    let mut variables = None;
+    let mut operation_name = None;
+    while !reader.is_eof() {
+        if let Some(vars) = optional(graphql_variables, reader)? {
+            variables = Some(vars);
+            break;
+        }
+        if let Some(name) = optional(graphql_operation_name, reader)? {
+            operation_name = Some(name);
+            break;
+        }
+        if graphql_query_end(reader) {
+            break;
+        }
+        loop {
+            let pos = reader.cursor().pos;
+            let Some(c) = reader.read() else {
+                break;
+            };
+            chars.push((c, c.to_string(), pos));
+            if c == '\n' {
+                break;
+            }
+        }
+        end = reader.cursor();
+    }
+    if chars.is_empty() {
+        let kind = ParseErrorKind::Expecting {
+            value: "a GraphQL query".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    let encoded_string = template::EncodedString {
+        source_info: SourceInfo::new(start.pos, end.pos),
+        chars,
+    };
+    let elements = template::templatize(encoded_string)?;
+    let query = Template::new(None, elements, SourceInfo::new(start.pos, end.pos));
+
+    // `variables` and `operationName` can come in any order, the one found while reading the
+    // query has already been consumed.
+    loop {
+        if variables.is_none() {
+            if let Some(vars) = optional(graphql_variables, reader)? {
+                variables = Some(vars);
+                continue;
+            }
+        }
+        if operation_name.is_none() {
+            if let Some(name) = optional(graphql_operation_name, reader)? {
+                operation_name = Some(name);
+                continue;
+            }
+        }
+        break;
+    }
+    Ok(SectionValue::GraphQl(GraphQlSection {
+        query,
+        variables,
+        operation_name,
+    }))
+}
+
+/// Returns true if the line under the reader ends the query of a `[GraphQL]` section.
+fn graphql_query_end(reader: &mut Reader) -> bool {
+    let save = reader.cursor();
+    let line = reader.read_while(|c| c != '\n' && c != '\r');
+    reader.seek(save);
+    let line = line.trim();
+    line.is_empty() || line.starts_with('[') || line.starts_with("HTTP")
+}
+
+fn graphql_operation_name(reader: &mut Reader) -> ParseResult<Template> {
+    try_literal("operationName", reader)?;
+    let _ = zero_or_more_spaces(reader)?;
+    literal(":", reader)?;
+    let _ = zero_or_more_spaces(reader)?;
+    let value = unquoted_template(reader)?;
+    line_terminator(reader)?;
+    Ok(value)
+}
+
+fn tag(reader: &mut Reader) -> ParseResult<Tag> {
+    let save = reader.cursor();
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let start = reader.cursor();
+    let name = reader.read_while(|c| c.is_alphanumeric() || c == '_' || c == '-');
+    if name.is_empty() {
+        let kind = ParseErrorKind::Expecting {
+            value: "a tag".to_string(),
+        };
+        reader.seek(save);
+        return Err(ParseError::new(start.pos, true, kind));
+    }
+    let source_info = SourceInfo::new(start.pos, reader.cursor().pos);
+    // A tag is alone on its line: if something else follows, this is not a tag line (it can be
+    // the method of the next entry for instance), we give the input back.
+    match line_terminator(reader) {
+        Ok(line_terminator0) => Ok(Tag {
+            line_terminators,
+            space0,
+            name,
+            source_info,
+            line_terminator0,
+        }),
+        Err(_) => {
+            let kind = ParseErrorKind::Expecting {
+                value: "a tag".to_string(),
+            };
+            reader.seek(save);
+            Err(ParseError::new(start.pos, true, kind))
+        }
+    }
+}
+
 fn cookie(reader: &mut Reader) -> ParseResult<Cookie> {
     // let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
@@ -235,7 +368,7 @@ fn file_value(reader: &mut Reader) -> ParseResult<FilenameValue> {
     let space1 = zero_or_more_spaces(reader)?;
     literal(";", reader)?;
     let save = reader.cursor();
-    let (space2, content_type) = match line_terminator(reader) {
+    let (space2, content_type, filename_override) = match line_terminator(reader) {
         Ok(_) => {
             reader.seek(save);
             let space2 = Whitespace {
@@ -245,20 +378,13 @@ fn file_value(reader: &mut Reader) -> ParseResult<FilenameValue> {
                     end: save.pos,
                 },
             };
-            (space2, None)
+            (space2, None, None)
         }
         Err(_) => {
             reader.seek(save);
             let space2 = zero_or_more_spaces(reader)?;
-            let start = reader.cursor();
-            let Ok(content_type) = unquoted_template(reader) else {
-                return Err(ParseError::new(
-                    start.pos,
-                    false,
-                    ParseErrorKind::FileContentType,
-                ));
-            };
-            (space2, Some(content_type))
+            let (content_type, filename_override) = file_annotations(reader)?;
+            (space2, content_type, filename_override)
         }
     };
 
@@ -268,9 +394,68 @@ fn file_value(reader: &mut Reader) -> ParseResult<FilenameValue> {
         space1,
         space2,
         content_type,
+        filename_override,
     })
 }
 
+/// Parses the annotations of a file value, after the first `;`.
+///
+/// Annotations are either keyed (`type: image/webp`, `filename: override.jpg`), separated by `;`,
+/// or the historic bare content type form which runs to the end of line (a bare content type can
+/// itself contain `;`, like `text/plain; charset=us-ascii`).
+fn file_annotations(reader: &mut Reader) -> ParseResult<(Option<Template>, Option<Template>)> {
+    let mut content_type = None;
+    let mut filename_override = None;
+    loop {
+        let start = reader.cursor();
+        if try_literal("type:", reader).is_ok() {
+            zero_or_more_spaces(reader)?;
+            let Ok(value) = unquoted_template_until(reader, &[';']) else {
+                return Err(ParseError::new(
+                    reader.cursor().pos,
+                    false,
+                    ParseErrorKind::FileContentType,
+                ));
+            };
+            content_type = Some(value);
+        } else if try_literal("filename:", reader).is_ok() {
+            zero_or_more_spaces(reader)?;
+            let Ok(value) = unquoted_template_until(reader, &[';']) else {
+                return Err(ParseError::new(
+                    reader.cursor().pos,
+                    false,
+                    ParseErrorKind::Filename,
+                ));
+            };
+            filename_override = Some(value);
+        } else if content_type.is_none() && filename_override.is_none() {
+            let Ok(value) = unquoted_template(reader) else {
+                return Err(ParseError::new(
+                    start.pos,
+                    false,
+                    ParseErrorKind::FileContentType,
+                ));
+            };
+            content_type = Some(value);
+            break;
+        } else {
+            return Err(ParseError::new(
+                start.pos,
+                false,
+                ParseErrorKind::FileContentType,
+            ));
+        }
+        let save = reader.cursor();
+        zero_or_more_spaces(reader)?;
+        if try_literal(";", reader).is_err() {
+            reader.seek(save);
+            break;
+        }
+        zero_or_more_spaces(reader)?;
+    }
+    Ok((content_type, filename_override))
+}
+
 fn capture(reader: &mut Reader) -> ParseResult<Capture> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -357,31 +542,52 @@ fn binding_param(reader: &mut Reader) -> ParseResult<BindingParam> {
 fn binding_expr(reader: &mut Reader) -> ParseResult<BindingExpr> {
     let save = reader.cursor();
 
-    if literal("file", reader).is_ok() {
+    if try_literal("jsonfile", reader).is_ok() {
         let space0 = zero_or_more_spaces(reader)?;
+        let filename = binding_filename(reader)?;
+        return Ok(BindingExpr::JsonFile { space0, filename });
+    }
 
-        // Try different template types:
-        // 1. Quoted: ".token"
-        // 2. Filename: .token or file\ with\ spaces.txt
-        // 3. Unquoted template: {env}/.token
-        let filename = if let Ok(template) = quoted_template(reader) {
-            template
-        } else if let Ok(template) = filename::parse(reader) {
-            template
-        } else {
-            unquoted_template(reader)?
-        };
+    if try_literal("yamlfile", reader).is_ok() {
+        let space0 = zero_or_more_spaces(reader)?;
+        let filename = binding_filename(reader)?;
+        return Ok(BindingExpr::YamlFile { space0, filename });
+    }
+
+    if try_literal("file", reader).is_ok() {
+        let space0 = zero_or_more_spaces(reader)?;
+        let filename = binding_filename(reader)?;
         return Ok(BindingExpr::File { space0, filename });
     }
 
+    if try_literal("env", reader).is_ok() {
+        let space0 = zero_or_more_spaces(reader)?;
+        let var_name = binding_filename(reader)?;
+        return Ok(BindingExpr::Env { space0, var_name });
+    }
+
     // No valid sync expression found
     reader.seek(save);
     let kind = ParseErrorKind::Expecting {
-        value: "sync expression (file, ...)".to_string(),
+        value: "sync expression (file, jsonfile, yamlfile, env, ...)".to_string(),
     };
     Err(ParseError::new(reader.cursor().pos, false, kind))
 }
 
+fn binding_filename(reader: &mut Reader) -> ParseResult<Template> {
+    // Try different template types:
+    // 1. Quoted: ".token"
+    // 2. Filename: .token or file\ with\ spaces.txt
+    // 3. Unquoted template: {env}/.token
+    if let Ok(template) = quoted_template(reader) {
+        Ok(template)
+    } else if let Ok(template) = filename::parse(reader) {
+        Ok(template)
+    } else {
+        unquoted_template(reader)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -583,6 +789,7 @@ mod tests {
                     source_info: SourceInfo::new(Pos::new(1, 16), Pos::new(1, 16)),
                 },
                 content_type: None,
+                filename_override: None,
             }
         );
         let mut reader = Reader::new("file,hello.txt; text/html");
@@ -617,6 +824,7 @@ mod tests {
                     }],
                     SourceInfo::new(Pos::new(1, 17), Pos::new(1, 26)),
                 )),
+                filename_override: None,
             }
         );
     }
@@ -663,6 +871,31 @@ mod tests {
         assert_eq!(reader.cursor().index, CharPos(41));
     }
 
+    #[test]
+    fn test_file_annotations() {
+        // Typed annotations can override the content type and the filename, in any order.
+        let mut reader = Reader::new("file,photo.jpg; type: image/webp; filename: override.jpg");
+        let file_value = file_value(&mut reader).unwrap();
+        assert_eq!(file_value.content_type.unwrap().to_string(), "image/webp");
+        assert_eq!(
+            file_value.filename_override.unwrap().to_string(),
+            "override.jpg"
+        );
+
+        let mut reader = Reader::new("file,photo.jpg; filename: override.jpg # comment");
+        let file_value = crate::parser::sections::file_value(&mut reader).unwrap();
+        assert!(file_value.content_type.is_none());
+        assert_eq!(
+            file_value.filename_override.unwrap().to_string(),
+            "override.jpg"
+        );
+
+        // The bare content type form is only valid as the sole annotation.
+        let mut reader = Reader::new("file,photo.jpg; filename: a.jpg; image/webp");
+        let error = crate::parser::sections::file_value(&mut reader).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::FileContentType);
+    }
+
     #[test]
     fn test_capture() {
         let mut reader = Reader::new("url: header \"Location\"");
@@ -1037,4 +1270,53 @@ mod tests {
         );
         assert_eq!(reader.cursor().pos, Pos { line: 2, column: 1 });
     }
+
+    #[test]
+    fn test_graphql_section() {
+        let mut reader = Reader::new(
+            "[GraphQL]\nquery Foo {\n  me { name }\n}\nvariables {\"id\": 1}\noperationName: Foo\n\nHTTP 200\n",
+        );
+        let section = request_section(&mut reader).unwrap();
+        assert_eq!(section.identifier(), "GraphQL");
+        let SectionValue::GraphQl(graphql) = section.value else {
+            panic!("expecting a GraphQL section");
+        };
+        assert_eq!(
+            graphql.query.to_source().as_str(),
+            "query Foo {\n  me { name }\n}\n"
+        );
+        assert_eq!(
+            graphql.variables.unwrap().value.to_source().as_str(),
+            "{\"id\": 1}"
+        );
+        assert_eq!(graphql.operation_name.unwrap().to_source().as_str(), "Foo");
+        assert_eq!(reader.cursor().index, CharPos(77));
+    }
+
+    #[test]
+    fn test_graphql_section_query_only() {
+        let mut reader = Reader::new("[GraphQL]\n{\n  me { name }\n}\n\nHTTP 200\n");
+        let section = request_section(&mut reader).unwrap();
+        let SectionValue::GraphQl(graphql) = section.value else {
+            panic!("expecting a GraphQL section");
+        };
+        assert_eq!(graphql.query.to_source().as_str(), "{\n  me { name }\n}\n");
+        assert!(graphql.variables.is_none());
+        assert!(graphql.operation_name.is_none());
+        assert_eq!(reader.cursor().pos, Pos { line: 5, column: 1 });
+    }
+
+    #[test]
+    fn test_graphql_section_error() {
+        let mut reader = Reader::new("[GraphQL]\n\nHTTP 200\n");
+        let error = request_section(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "a GraphQL query".to_string()
+            }
+        );
+        assert_eq!(error.pos, Pos { line: 2, column: 1 });
+    }
 }
@@ -26,13 +26,22 @@ use crate::reader::Reader;
 ///    the string does not contain trailing space
 /// 2- templatize
 pub fn unquoted_template(reader: &mut Reader) -> ParseResult<Template> {
+    unquoted_template_until(reader, &[])
+}
+
+/// Same as [`unquoted_template`], but the template also ends on any char of `stop` (left
+/// unconsumed). This is used for values that can be followed by another token on the same line,
+/// like multipart file annotations separated by `;`.
+pub fn unquoted_template_until(reader: &mut Reader, stop: &[char]) -> ParseResult<Template> {
+    let mut except = vec!['#'];
+    except.extend_from_slice(stop);
     let start = reader.cursor();
     let mut chars = vec![];
     let mut spaces = vec![];
     let mut end = start;
     loop {
         let pos = reader.cursor().pos;
-        match any_char(&['#'], reader) {
+        match any_char(&except, reader) {
             Err(e) => {
                 if e.recoverable {
                     break;
@@ -54,7 +54,7 @@ pub struct Reader {
 /// Represents a line and column position in a reader.
 ///
 /// Indices are 1-based.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Pos {
     pub line: usize,
     pub column: usize,
@@ -188,14 +188,11 @@ impl Reader {
         loop {
             match self.peek() {
                 None => return s,
-                Some(c) => {
-                    if predicate(c) {
-                        _ = self.read();
-                        s.push(c);
-                    } else {
-                        return s;
-                    }
+                Some(c) if predicate(c) => {
+                    _ = self.read();
+                    s.push(c);
                 }
+                Some(_) => return s,
             }
         }
     }
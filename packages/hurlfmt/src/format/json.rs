@@ -19,9 +19,10 @@ use base64::engine::general_purpose;
 use base64::Engine;
 use hurl_core::ast::{
     Assert, Base64, Body, BooleanOption, Bytes, Capture, CertificateAttributeName, Comment, Cookie,
-    CountOption, DurationOption, Entry, EntryOption, File, FilenameParam, Filter, FilterValue, Hex,
+    CountOption, CsvColumn, DurationOption, Entry, EntryOption, File, FilenameParam, Filter,
+    FilterValue, Hex,
     HurlFile, JsonListElement, JsonValue, KeyValue, MultilineString, MultilineStringKind,
-    MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate, PredicateFuncValue,
+    MultipartParam, NaturalOption, NdjsonSelector, OptionKind, Placeholder, Predicate, PredicateFuncValue,
     PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response, StatusValue,
     VersionValue,
 };
@@ -95,6 +96,14 @@ impl ToJson for Request {
             let options = self.options().iter().map(|c| c.to_json()).collect();
             attributes.push(("options".to_string(), JValue::List(options)));
         }
+        if !self.tags().is_empty() {
+            let tags = self
+                .tags()
+                .iter()
+                .map(|t| JValue::String(t.name.clone()))
+                .collect();
+            attributes.push(("tags".to_string(), JValue::List(tags)));
+        }
         if let Some(body) = &self.body {
             attributes.push(("body".to_string(), body.to_json()));
         }
@@ -294,6 +303,12 @@ impl ToJson for FilenameParam {
                 JValue::String(content_type.to_string()),
             ));
         }
+        if let Some(filename) = &self.value.filename_override {
+            attributes.push((
+                "filename_override".to_string(),
+                JValue::String(filename.to_string()),
+            ));
+        }
         JValue::Object(attributes)
     }
 }
@@ -313,11 +328,14 @@ impl ToJson for EntryOption {
         let value = match &self.kind {
             OptionKind::AwsSigV4(value) => JValue::String(value.to_string()),
             OptionKind::CaCertificate(filename) => JValue::String(filename.to_string()),
+            OptionKind::CaCertificateAppend(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientCert(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientKey(filename) => JValue::String(filename.to_string()),
+            OptionKind::Compress(value) => JValue::String(value.to_string()),
             OptionKind::Compressed(value) => value.to_json(),
             OptionKind::ConnectTo(value) => JValue::String(value.to_string()),
             OptionKind::ConnectTimeout(value) => value.to_json(),
+            OptionKind::CsvSeparator(value) => JValue::String(value.to_string()),
             OptionKind::Delay(value) => value.to_json(),
             OptionKind::Digest(value) => value.to_json(),
             OptionKind::FollowLocation(value) => value.to_json(),
@@ -330,7 +348,9 @@ impl ToJson for EntryOption {
             OptionKind::Insecure(value) => value.to_json(),
             OptionKind::IpV4(value) => value.to_json(),
             OptionKind::IpV6(value) => value.to_json(),
+            OptionKind::IpVersion(value) => JValue::String(value.to_string()),
             OptionKind::LimitRate(value) => value.to_json(),
+            OptionKind::MaxFilesize(value) => value.to_json(),
             OptionKind::MaxRedirect(value) => value.to_json(),
             OptionKind::MaxTime(value) => value.to_json(),
             OptionKind::Negotiate(value) => value.to_json(),
@@ -346,6 +366,10 @@ impl ToJson for EntryOption {
             OptionKind::Resolve(value) => JValue::String(value.to_string()),
             OptionKind::Retry(value) => value.to_json(),
             OptionKind::RetryInterval(value) => value.to_json(),
+            OptionKind::RetryMaxInterval(value) => value.to_json(),
+            OptionKind::RetryOnStatus(value) => {
+                JValue::List(value.iter().map(|s| JValue::Number(s.to_string())).collect())
+            }
             OptionKind::Skip(value) => value.to_json(),
             OptionKind::UnixSocket(value) => JValue::String(value.to_string()),
             OptionKind::User(value) => JValue::String(value.to_string()),
@@ -355,6 +379,7 @@ impl ToJson for EntryOption {
             OptionKind::Verbose(value) => value.to_json(),
             OptionKind::Verbosity(value) => JValue::String(value.to_string()),
             OptionKind::VeryVerbose(value) => value.to_json(),
+            OptionKind::XpathNamespace(value) => JValue::String(value.to_string()),
         };
 
         // If the value contains the unit such as `{ "value": 10, "unit": "second" }`
@@ -473,9 +498,20 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Jsonpath { expr, .. } => {
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
         }
+        QueryValue::Csv { column, row, .. } => {
+            let column = match column {
+                CsvColumn::Index(index) => JValue::Number(index.to_string()),
+                CsvColumn::Name(name) => JValue::String(name.to_string()),
+            };
+            attributes.push(("col".to_string(), column));
+            attributes.push(("row".to_string(), JValue::Number(row.to_string())));
+        }
         QueryValue::Header { name, .. } => {
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
         }
+        QueryValue::LinkHeader { rel, .. } => {
+            attributes.push(("rel".to_string(), JValue::String(rel.to_string())));
+        }
         QueryValue::Cookie { expr, .. } => {
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
         }
@@ -494,6 +530,30 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         } => {
             attributes.push(("expr".to_string(), field.to_json()));
         }
+        QueryValue::BodyDigest { algorithm, .. } => {
+            attributes.push((
+                "algorithm".to_string(),
+                JValue::String(algorithm.identifier().to_string()),
+            ));
+        }
+        QueryValue::Ndjson { selector, .. } => {
+            attributes.push(("format".to_string(), JValue::String("ndjson".to_string())));
+            match selector {
+                NdjsonSelector::Count => {
+                    attributes.push(("selector".to_string(), JValue::String("count".to_string())));
+                }
+                NdjsonSelector::Line { line, expr, .. } => {
+                    attributes.push(("line".to_string(), JValue::Number(line.to_string())));
+                    attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+                }
+            }
+        }
+        QueryValue::Duration { phase: Some(phase) } => {
+            attributes.push((
+                "phase".to_string(),
+                JValue::String(phase.name.identifier().to_string()),
+            ));
+        }
         _ => {}
     };
     attributes
@@ -535,6 +595,9 @@ impl ToJson for Predicate {
 
         match &self.predicate_func.value {
             PredicateFuncValue::Equal { value, .. } => add_predicate_value(&mut attributes, value),
+            PredicateFuncValue::EqualIgnoreCase { value, .. } => {
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::NotEqual { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
@@ -553,20 +616,63 @@ impl ToJson for Predicate {
             PredicateFuncValue::StartWith { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::StartWithIgnoreCase { value, .. } => {
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::EndWith { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::EndWithIgnoreCase { value, .. } => {
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::Contain { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::ContainIgnoreCase { value, .. } => {
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::Include { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
             PredicateFuncValue::Match { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::JsonSchema { value, .. }
+            | PredicateFuncValue::MatchesSchema { value, .. } => {
+                add_predicate_value(&mut attributes, value);
+            }
+            PredicateFuncValue::MatchesJson { value, .. } => {
+                attributes.push(("value".to_string(), value.to_json()));
+            }
+            PredicateFuncValue::Approximately { value, delta, .. } => {
+                add_predicate_value(&mut attributes, value);
+                let (delta, _) = json_predicate_value(delta);
+                attributes.push(("delta".to_string(), delta));
+            }
+            PredicateFuncValue::Between { min, max, .. } => {
+                let (min, _) = json_predicate_value(min);
+                attributes.push(("min".to_string(), min));
+                let (max, _) = json_predicate_value(max);
+                attributes.push(("max".to_string(), max));
+            }
+            PredicateFuncValue::IsSorted { order, .. } => {
+                attributes.push((
+                    "order".to_string(),
+                    JValue::String(order.identifier().to_string()),
+                ));
+            }
+            PredicateFuncValue::Semver {
+                operator, value, ..
+            } => {
+                attributes.push((
+                    "operator".to_string(),
+                    JValue::String(operator.identifier().to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::Exist
             | PredicateFuncValue::IsBoolean
+            | PredicateFuncValue::IsClientError
             | PredicateFuncValue::IsCollection
             | PredicateFuncValue::IsDate
             | PredicateFuncValue::IsEmpty
@@ -575,11 +681,17 @@ impl ToJson for Predicate {
             | PredicateFuncValue::IsIpv4
             | PredicateFuncValue::IsIpv6
             | PredicateFuncValue::IsIsoDate
+            | PredicateFuncValue::IsJson
             | PredicateFuncValue::IsList
             | PredicateFuncValue::IsNumber
             | PredicateFuncValue::IsObject
+            | PredicateFuncValue::IsRedirect
+            | PredicateFuncValue::IsServerError
             | PredicateFuncValue::IsString
-            | PredicateFuncValue::IsUuid => {}
+            | PredicateFuncValue::IsSuccess
+            | PredicateFuncValue::IsUnique
+            | PredicateFuncValue::IsUuid
+            | PredicateFuncValue::IsXml => {}
         }
         JValue::Object(attributes)
     }
@@ -633,6 +745,9 @@ impl ToJson for JsonValue {
                     .collect(),
             ),
             JsonValue::Placeholder(exp) => JValue::String(format!("{{{{{exp}}}}}")),
+            JsonValue::Exact(value) => {
+                JValue::Object(vec![("exact".to_string(), value.to_json())])
+            }
         }
     }
 }
@@ -669,6 +784,9 @@ impl ToJson for FilterValue {
             FilterValue::JsonPath { expr, .. } => {
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
+            FilterValue::JwtClaim { name, .. } => {
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
             FilterValue::Nth { n, .. } => {
                 attributes.push(("n".to_string(), JValue::Number(n.to_string())));
             }
@@ -17,11 +17,14 @@
  */
 use hurl_core::ast::{
     Assert, Base64, Body, BooleanOption, Bytes, Capture, CertificateAttributeName, Comment, Cookie,
-    CookiePath, CountOption, DurationOption, Entry, EntryOption, File, FilenameParam,
-    FilenameValue, FilterValue, Hex, HurlFile, IntegerValue, JsonValue, KeyValue, LineTerminator,
-    Method, MultilineString, MultipartParam, NaturalOption, Number, OptionKind, Placeholder,
+    CompressOption, CookiePath, CountOption, CsvColumn, DurationOption, Entry, EntryOption, File,
+    FilenameParam,
+    FilenameValue, FilterValue, Hex, HurlFile, IntegerValue, IpVersionOption, JsonValue, KeyValue,
+    LineTerminator,
+    Method, MultilineString, MultipartParam, NaturalOption, NdjsonSelector, Number, OptionKind, Placeholder,
     Predicate, PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Request,
-    Response, Section, SectionValue, StatusValue, BindingParam, BindingExpr, Template, VariableDefinition, 
+    Response, Section, SectionValue, StatusValue, BindingParam, BindingExpr, Tag, Template,
+    VariableDefinition, 
     VariableValue, VerbosityOption, VersionValue, I64, U64,
 };
 use hurl_core::types::{Count, Duration, DurationUnit, ToSource};
@@ -238,8 +241,18 @@ impl Lint for FilenameValue {
         s.push(';');
         if let Some(content_type) = &self.content_type {
             s.push(' ');
+            if self.filename_override.is_some() {
+                s.push_str("type: ");
+            }
             s.push_str(&content_type.lint());
         }
+        if let Some(filename) = &self.filename_override {
+            if self.content_type.is_some() {
+                s.push(';');
+            }
+            s.push_str(" filename: ");
+            s.push_str(&filename.lint());
+        }
         s
     }
 }
@@ -269,6 +282,10 @@ impl Lint for FilterValue {
                 s.push(' ');
                 s.push_str(&expr.lint());
             }
+            FilterValue::JwtClaim { name, .. } => {
+                s.push(' ');
+                s.push_str(&name.lint());
+            }
             FilterValue::Nth { n, .. } => {
                 s.push(' ');
                 s.push_str(&n.lint());
@@ -424,6 +441,27 @@ impl Lint for BindingExpr {
                 s.push_str(&filename.lint());
                 s
             }
+            BindingExpr::JsonFile { space0, filename } => {
+                let mut s = String::new();
+                s.push_str("jsonfile");
+                s.push_str(space0.as_str());
+                s.push_str(&filename.lint());
+                s
+            }
+            BindingExpr::YamlFile { space0, filename } => {
+                let mut s = String::new();
+                s.push_str("yamlfile");
+                s.push_str(space0.as_str());
+                s.push_str(&filename.lint());
+                s
+            }
+            BindingExpr::Env { space0, var_name } => {
+                let mut s = String::new();
+                s.push_str("env");
+                s.push_str(space0.as_str());
+                s.push_str(&var_name.lint());
+                s
+            }
         }
     }
 }
@@ -494,13 +532,16 @@ impl Lint for OptionKind {
         let value = match self {
             OptionKind::AwsSigV4(value) => value.lint(),
             OptionKind::CaCertificate(value) => value.lint(),
+            OptionKind::CaCertificateAppend(value) => value.lint(),
             OptionKind::ClientCert(value) => value.lint(),
             OptionKind::ClientKey(value) => value.lint(),
+            OptionKind::Compress(value) => value.lint(),
             OptionKind::Compressed(value) => value.lint(),
             OptionKind::ConnectTo(value) => value.lint(),
             OptionKind::ConnectTimeout(value) => {
                 lint_duration_option(value, DurationUnit::MilliSecond)
             }
+            OptionKind::CsvSeparator(value) => value.lint(),
             OptionKind::Delay(value) => lint_duration_option(value, DurationUnit::MilliSecond),
             OptionKind::Digest(value) => value.lint(),
             OptionKind::Header(value) => value.lint(),
@@ -511,9 +552,11 @@ impl Lint for OptionKind {
             OptionKind::Insecure(value) => value.lint(),
             OptionKind::IpV4(value) => value.lint(),
             OptionKind::IpV6(value) => value.lint(),
+            OptionKind::IpVersion(value) => value.lint(),
             OptionKind::FollowLocation(value) => value.lint(),
             OptionKind::FollowLocationTrusted(value) => value.lint(),
             OptionKind::LimitRate(value) => value.lint(),
+            OptionKind::MaxFilesize(value) => value.lint(),
             OptionKind::MaxRedirect(value) => value.lint(),
             OptionKind::MaxTime(value) => lint_duration_option(value, DurationUnit::MilliSecond),
             OptionKind::Negotiate(value) => value.lint(),
@@ -531,6 +574,14 @@ impl Lint for OptionKind {
             OptionKind::RetryInterval(value) => {
                 lint_duration_option(value, DurationUnit::MilliSecond)
             }
+            OptionKind::RetryMaxInterval(value) => {
+                lint_duration_option(value, DurationUnit::MilliSecond)
+            }
+            OptionKind::RetryOnStatus(value) => value
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
             OptionKind::Skip(value) => value.lint(),
             OptionKind::UnixSocket(value) => value.lint(),
             OptionKind::User(value) => value.lint(),
@@ -538,6 +589,7 @@ impl Lint for OptionKind {
             OptionKind::Verbose(value) => value.lint(),
             OptionKind::Verbosity(value) => value.lint(),
             OptionKind::VeryVerbose(value) => value.lint(),
+            OptionKind::XpathNamespace(value) => value.lint(),
         };
         s.push_str(&value);
         s
@@ -556,11 +608,31 @@ impl Lint for Query {
                 s.push(' ');
                 s.push_str(&name.lint());
             }
+            QueryValue::LinkHeader { rel, .. } => {
+                s.push(' ');
+                s.push_str(&rel.lint());
+            }
             QueryValue::Cookie { expr, .. } => {
                 s.push(' ');
                 s.push_str(&expr.lint());
             }
             QueryValue::Body => {}
+            QueryValue::BodyDigest { algorithm, .. } => {
+                s.push(' ');
+                s.push_str(algorithm.identifier());
+            }
+            QueryValue::Ndjson { selector, .. } => {
+                s.push_str(" ndjson ");
+                match selector {
+                    NdjsonSelector::Count => s.push_str("count"),
+                    NdjsonSelector::Line { line, expr, .. } => {
+                        s.push_str("line ");
+                        s.push_str(line.to_source().as_str());
+                        s.push_str(" jsonpath ");
+                        s.push_str(&expr.lint());
+                    }
+                }
+            }
             QueryValue::Xpath { expr, .. } => {
                 s.push(' ');
                 s.push_str(&expr.lint());
@@ -569,6 +641,15 @@ impl Lint for Query {
                 s.push(' ');
                 s.push_str(&expr.lint());
             }
+            QueryValue::Csv { column, row, .. } => {
+                s.push_str(" col ");
+                match column {
+                    CsvColumn::Index(index) => s.push_str(index.to_source().as_str()),
+                    CsvColumn::Name(name) => s.push_str(&name.lint()),
+                }
+                s.push_str(" row ");
+                s.push_str(row.to_source().as_str());
+            }
             QueryValue::Regex { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
@@ -577,7 +658,12 @@ impl Lint for Query {
                 s.push(' ');
                 s.push_str(&name.lint());
             }
-            QueryValue::Duration => {}
+            QueryValue::Duration { phase } => {
+                if let Some(phase) = phase {
+                    s.push(' ');
+                    s.push_str(phase.name.identifier());
+                }
+            }
             QueryValue::Bytes => {}
             QueryValue::RawBytes => {}
             QueryValue::Sha256 => {}
@@ -620,6 +706,10 @@ impl Lint for PredicateFuncValue {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            PredicateFuncValue::EqualIgnoreCase { value, .. } => {
+                s.push(' ');
+                s.push_str(&value.lint());
+            }
             PredicateFuncValue::NotEqual { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
@@ -640,18 +730,54 @@ impl Lint for PredicateFuncValue {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            PredicateFuncValue::Approximately { value, delta, .. } => {
+                s.push(' ');
+                s.push_str(&value.lint());
+                s.push_str(" delta ");
+                s.push_str(&delta.lint());
+            }
+            PredicateFuncValue::Between { min, max, .. } => {
+                s.push(' ');
+                s.push_str(&min.lint());
+                s.push(' ');
+                s.push_str(&max.lint());
+            }
+            PredicateFuncValue::Semver {
+                operator, value, ..
+            } => {
+                s.push(' ');
+                s.push_str(operator.identifier());
+                s.push(' ');
+                s.push_str(&value.lint());
+            }
+            PredicateFuncValue::IsSorted { order, .. } => {
+                s.push(' ');
+                s.push_str(order.identifier());
+            }
             PredicateFuncValue::StartWith { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            PredicateFuncValue::StartWithIgnoreCase { value, .. } => {
+                s.push(' ');
+                s.push_str(&value.lint());
+            }
             PredicateFuncValue::EndWith { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            PredicateFuncValue::EndWithIgnoreCase { value, .. } => {
+                s.push(' ');
+                s.push_str(&value.lint());
+            }
             PredicateFuncValue::Contain { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            PredicateFuncValue::ContainIgnoreCase { value, .. } => {
+                s.push(' ');
+                s.push_str(&value.lint());
+            }
             PredicateFuncValue::Include { value, .. } => {
                 s.push(' ');
                 s.push_str(&value.lint());
@@ -660,8 +786,18 @@ impl Lint for PredicateFuncValue {
                 s.push(' ');
                 s.push_str(&value.lint());
             }
+            PredicateFuncValue::JsonSchema { value, .. }
+            | PredicateFuncValue::MatchesSchema { value, .. } => {
+                s.push(' ');
+                s.push_str(&value.lint());
+            }
+            PredicateFuncValue::MatchesJson { value, .. } => {
+                s.push(' ');
+                s.push_str(value.to_source().as_str());
+            }
             PredicateFuncValue::Exist
             | PredicateFuncValue::IsBoolean
+            | PredicateFuncValue::IsClientError
             | PredicateFuncValue::IsCollection
             | PredicateFuncValue::IsDate
             | PredicateFuncValue::IsEmpty
@@ -670,11 +806,17 @@ impl Lint for PredicateFuncValue {
             | PredicateFuncValue::IsIpv4
             | PredicateFuncValue::IsIpv6
             | PredicateFuncValue::IsIsoDate
+            | PredicateFuncValue::IsJson
             | PredicateFuncValue::IsList
             | PredicateFuncValue::IsNumber
             | PredicateFuncValue::IsObject
+            | PredicateFuncValue::IsRedirect
+            | PredicateFuncValue::IsServerError
             | PredicateFuncValue::IsString
-            | PredicateFuncValue::IsUuid => {}
+            | PredicateFuncValue::IsSuccess
+            | PredicateFuncValue::IsUnique
+            | PredicateFuncValue::IsUuid
+            | PredicateFuncValue::IsXml => {}
         }
         s
     }
@@ -726,6 +868,9 @@ impl Lint for Request {
         self.headers.iter().for_each(|h| s.push_str(&h.lint()));
 
         // We rewrite our file and reorder the various section.
+        if let Some(section) = get_tags_section(self) {
+            s.push_str(&section.lint());
+        }
         if let Some(section) = get_option_section(self) {
             s.push_str(&section.lint());
         }
@@ -827,11 +972,39 @@ impl Lint for SectionValue {
             SectionValue::Bindings(params) => {
                 params.iter().for_each(|p| s.push_str(&p.lint()));
             }
+            SectionValue::Tags(tags) => {
+                tags.iter().for_each(|t| s.push_str(&t.lint()));
+            }
+            SectionValue::GraphQl(graphql) => {
+                s.push_str(graphql.query.to_source().as_str());
+                if let Some(vars) = &graphql.variables {
+                    s.push_str("variables ");
+                    s.push_str(vars.value.to_source().as_str());
+                    s.push('\n');
+                }
+                if let Some(name) = &graphql.operation_name {
+                    s.push_str("operationName: ");
+                    s.push_str(name.to_source().as_str());
+                    s.push('\n');
+                }
+            }
         }
         s
     }
 }
 
+impl Lint for Tag {
+    fn lint(&self) -> String {
+        let mut s = String::new();
+        self.line_terminators
+            .iter()
+            .for_each(|lt| s.push_str(&lint_lt(lt, false)));
+        s.push_str(&self.name);
+        s.push_str(&lint_lt(&self.line_terminator0, true));
+        s
+    }
+}
+
 impl Lint for StatusValue {
     fn lint(&self) -> String {
         self.to_source().to_string()
@@ -872,12 +1045,24 @@ impl Lint for VersionValue {
     }
 }
 
+impl Lint for CompressOption {
+    fn lint(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl Lint for VerbosityOption {
     fn lint(&self) -> String {
         self.to_string()
     }
 }
 
+impl Lint for IpVersionOption {
+    fn lint(&self) -> String {
+        self.to_string()
+    }
+}
+
 fn get_asserts_section(response: &Response) -> Option<&Section> {
     for s in &response.sections {
         if let SectionValue::Asserts(_) = s.value {
@@ -950,6 +1135,15 @@ fn get_basic_auth_section(request: &Request) -> Option<&Section> {
     None
 }
 
+fn get_tags_section(request: &Request) -> Option<&Section> {
+    for s in &request.sections {
+        if let SectionValue::Tags(_) = s.value {
+            return Some(s);
+        }
+    }
+    None
+}
+
 fn get_sync_section(request: &Request) -> Option<&Section> {
     for s in &request.sections {
         if let SectionValue::Bindings(_) = s.value {
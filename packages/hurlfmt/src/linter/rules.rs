@@ -313,6 +313,10 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::EqualIgnoreCase { value, .. } => PredicateFuncValue::EqualIgnoreCase {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
         PredicateFuncValue::NotEqual { value, .. } => PredicateFuncValue::NotEqual {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -335,10 +339,39 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::Approximately { value, delta, .. } => {
+            PredicateFuncValue::Approximately {
+                space0: one_whitespace(),
+                value: lint_predicate_value(value),
+                space1: one_whitespace(),
+                space2: one_whitespace(),
+                delta: lint_predicate_value(delta),
+            }
+        }
+        PredicateFuncValue::Between { min, max, .. } => PredicateFuncValue::Between {
+            space0: one_whitespace(),
+            min: lint_predicate_value(min),
+            space1: one_whitespace(),
+            max: lint_predicate_value(max),
+        },
+        PredicateFuncValue::Semver {
+            operator, value, ..
+        } => PredicateFuncValue::Semver {
+            space0: one_whitespace(),
+            operator: *operator,
+            space1: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
         PredicateFuncValue::Contain { value, .. } => PredicateFuncValue::Contain {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::ContainIgnoreCase { value, .. } => {
+            PredicateFuncValue::ContainIgnoreCase {
+                space0: one_whitespace(),
+                value: lint_predicate_value(value),
+            }
+        }
 
         PredicateFuncValue::Include { value, .. } => PredicateFuncValue::Include {
             space0: one_whitespace(),
@@ -349,14 +382,34 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::JsonSchema { value, .. } => PredicateFuncValue::JsonSchema {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
+        PredicateFuncValue::MatchesSchema { value, .. } => PredicateFuncValue::MatchesSchema {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
         PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::StartWithIgnoreCase { value, .. } => {
+            PredicateFuncValue::StartWithIgnoreCase {
+                space0: one_whitespace(),
+                value: lint_predicate_value(value),
+            }
+        }
         PredicateFuncValue::EndWith { value, .. } => PredicateFuncValue::EndWith {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::EndWithIgnoreCase { value, .. } => {
+            PredicateFuncValue::EndWithIgnoreCase {
+                space0: one_whitespace(),
+                value: lint_predicate_value(value),
+            }
+        }
         PredicateFuncValue::IsInteger => PredicateFuncValue::IsInteger,
         PredicateFuncValue::IsFloat => PredicateFuncValue::IsFloat,
         PredicateFuncValue::IsBoolean => PredicateFuncValue::IsBoolean,
@@ -461,6 +514,7 @@ fn lint_body(body: &Body) -> Body {
         line_terminators,
         space0,
         value,
+        is_heredoc: body.is_heredoc,
         line_terminator0,
     }
 }
@@ -619,6 +673,9 @@ fn lint_option_kind(option_kind: &OptionKind) -> OptionKind {
         OptionKind::RetryInterval(duration) => {
             OptionKind::RetryInterval(lint_duration_option(duration, DurationUnit::MilliSecond))
         }
+        OptionKind::RetryMaxInterval(duration) => {
+            OptionKind::RetryMaxInterval(lint_duration_option(duration, DurationUnit::MilliSecond))
+        }
         OptionKind::Variable(var_def) => OptionKind::Variable(lint_variable_definition(var_def)),
         _ => option_kind.clone(),
     }